@@ -0,0 +1,95 @@
+//! Authenticates with a key held by a remote signing service instead of a private key in memory.
+//!
+//! Any [`Signer`] can authenticate a [`Client`] and sign its orders: the client only needs the
+//! `address` and `chain_id` of the signer and its `sign_hash`, which is async so that the hash can
+//! be sent to a hardware wallet, an HSM or a signing service. For AWS KMS, alloy's `AwsSigner`
+//! already implements it, see the `aws_authenticated` example; other key managers only need the
+//! same three methods, as shown by [`RemoteSigner`] below.
+//!
+//! Run with `REMOTE_SIGNER_URL` set to the signing service, e.g. `https://signer.internal`, and
+//! `REMOTE_SIGNER_ADDRESS` to the address of the key it signs with.
+
+#![allow(clippy::print_stdout, reason = "Examples are okay to print to stdout")]
+
+use std::env;
+use std::str::FromStr as _;
+
+use alloy::primitives::B256;
+use alloy::signers::{Error as SignerError, Result as SignerResult, Signer};
+use async_trait::async_trait;
+use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::types::{Address, ChainId, Signature};
+use serde_json::{Value, json};
+
+/// Signs hashes by posting them to `{url}/sign` as `{"address": …, "hash": "0x…"}` and reading
+/// the 65-byte signature back from `{"signature": "0x…"}`.
+#[derive(Clone, Debug)]
+struct RemoteSigner {
+    http: reqwest::Client,
+    url: String,
+    address: Address,
+    chain_id: Option<ChainId>,
+}
+
+impl RemoteSigner {
+    fn new(url: String, address: Address) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            url,
+            address,
+            chain_id: None,
+        }
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    async fn sign_hash(&self, hash: &B256) -> SignerResult<Signature> {
+        let response: Value = self
+            .http
+            .post(format!("{}/sign", self.url))
+            .json(&json!({ "address": self.address, "hash": hash }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SignerError::other)?
+            .json()
+            .await
+            .map_err(SignerError::other)?;
+
+        let signature = response["signature"]
+            .as_str()
+            .ok_or_else(|| SignerError::message("signing service returned no signature"))?;
+
+        Signature::from_str(signature).map_err(SignerError::other)
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn chain_id(&self) -> Option<ChainId> {
+        self.chain_id
+    }
+
+    fn set_chain_id(&mut self, chain_id: Option<ChainId>) {
+        self.chain_id = chain_id;
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let url = env::var("REMOTE_SIGNER_URL").expect("Need a signing service URL");
+    let address = env::var("REMOTE_SIGNER_ADDRESS").expect("Need the address of the key");
+    let signer = RemoteSigner::new(url, Address::from_str(&address)?).with_chain_id(Some(POLYGON));
+
+    let client = Client::new("https://clob.polymarket.com", Config::default())?
+        .authentication_builder(&signer)
+        .authenticate()
+        .await?;
+
+    println!("api_keys -- {:?}", client.api_keys().await?);
+
+    Ok(())
+}
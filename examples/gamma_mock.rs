@@ -0,0 +1,193 @@
+//! Writing code that is generic over the Gamma API, and testing it with a hand-written mock.
+//!
+//! [`GammaApi`] is implemented by [`Client`], so the same function runs against the live API or
+//! against a test double that serves canned data.
+//!
+//! Run with:
+//! ```sh
+//! cargo run --example gamma_mock --features gamma
+//! ```
+
+#![allow(clippy::print_stdout, reason = "Examples are okay to print to stdout")]
+
+use async_trait::async_trait;
+use polymarket_client_sdk::Result;
+use polymarket_client_sdk::error::Error;
+use polymarket_client_sdk::gamma::types::request::{
+    CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest, EventByIdRequest,
+    EventBySlugRequest, EventTagsRequest, EventsRequest, MarketByIdRequest, MarketBySlugRequest,
+    MarketTagsRequest, MarketsRequest, PublicProfileRequest, RelatedTagsByIdRequest,
+    RelatedTagsBySlugRequest, SearchRequest, SeriesByIdRequest, SeriesListRequest,
+    SportsGamesRequest, TagByIdRequest, TagBySlugRequest, TagsRequest, TeamsRequest,
+};
+use polymarket_client_sdk::gamma::types::response::{
+    Comment, Event, Game, HealthResponse, Market, PublicProfile, RelatedTag, SearchResults, Series,
+    SportsMarketTypesResponse, SportsMetadata, Tag, Team,
+};
+use polymarket_client_sdk::gamma::{Client, GammaApi};
+
+/// Returns the labels of the first `limit` tags, skipping tags without a label.
+async fn tag_labels(api: &impl GammaApi, limit: i32) -> Result<Vec<String>> {
+    let request = TagsRequest::builder().limit(limit).build();
+    let tags = api.tags(&request).await?;
+
+    Ok(tags.into_iter().filter_map(|tag| tag.label).collect())
+}
+
+/// A test double serving a fixed set of tags. Every other method returns an error.
+struct MockGamma {
+    tags: Vec<Tag>,
+}
+
+#[async_trait]
+impl GammaApi for MockGamma {
+    async fn tags(&self, request: &TagsRequest) -> Result<Vec<Tag>> {
+        let limit = request
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(self.tags.len());
+
+        Ok(self.tags.iter().take(limit).cloned().collect())
+    }
+
+    async fn status(&self) -> Result<HealthResponse> {
+        Err(unmocked("status"))
+    }
+
+    async fn teams(&self, _request: &TeamsRequest) -> Result<Vec<Team>> {
+        Err(unmocked("teams"))
+    }
+
+    async fn sports(&self) -> Result<Vec<SportsMetadata>> {
+        Err(unmocked("sports"))
+    }
+
+    async fn sports_market_types(&self) -> Result<SportsMarketTypesResponse> {
+        Err(unmocked("sports_market_types"))
+    }
+
+    async fn sports_games(&self, _request: &SportsGamesRequest) -> Result<Vec<Game>> {
+        Err(unmocked("sports_games"))
+    }
+
+    async fn tag_by_id(&self, _request: &TagByIdRequest) -> Result<Tag> {
+        Err(unmocked("tag_by_id"))
+    }
+
+    async fn tag_by_slug(&self, _request: &TagBySlugRequest) -> Result<Tag> {
+        Err(unmocked("tag_by_slug"))
+    }
+
+    async fn related_tags_by_id(
+        &self,
+        _request: &RelatedTagsByIdRequest,
+    ) -> Result<Vec<RelatedTag>> {
+        Err(unmocked("related_tags_by_id"))
+    }
+
+    async fn related_tags_by_slug(
+        &self,
+        _request: &RelatedTagsBySlugRequest,
+    ) -> Result<Vec<RelatedTag>> {
+        Err(unmocked("related_tags_by_slug"))
+    }
+
+    async fn tags_related_to_tag_by_id(
+        &self,
+        _request: &RelatedTagsByIdRequest,
+    ) -> Result<Vec<Tag>> {
+        Err(unmocked("tags_related_to_tag_by_id"))
+    }
+
+    async fn tags_related_to_tag_by_slug(
+        &self,
+        _request: &RelatedTagsBySlugRequest,
+    ) -> Result<Vec<Tag>> {
+        Err(unmocked("tags_related_to_tag_by_slug"))
+    }
+
+    async fn events(&self, _request: &EventsRequest) -> Result<Vec<Event>> {
+        Err(unmocked("events"))
+    }
+
+    async fn event_by_id(&self, _request: &EventByIdRequest) -> Result<Event> {
+        Err(unmocked("event_by_id"))
+    }
+
+    async fn event_by_slug(&self, _request: &EventBySlugRequest) -> Result<Event> {
+        Err(unmocked("event_by_slug"))
+    }
+
+    async fn event_tags(&self, _request: &EventTagsRequest) -> Result<Vec<Tag>> {
+        Err(unmocked("event_tags"))
+    }
+
+    async fn markets(&self, _request: &MarketsRequest) -> Result<Vec<Market>> {
+        Err(unmocked("markets"))
+    }
+
+    async fn market_by_id(&self, _request: &MarketByIdRequest) -> Result<Market> {
+        Err(unmocked("market_by_id"))
+    }
+
+    async fn market_by_slug(&self, _request: &MarketBySlugRequest) -> Result<Market> {
+        Err(unmocked("market_by_slug"))
+    }
+
+    async fn market_tags(&self, _request: &MarketTagsRequest) -> Result<Vec<Tag>> {
+        Err(unmocked("market_tags"))
+    }
+
+    async fn series(&self, _request: &SeriesListRequest) -> Result<Vec<Series>> {
+        Err(unmocked("series"))
+    }
+
+    async fn series_by_id(&self, _request: &SeriesByIdRequest) -> Result<Series> {
+        Err(unmocked("series_by_id"))
+    }
+
+    async fn comments(&self, _request: &CommentsRequest) -> Result<Vec<Comment>> {
+        Err(unmocked("comments"))
+    }
+
+    async fn comments_by_id(&self, _request: &CommentsByIdRequest) -> Result<Vec<Comment>> {
+        Err(unmocked("comments_by_id"))
+    }
+
+    async fn comments_by_user_address(
+        &self,
+        _request: &CommentsByUserAddressRequest,
+    ) -> Result<Vec<Comment>> {
+        Err(unmocked("comments_by_user_address"))
+    }
+
+    async fn public_profile(&self, _request: &PublicProfileRequest) -> Result<PublicProfile> {
+        Err(unmocked("public_profile"))
+    }
+
+    async fn search(&self, _request: &SearchRequest) -> Result<SearchResults> {
+        Err(unmocked("search"))
+    }
+}
+
+fn unmocked(method: &str) -> Error {
+    Error::validation(format!("`{method}` is not mocked"))
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let tags = serde_json::from_value(serde_json::json!([
+        { "id": "1", "label": "Politics", "slug": "politics" },
+        { "id": "2", "slug": "unlabeled" },
+        { "id": "3", "label": "Sports", "slug": "sports" },
+    ]))?;
+    let mock = MockGamma { tags };
+
+    println!("mock tags: {:?}", tag_labels(&mock, 10).await?);
+    println!("mock sports: {:?}", mock.sports().await.map(|s| s.len()));
+
+    let client = Client::default();
+    println!("live tags: {:?}", tag_labels(&client, 5).await?);
+
+    Ok(())
+}
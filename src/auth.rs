@@ -1,3 +1,7 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
 // Re-exported types for public API convenience
 /// The [`Signer`] trait from alloy for signing operations.
 /// Implement this trait or use provided signers like [`LocalSigner`] or AWS KMS signers.
@@ -64,10 +68,68 @@ impl Credentials {
     }
 }
 
+/// The [`Credentials`] an [`state::Authenticated`] client signs its requests with, along with when
+/// they were obtained. Rotating the API key swaps them as a whole, so a request that has already
+/// read them keeps the same set until it completes.
+#[derive(Debug)]
+pub(crate) struct CurrentCredentials {
+    inner: RwLock<(Arc<Credentials>, Instant)>,
+    rotating: AtomicBool,
+}
+
+impl CurrentCredentials {
+    pub(crate) fn new(credentials: Credentials) -> Self {
+        Self {
+            inner: RwLock::new((Arc::new(credentials), Instant::now())),
+            rotating: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the credentials to sign the next request with.
+    pub(crate) fn get(&self) -> Arc<Credentials> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        Arc::clone(&inner.0)
+    }
+
+    /// Returns how long ago the current credentials were obtained.
+    pub(crate) fn age(&self) -> Duration {
+        self.inner
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .1
+            .elapsed()
+    }
+
+    /// Makes `credentials` the ones used for subsequent requests.
+    pub(crate) fn replace(&self, credentials: Credentials) {
+        *self.inner.write().unwrap_or_else(PoisonError::into_inner) =
+            (Arc::new(credentials), Instant::now());
+    }
+
+    /// Marks a rotation as started, returning `false` if another one is already in progress.
+    pub(crate) fn begin_rotation(&self) -> bool {
+        !self.rotating.swap(true, Ordering::AcqRel)
+    }
+
+    pub(crate) fn end_rotation(&self) {
+        self.rotating.store(false, Ordering::Release);
+    }
+}
+
+impl Clone for CurrentCredentials {
+    fn clone(&self) -> Self {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        Self {
+            inner: RwLock::new((Arc::clone(&inner.0), inner.1)),
+            rotating: AtomicBool::new(false),
+        }
+    }
+}
+
 /// Each [`Client`] can exist in one state at a time, i.e. [`state::Unauthenticated`] or
 /// [`state::Authenticated`].
 pub mod state {
-    use crate::auth::{Credentials, Kind};
+    use crate::auth::{CurrentCredentials, Kind};
     use crate::types::Address;
 
     /// The initial state of the [`super::Client`]
@@ -85,9 +147,10 @@ pub mod state {
     pub struct Authenticated<K: Kind> {
         /// The signer's address that created the credentials
         pub(crate) address: Address,
-        /// The [`Credentials`]'s `secret` is used to generate an [`crate::signer::hmac`] which is
-        /// passed in the L2 headers ([`super::HeaderMap`]) `POLY_SIGNATURE` field.
-        pub(crate) credentials: Credentials,
+        /// The current [`super::Credentials`]'s `secret` is used to generate an
+        /// [`crate::signer::hmac`] which is passed in the L2 headers ([`super::HeaderMap`])
+        /// `POLY_SIGNATURE` field.
+        pub(crate) credentials: CurrentCredentials,
         /// The [`Kind`] that this [`Authenticated`] exhibits. Used to generate additional headers
         /// for different types of authentication, e.g. Builder.
         pub(crate) kind: K,
@@ -218,7 +281,7 @@ pub(crate) mod l2 {
     use secrecy::ExposeSecret as _;
 
     use crate::auth::state::Authenticated;
-    use crate::auth::{Kind, hmac, to_message};
+    use crate::auth::{Credentials, Kind, hmac, to_message};
     use crate::{Result, Timestamp};
 
     pub(crate) const POLY_ADDRESS: &str = "POLY_ADDRESS";
@@ -233,7 +296,17 @@ pub(crate) mod l2 {
         request: &Request,
         timestamp: Timestamp,
     ) -> Result<HeaderMap> {
-        let credentials = &state.credentials;
+        create_headers_with(state, &state.credentials.get(), request, timestamp).await
+    }
+
+    /// Returns the headers of [`create_headers`] signed with `credentials` instead of the
+    /// current credentials of `state`, e.g. to delete a key that was just rotated out.
+    pub(crate) async fn create_headers_with<K: Kind>(
+        state: &Authenticated<K>,
+        credentials: &Credentials,
+        request: &Request,
+        timestamp: Timestamp,
+    ) -> Result<HeaderMap> {
         let signature = hmac(&credentials.secret, &to_message(request, timestamp))?;
 
         let mut map = HeaderMap::new();
@@ -242,10 +315,10 @@ pub(crate) mod l2 {
             POLY_ADDRESS,
             state.address.encode_hex_with_prefix().parse()?,
         );
-        map.insert(POLY_API_KEY, state.credentials.key.to_string().parse()?);
+        map.insert(POLY_API_KEY, credentials.key.to_string().parse()?);
         map.insert(
             POLY_PASSPHRASE,
-            state.credentials.passphrase.expose_secret().parse()?,
+            credentials.passphrase.expose_secret().parse()?,
         );
         map.insert(POLY_SIGNATURE, signature.parse()?);
         map.insert(POLY_TIMESTAMP, timestamp.to_string().parse()?);
@@ -305,7 +378,7 @@ pub mod builder {
         }
 
         pub fn remote(host: &str, token: Option<String>) -> Result<Self> {
-            let host = Url::parse(host)?;
+            let host = crate::parse_host(host)?;
             Ok(Config::Remote { host, token })
         }
     }
@@ -464,7 +537,7 @@ mod tests {
 
         let authenticated = Authenticated {
             address: signer.address(),
-            credentials: Credentials {
+            credentials: CurrentCredentials::new(Credentials {
                 key: Uuid::nil(),
                 passphrase: SecretString::from(
                     "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned(),
@@ -472,7 +545,7 @@ mod tests {
                 secret: SecretString::from(
                     "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned(),
                 ),
-            },
+            }),
             kind: Normal,
         };
 
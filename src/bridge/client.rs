@@ -5,7 +5,7 @@ use reqwest::{
 use url::Url;
 
 use super::types::{DepositRequest, DepositResponse, SupportedAssetsResponse};
-use crate::Result;
+use crate::{RedirectPolicy, Result};
 
 /// Client for the Polymarket Bridge API.
 ///
@@ -52,16 +52,34 @@ impl Client {
     ///
     /// Returns an error if the host URL is invalid or the HTTP client fails to build.
     pub fn new(host: &str) -> Result<Client> {
+        Self::with_redirect(host, RedirectPolicy::default())
+    }
+
+    /// Creates a new Bridge API client with a custom host and redirect handling.
+    ///
+    /// With [`RedirectPolicy::None`], the default of [`Client::new`], a `3xx` response surfaces
+    /// as a [`Kind::Redirect`](crate::error::Kind::Redirect) error instead of being followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the host URL is invalid or the HTTP client fails to build.
+    pub fn with_redirect(host: &str, redirect: RedirectPolicy) -> Result<Client> {
         let mut headers = HeaderMap::new();
 
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
+        headers.insert(
+            "User-Agent",
+            HeaderValue::from_static(crate::DEFAULT_USER_AGENT),
+        );
         headers.insert("Accept", HeaderValue::from_static("*/*"));
         headers.insert("Connection", HeaderValue::from_static("keep-alive"));
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+        let client = ReqwestClient::builder()
+            .default_headers(headers)
+            .redirect(redirect.to_reqwest())
+            .build()?;
 
         Ok(Self {
-            host: Url::parse(host)?,
+            host: crate::parse_host(host)?,
             client,
         })
     }
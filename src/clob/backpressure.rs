@@ -0,0 +1,176 @@
+//! Pausing of every request to a host that answered with `429 Too Many Requests`, enabled by
+//! the `backpressure` of [`Config`](crate::clob::Config).
+//!
+//! When a `429` carries a `Retry-After`, the requests to its host from the client and all of its
+//! clones wait for that delay before being sent, instead of each being rejected in turn. Unlike
+//! the `should_retry` of the config, this never sends a request again: the request that got the
+//! `429` still fails with it. A pause never lasts longer than the `max_backpressure_pause` of
+//! the config, however far out the `Retry-After` is.
+
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use futures_timer::Delay;
+use reqwest::StatusCode;
+use url::Url;
+
+use crate::error::{Error, Status};
+
+/// End of the pause of each host, keyed by the authority of its URLs, e.g.
+/// `clob.polymarket.com`.
+#[derive(Debug)]
+pub(crate) struct Backpressure {
+    paused_until: DashMap<String, Instant>,
+    /// Longest pause made for a single `429`
+    max_pause: Duration,
+}
+
+impl Backpressure {
+    pub(crate) fn new(max_pause: Duration) -> Self {
+        Self {
+            paused_until: DashMap::new(),
+            max_pause,
+        }
+    }
+
+    /// Waits until requests to the host of `url` are no longer paused, including any extension
+    /// of the pause made while waiting.
+    pub(crate) async fn wait(&self, url: &Url) {
+        loop {
+            let remaining = match self.paused_until.get(url.authority()) {
+                Some(until) => until.saturating_duration_since(Instant::now()),
+                None => return,
+            };
+            if remaining.is_zero() {
+                return;
+            }
+
+            Delay::new(remaining).await;
+        }
+    }
+
+    /// Pauses requests to the host of `url` for the `Retry-After` of `error`, at most the maximum
+    /// pause, if it is a `429` that carries one. A pause already in place is only ever extended,
+    /// never shortened.
+    pub(crate) fn record(&self, url: &Url, error: &Error) {
+        let Some(status) = error.downcast_ref::<Status>() else {
+            return;
+        };
+        let Some(retry_after) = status.retry_after else {
+            return;
+        };
+        if status.status_code != StatusCode::TOO_MANY_REQUESTS {
+            return;
+        }
+        let retry_after = retry_after.min(self.max_pause);
+        // A delay too far out to be represented is not a pause worth keeping
+        let Some(until) = Instant::now().checked_add(retry_after) else {
+            return;
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            host = url.authority(),
+            ?retry_after,
+            "Pausing requests to rate-limited host"
+        );
+
+        self.paused_until
+            .entry(url.authority().to_owned())
+            .and_modify(|paused_until| *paused_until = (*paused_until).max(until))
+            .or_insert(until);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use reqwest::Method;
+
+    use super::*;
+
+    fn status(status_code: StatusCode, retry_after: Option<Duration>) -> Error {
+        Status {
+            status_code,
+            method: Method::GET,
+            path: "/".to_owned(),
+            message: String::new(),
+            retry_after,
+            body: None,
+        }
+        .into()
+    }
+
+    fn rate_limited(retry_after: Option<Duration>) -> Error {
+        status(StatusCode::TOO_MANY_REQUESTS, retry_after)
+    }
+
+    #[test]
+    fn rate_limited_host_should_be_paused() {
+        let backpressure = Backpressure::new(Duration::from_secs(3600));
+        let host = Url::parse("https://clob.polymarket.com/book").unwrap();
+        let other = Url::parse("https://gamma-api.polymarket.com/markets").unwrap();
+
+        backpressure.record(&host, &rate_limited(Some(Duration::from_millis(50))));
+
+        let started = Instant::now();
+        block_on(backpressure.wait(&other));
+        assert!(
+            started.elapsed() < Duration::from_millis(50),
+            "other hosts should not be paused"
+        );
+        block_on(backpressure.wait(&host));
+        assert!(
+            started.elapsed() >= Duration::from_millis(50),
+            "the rate-limited host should be paused for the retry-after"
+        );
+    }
+
+    #[test]
+    fn pause_should_only_be_extended() {
+        let backpressure = Backpressure::new(Duration::from_secs(3600));
+        let host = Url::parse("https://clob.polymarket.com/").unwrap();
+
+        backpressure.record(&host, &rate_limited(Some(Duration::from_secs(60))));
+        backpressure.record(&host, &rate_limited(Some(Duration::from_millis(1))));
+        backpressure.record(&host, &rate_limited(None));
+
+        let until = *backpressure.paused_until.get(host.authority()).unwrap();
+        assert!(
+            until > Instant::now() + Duration::from_secs(50),
+            "a shorter retry-after should not shorten the pause"
+        );
+    }
+
+    #[test]
+    fn pause_should_be_clamped_to_maximum() {
+        let backpressure = Backpressure::new(Duration::from_secs(5));
+        let host = Url::parse("https://clob.polymarket.com/").unwrap();
+
+        backpressure.record(&host, &rate_limited(Some(Duration::from_secs(86_400))));
+
+        let until = *backpressure.paused_until.get(host.authority()).unwrap();
+        assert!(
+            until <= Instant::now() + Duration::from_secs(5),
+            "the pause should not exceed the maximum"
+        );
+    }
+
+    #[test]
+    fn other_errors_should_not_pause() {
+        let backpressure = Backpressure::new(Duration::from_secs(3600));
+        let host = Url::parse("https://clob.polymarket.com/").unwrap();
+        let unavailable = status(
+            StatusCode::SERVICE_UNAVAILABLE,
+            Some(Duration::from_secs(60)),
+        );
+
+        backpressure.record(&host, &unavailable);
+        backpressure.record(&host, &Error::validation("bad request"));
+
+        assert!(
+            backpressure.paused_until.is_empty(),
+            "only a 429 should pause the host"
+        );
+    }
+}
@@ -1,7 +1,13 @@
 use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::iter;
 use std::marker::PhantomData;
 use std::mem;
-use std::sync::Arc;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
 use alloy::dyn_abi::Eip712Domain;
 use alloy::primitives::U256;
@@ -9,23 +15,32 @@ use alloy::signers::Signer;
 use alloy::sol_types::SolStruct as _;
 use async_stream::try_stream;
 use bon::Builder;
+use bytes::Bytes;
 use chrono::{NaiveDate, Utc};
 use dashmap::DashMap;
-use futures::Stream;
-use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::{Client as ReqwestClient, Method, Request};
+use futures::{Stream, StreamExt as _, TryStreamExt as _, stream};
+use futures_timer::Delay;
+use http_body::Frame;
+use http_body_util::StreamBody;
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderValue};
+use reqwest::{Body, Client as ReqwestClient, Method, Request};
+use serde::Serialize;
 use serde_json::json;
 use url::Url;
 
 use crate::auth::builder::{Builder, Config as BuilderConfig};
 use crate::auth::state::{Authenticated, State, Unauthenticated};
-use crate::auth::{Credentials, Kind, Normal};
+use crate::auth::{Credentials, CurrentCredentials, Kind, Normal};
+use crate::clob::backpressure::Backpressure;
+use crate::clob::coalesce::{self, Coalescer};
+use crate::clob::latency::{LatencyStats, LatencyTracker};
 use crate::clob::order_builder::{Limit, Market, OrderBuilder, generate_seed};
+use crate::clob::pagination::Cursor;
 use crate::clob::types::request::{
     BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
-    LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, OrdersRequest,
-    PriceHistoryRequest, PriceRequest, SpreadRequest, TradesRequest, UpdateBalanceAllowanceRequest,
-    UserRewardsEarningRequest,
+    LastTradePriceRequest, MarketsRequest, MidpointRequest, OrderBookSummaryRequest, OrdersRequest,
+    PriceHistoryRequest, PriceRequest, SamplingFilter, SpreadRequest, TradesRequest,
+    UpdateBalanceAllowanceRequest, UserRewardsEarningRequest,
 };
 use crate::clob::types::response::{
     ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, BuilderApiKeyResponse,
@@ -33,24 +48,22 @@ use crate::clob::types::response::{
     GeoblockResponse, LastTradePriceResponse, LastTradesPricesResponse, MarketResponse,
     MarketRewardResponse, MidpointResponse, MidpointsResponse, NegRiskResponse,
     NotificationResponse, OpenOrderResponse, OrderBookSummaryResponse, OrderScoringResponse,
-    OrdersScoringResponse, Page, PostOrderResponse, PriceHistoryResponse, PriceResponse,
-    PricesResponse, RewardsPercentagesResponse, SimplifiedMarketResponse, SpreadResponse,
-    SpreadsResponse, TickSizeResponse, TotalUserEarningResponse, TradeResponse,
-    UserEarningResponse, UserRewardsEarningResponse,
+    OrdersScoringResponse, Page, PostOrderResponse, PriceHistoryResponse, PricePoint,
+    PriceResponse, PricesResponse, PublicTrade, RewardsPercentagesResponse,
+    SimplifiedMarketResponse, SpreadResponse, SpreadsResponse, TickSizeResponse,
+    TotalUserEarningResponse, TradeResponse, UserEarningResponse, UserRewardsEarningResponse,
 };
-use crate::clob::types::{SignableOrder, SignatureType, SignedOrder, TickSize};
-use crate::error::{Error, Synchronization};
+use crate::clob::types::{Side, SignableOrder, SignatureType, SignedOrder, TickSize};
+use crate::error::{Error, Kind as ErrorKind, Status, Synchronization};
 use crate::types::Address;
 use crate::{
-    AMOY, POLYGON, Result, Timestamp, ToQueryParams as _, auth, contract_config,
-    derive_proxy_wallet, derive_safe_wallet,
+    AMOY, FromResponse, NoContent, POLYGON, RedirectPolicy, Result, Timestamp, ToQueryParams as _,
+    auth, contract_config, derive_proxy_wallet, derive_safe_wallet,
 };
 
 const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
 const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
 
-const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
-
 /// The type used to build a request to authenticate the inner [`Client<Unauthorized>`]. Calling
 /// `authenticate` on this will elevate that inner `client` into an [`Client<Authenticated<K>>`].
 pub struct AuthenticationBuilder<'signer, S: Signer, K: Kind = Normal> {
@@ -72,7 +85,8 @@ pub struct AuthenticationBuilder<'signer, S: Signer, K: Kind = Normal> {
     funder: Option<Address>,
     /// The optional [`SignatureType`], see `funder` for more information.
     signature_type: Option<SignatureType>,
-    /// The optional salt/seed generator for use in creating [`SignableOrder`]s
+    /// The optional salt/seed generator for use in creating [`SignableOrder`]s. Defaults to a
+    /// random salt when unset.
     salt_generator: Option<fn() -> u64>,
 }
 
@@ -101,6 +115,11 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
         self
     }
 
+    /// Overrides how order salts are generated.
+    ///
+    /// By default every order gets a random salt so that otherwise identical orders hash (and
+    /// sign) differently. A fixed generator such as `|| 1` makes signatures reproducible, which
+    /// is meant for known-answer tests and must not be used for live trading.
     #[must_use]
     pub fn salt_generator(mut self, salt_generator: fn() -> u64) -> Self {
         self.salt_generator = Some(salt_generator);
@@ -194,11 +213,12 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
 
         let state = Authenticated {
             address: self.signer.address(),
-            credentials,
+            credentials: CurrentCredentials::new(credentials),
             kind: self.kind,
         };
 
         Ok(Client {
+            headers: self.client.headers,
             inner: Arc::new(ClientInner {
                 state,
                 config: inner.config,
@@ -208,9 +228,17 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                token_index: inner.token_index,
                 funder,
                 signature_type: self.signature_type.unwrap_or(SignatureType::Eoa),
                 salt_generator: self.salt_generator.unwrap_or(generate_seed),
+                clock: inner.clock,
+                circuit_breaker: inner.circuit_breaker,
+                idempotency: inner.idempotency,
+                latency: inner.latency,
+                price_coalescer: inner.price_coalescer,
+                midpoint_coalescer: inner.midpoint_coalescer,
+                backpressure: inner.backpressure,
             }),
         })
     }
@@ -274,6 +302,9 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
 #[derive(Clone, Debug)]
 pub struct Client<S: State = Unauthenticated> {
     inner: Arc<ClientInner<S>>,
+    /// Headers merged over those of every request sent by this handle, see
+    /// [`Client::with_headers`]
+    headers: Option<Arc<HeaderMap>>,
 }
 
 impl Default for Client<Unauthenticated> {
@@ -284,21 +315,190 @@ impl Default for Client<Unauthenticated> {
 }
 
 /// Configuration for [`Client`]
-#[derive(Clone, Debug, Default, Builder)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Each flag is an independent opt-in set through the builder"
+)]
+#[derive(Clone, Debug, Builder)]
 pub struct Config {
     /// Whether the [`Client`] will use the server time provided by Polymarket when creating auth
     /// headers. This adds another round trip to the requests.
     #[builder(default)]
     use_server_time: bool,
+    /// When set, the [`Client`] fetches the server time at most once per interval and applies the
+    /// offset between server and local time to the timestamps of auth headers. This avoids auth
+    /// failures on machines with drifted clocks without a round trip per request. Ignored when
+    /// `use_server_time` is on. Defaults to `None`.
+    server_time_sync_interval: Option<Duration>,
     /// Override for the geoblock API host. Defaults to `https://polymarket.com`.
     /// This is primarily useful for testing.
     #[builder(into)]
     geoblock_host: Option<String>,
+    /// Whether to speak HTTP/2 immediately instead of negotiating it via ALPN. This saves a round
+    /// trip on connection setup, but requests will fail against servers that do not support
+    /// HTTP/2 (or h2c, for plain-text hosts).
+    #[builder(default)]
+    http2_prior_knowledge: bool,
+    /// Interval at which HTTP/2 PING frames are sent to keep idle connections warm. Defaults to
+    /// `None`, which disables keep-alive pings.
+    http2_keep_alive_interval: Option<Duration>,
+    /// Whether to set `TCP_NODELAY` on connections, disabling Nagle's algorithm. Defaults to
+    /// `true`.
+    #[builder(default = true)]
+    tcp_nodelay: bool,
+    /// How redirect responses are handled. Defaults to [`RedirectPolicy::None`], which surfaces
+    /// a redirect as an error rather than silently following a moved endpoint.
+    #[builder(default)]
+    redirect: RedirectPolicy,
+    /// When set, requests fail fast with [`Kind::CircuitOpen`](crate::error::Kind::CircuitOpen)
+    /// after repeated failures instead of reaching a struggling API. Defaults to `None`.
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    /// Batch requests (e.g. [`Client::prices`], [`Client::order_books`]) with more entries than
+    /// this are sent with a streamed JSON body, serialized one entry at a time, instead of being
+    /// buffered into a single string first. This lowers peak memory for very large batches.
+    /// Defaults to `None`, which always buffers.
+    batch_stream_threshold: Option<usize>,
+    /// Value of the `User-Agent` header sent with every request. Defaults to
+    /// [`DEFAULT_USER_AGENT`](crate::DEFAULT_USER_AGENT), which carries the crate version.
+    #[builder(into)]
+    user_agent: Option<String>,
+    /// How long the response of [`Client::post_order_idempotent`] and
+    /// [`Client::post_orders_idempotent`] is kept for retries with the same idempotency key.
+    /// Defaults to one minute.
+    #[builder(default = DEFAULT_IDEMPOTENCY_WINDOW)]
+    idempotency_window: Duration,
+    /// When set, a failed request is sent again for as long as this returns `true`. It is called
+    /// with the error and the number of the attempt that failed, starting at `1`, so that it can
+    /// both pick the errors worth retrying and cap the attempts. Retries are sent immediately,
+    /// without a delay, and each one goes through the `circuit_breaker`. Requests with a streamed
+    /// body cannot be replayed and are never retried. Defaults to `None`, which sends every
+    /// request once.
+    #[builder(with = |classifier: impl Fn(&Error, u32) -> bool + Send + Sync + 'static| {
+        RetryClassifier::new(classifier)
+    })]
+    should_retry: Option<RetryClassifier>,
+    /// When set, connections to the host name are made to the address instead of the one it
+    /// resolves to, while the `Host` header and TLS server name still carry the host name. This
+    /// pins requests to one backend instance behind a load balancer, e.g. to debug it. The port
+    /// of the request URL takes precedence, and a port of `0` uses the default port of the
+    /// scheme. Defaults to `None`.
+    resolve_override: Option<(String, SocketAddr)>,
+    /// When set, the response times of the latest this many requests per endpoint are kept for
+    /// [`Client::latency_stats`]. Defaults to `None`, which keeps none.
+    latency_window: Option<usize>,
+    /// When set, calls to [`Client::price`] and [`Client::midpoint`] made within this window of
+    /// each other are sent as one request to [`Client::prices`] and [`Client::midpoints`], and
+    /// each call gets its entry of the batch response. The first call of a batch waits for the
+    /// window before sending it. A token missing from the batch response is requested on its
+    /// own, and an error of the batch is returned to every call in it, with the same kind.
+    /// Defaults to `None`, which sends each call as it is made.
+    coalesce_window: Option<Duration>,
+    /// Whether a `429 Too Many Requests` with a `Retry-After` pauses every request to its host,
+    /// from this client and all of its clones, until the delay has elapsed. Requests made during
+    /// the pause wait for it to end instead of being rejected in turn, and retries allowed by
+    /// `should_retry` wait as well. Authenticated requests wait before they are signed, so that
+    /// their timestamp is not stale once sent. The rate-limited request itself still fails.
+    /// Defaults to `false`.
+    #[builder(default)]
+    backpressure: bool,
+    /// The longest pause `backpressure` makes for a single `429`, however far out its
+    /// `Retry-After` is. Defaults to one minute.
+    #[builder(default = DEFAULT_MAX_BACKPRESSURE_PAUSE)]
+    max_backpressure_pause: Duration,
+}
+
+/// Decides whether a failed request is retried, see the `should_retry` of [`Config`].
+#[derive(Clone)]
+pub struct RetryClassifier(Arc<ClassifierFn>);
+
+type ClassifierFn = dyn Fn(&Error, u32) -> bool + Send + Sync;
+
+impl RetryClassifier {
+    /// Wraps `classifier`, which is called with the error and the number of the failed attempt.
+    pub fn new<F>(classifier: F) -> Self
+    where
+        F: Fn(&Error, u32) -> bool + Send + Sync + 'static,
+    {
+        Self(Arc::new(classifier))
+    }
+
+    /// Returns `true` if the request whose `attempt` failed with `error` should be retried.
+    #[must_use]
+    pub fn should_retry(&self, error: &Error, attempt: u32) -> bool {
+        (self.0)(error, attempt)
+    }
+}
+
+impl fmt::Debug for RetryClassifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RetryClassifier(..)")
+    }
+}
+
+/// Settings of the circuit breaker enabled by the `circuit_breaker` of [`Config`].
+///
+/// The breaker opens after `failure_threshold` consecutive failures, i.e. failures to connect or
+/// send, timeouts and `5xx` responses. While open, requests are short-circuited with
+/// [`Error::circuit_open`] without being sent. Once `cooldown` has elapsed, it half-opens and
+/// lets a single probe request through: the breaker closes if the probe succeeds and opens
+/// again if it fails.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    pub failure_threshold: u32,
+    pub cooldown: Duration,
+}
+
+impl CircuitBreakerConfig {
+    #[must_use]
+    pub const fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold,
+            cooldown,
+        }
+    }
+}
+
+/// State of the circuit breaker of a [`Client`], see [`Client::circuit_state`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent as usual
+    Closed,
+    /// Requests are short-circuited until the cooldown has elapsed
+    Open,
+    /// The next request is sent as a probe that decides whether the breaker closes
+    HalfOpen,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl<S: config_builder::IsComplete> ConfigBuilder<S> {
+    /// Creates an unauthenticated client for `host` with the [`Config`] built so far, like
+    /// [`Client::new`], see [`Client::builder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the host or any option is invalid, or the HTTP client cannot be
+    /// created.
+    pub fn build_client(self, host: &str) -> Result<Client<Unauthenticated>> {
+        Client::new(host, self.build())
+    }
 }
 
 /// The default geoblock API host (separate from CLOB host)
 const DEFAULT_GEOBLOCK_HOST: &str = "https://polymarket.com";
 
+/// The default `idempotency_window` of [`Config`]
+const DEFAULT_IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(60);
+
+/// The default `max_backpressure_pause` of [`Config`]
+const DEFAULT_MAX_BACKPRESSURE_PAUSE: Duration = Duration::from_secs(60);
+
 #[derive(Debug)]
 struct ClientInner<S: State> {
     config: Config,
@@ -316,6 +516,8 @@ struct ClientInner<S: State> {
     neg_risk: DashMap<String, bool>,
     /// Local cache representing the fee rate in basis points per token ID
     fee_rate_bps: DashMap<String, u32>,
+    /// Local cache of the condition ID and token ID mappings
+    token_index: TokenIndex,
     /// The funder for this [`ClientInner`]. If funder is present, then `signature_type` cannot
     /// be [`SignatureType::Eoa`]. Conversely, if funder is absent, then `signature_type` cannot be
     /// [`SignatureType::Proxy`] or [`SignatureType::GnosisSafe`].
@@ -324,20 +526,412 @@ struct ClientInner<S: State> {
     signature_type: SignatureType,
     /// The salt/seed generator for use in creating [`SignableOrder`]s
     salt_generator: fn() -> u64,
+    /// Offset between server and local time, applied to auth header timestamps
+    clock: ServerClock,
+    /// Circuit breaker guarding requests, if enabled in `config`
+    circuit_breaker: Option<CircuitBreaker>,
+    /// Responses of recent idempotent order posts per idempotency key
+    idempotency: IdempotencyCache,
+    /// Response times per endpoint, if enabled in `config`
+    latency: Option<LatencyTracker>,
+    /// Pending [`Client::price`] calls per token ID and side, if coalescing is enabled in `config`
+    price_coalescer: Option<Coalescer<(String, Side), PricesResponse>>,
+    /// Pending [`Client::midpoint`] calls per token ID, if coalescing is enabled in `config`
+    midpoint_coalescer: Option<Coalescer<String, MidpointsResponse>>,
+    /// Hosts whose requests are paused after a `429`, if enabled in `config`
+    backpressure: Option<Backpressure>,
 }
 
 impl<S: State> ClientInner<S> {
+    /// Sends `request` with [`Self::throttled`], retrying it while the `should_retry` of
+    /// `config` allows.
+    async fn request<Response: FromResponse>(
+        &self,
+        request: Request,
+        headers: Option<HeaderMap>,
+    ) -> Result<Response> {
+        let Some(classifier) = &self.config.should_retry else {
+            return self.throttled(request, headers).await;
+        };
+
+        let mut attempt = 1;
+        loop {
+            let Some(replay) = request.try_clone() else {
+                return self.throttled(request, headers).await;
+            };
+
+            match self.throttled(replay, headers.clone()).await {
+                Err(e) if classifier.should_retry(&e, attempt) => attempt += 1,
+                result => return result,
+            }
+        }
+    }
+
+    /// Sends `request` with [`Self::send`] once its host is no longer paused, pausing it in turn
+    /// if the response is a `429`, when backpressure is enabled.
+    async fn throttled<Response: FromResponse>(
+        &self,
+        request: Request,
+        headers: Option<HeaderMap>,
+    ) -> Result<Response> {
+        let Some(backpressure) = &self.backpressure else {
+            return self.send(request, headers).await;
+        };
+
+        let url = request.url().clone();
+        backpressure.wait(&url).await;
+        let result = self.send(request, headers).await;
+        if let Err(e) = &result {
+            backpressure.record(&url, e);
+        }
+        result
+    }
+
+    /// Sends `request` with [`crate::request`], through the circuit breaker if one is enabled.
+    async fn send<Response: FromResponse>(
+        &self,
+        request: Request,
+        headers: Option<HeaderMap>,
+    ) -> Result<Response> {
+        let Some(breaker) = &self.circuit_breaker else {
+            return self.timed(request, headers).await;
+        };
+
+        breaker.acquire()?;
+        let result = self.timed(request, headers).await;
+        breaker.record(result.as_ref().err());
+        result
+    }
+
+    /// Sends `request` with [`crate::request`], recording its response time if enabled.
+    async fn timed<Response: FromResponse>(
+        &self,
+        request: Request,
+        headers: Option<HeaderMap>,
+    ) -> Result<Response> {
+        let Some(latency) = &self.latency else {
+            return crate::request(&self.client, request, headers).await;
+        };
+
+        let path = request.url().path().to_owned();
+        let started = Instant::now();
+        let result = crate::request(&self.client, request, headers).await;
+        latency.record(&path, started.elapsed());
+        result
+    }
+
     pub async fn server_time(&self) -> Result<Timestamp> {
         let request = self
             .client
             .request(Method::GET, format!("{}time", self.host))
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        self.request(request, None).await
+    }
+
+    /// Fetches the server time and stores its offset from the local clock, returning the offset in
+    /// seconds.
+    async fn sync_time(&self) -> Result<i64> {
+        let before = Utc::now().timestamp();
+        let server_time = self.server_time().await?;
+        let after = Utc::now().timestamp();
+
+        // Assume the server read its clock halfway through the round trip
+        let offset = server_time - before.midpoint(after);
+        self.clock.set(offset);
+
+        Ok(offset)
+    }
+
+    /// Returns the timestamp to use in auth headers, once any backpressure pause of the host has
+    /// ended, so that the headers are not signed long before they are sent.
+    async fn auth_timestamp(&self) -> Result<Timestamp> {
+        if let Some(backpressure) = &self.backpressure {
+            backpressure.wait(&self.host).await;
+        }
+
+        if self.config.use_server_time {
+            return self.server_time().await;
+        }
+
+        if let Some(interval) = self.config.server_time_sync_interval
+            && self.clock.is_stale(interval)
+        {
+            self.sync_time().await?;
+        }
+
+        Ok(Utc::now().timestamp() + self.clock.offset())
+    }
+}
+
+/// Puts every series of `histories` on the union of their timestamps, carrying each token's
+/// previous price forward into the gaps.
+fn align_price_histories(histories: &mut HashMap<String, Vec<PricePoint>>) {
+    let grid: BTreeSet<i64> = histories
+        .values()
+        .flat_map(|history| history.iter().map(|point| point.t))
+        .collect();
+
+    for history in histories.values_mut() {
+        history.sort_by_key(|point| point.t);
+
+        let mut points = history.iter().peekable();
+        let mut last = None;
+        let mut aligned = Vec::with_capacity(grid.len());
+        for &t in &grid {
+            while let Some(point) = points.next_if(|point| point.t <= t) {
+                last = Some(point.p);
+            }
+            if let Some(p) = last {
+                aligned.push(PricePoint { t, p });
+            }
+        }
+
+        *history = aligned;
+    }
+}
+
+/// Offset between the server clock and the local clock, and when it was last measured.
+#[derive(Debug, Default)]
+struct ServerClock {
+    offset: AtomicI64,
+    synced_at: Mutex<Option<Instant>>,
+}
+
+impl ServerClock {
+    fn offset(&self) -> i64 {
+        self.offset.load(Ordering::Relaxed)
+    }
+
+    fn set(&self, offset: i64) {
+        self.offset.store(offset, Ordering::Relaxed);
+        // The guarded value is a plain timestamp, so a poisoned lock holds no broken state
+        *self
+            .synced_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Instant::now());
+    }
+
+    fn is_stale(&self, interval: Duration) -> bool {
+        self.synced_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_none_or(|synced_at| synced_at.elapsed() >= interval)
+    }
+}
+
+/// Circuit breaker state shared by all clones of a [`Client`].
+#[derive(Debug)]
+struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    /// Consecutive failures while closed
+    failures: u32,
+    /// When the breaker last opened, or `None` while closed
+    opened_at: Option<Instant>,
+    /// When the probe request of the half-open breaker was sent
+    probe_sent_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::default(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        // The state is a few plain counters, so it is still consistent after a panic
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn state(&self) -> CircuitState {
+        let state = self.lock();
+        match state.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() < self.config.cooldown => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Returns an error if a request may not be sent right now. The first request after the
+    /// cooldown becomes the probe; a probe that never reports back (e.g. because its future was
+    /// dropped) is replaced after another cooldown.
+    fn acquire(&self) -> Result<()> {
+        let mut state = self.lock();
+        let Some(opened_at) = state.opened_at else {
+            return Ok(());
+        };
+
+        let cooldown = self.config.cooldown;
+        let retry_in = cooldown.saturating_sub(opened_at.elapsed());
+        if !retry_in.is_zero() {
+            return Err(Error::circuit_open(retry_in));
+        }
+
+        if let Some(sent_at) = state.probe_sent_at
+            && sent_at.elapsed() < cooldown
+        {
+            return Err(Error::circuit_open(cooldown - sent_at.elapsed()));
+        }
+
+        state.probe_sent_at = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Records the outcome of a request allowed by [`CircuitBreaker::acquire`].
+    fn record(&self, error: Option<&Error>) {
+        let mut state = self.lock();
+        if !error.is_some_and(is_breaker_failure) {
+            *state = BreakerState::default();
+            return;
+        }
+
+        state.failures = state.failures.saturating_add(1);
+        if state.opened_at.is_some() || state.failures >= self.config.failure_threshold {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(failures = state.failures, "Circuit breaker opened");
+
+            state.opened_at = Some(Instant::now());
+            state.probe_sent_at = None;
+        }
     }
 }
 
-impl ClientInner<Unauthenticated> {
+/// Responses of idempotent order posts, shared by all clones of a [`Client`].
+///
+/// Each idempotency key has its own async lock, held while its post is in flight, so that a
+/// concurrent retry waits for the first attempt and then reuses its response.
+#[derive(Debug, Default)]
+struct IdempotencyCache {
+    entries: DashMap<String, Arc<futures::lock::Mutex<Option<CachedPost>>>>,
+}
+
+#[derive(Debug)]
+struct CachedPost {
+    posted_at: Instant,
+    response: CachedResponse,
+}
+
+#[derive(Clone, Debug)]
+enum CachedResponse {
+    Order(PostOrderResponse),
+    Orders(Vec<PostOrderResponse>),
+}
+
+impl IdempotencyCache {
+    /// Returns the response cached for `key` if it was posted less than `window` ago, and
+    /// otherwise awaits `post` and caches its response. Errors are not cached, so a failed post
+    /// can be retried with the same key.
+    async fn run<Fut>(&self, key: &str, window: Duration, post: Fut) -> Result<CachedResponse>
+    where
+        Fut: Future<Output = Result<CachedResponse>>,
+    {
+        // Entries in flight are locked and kept, so only settled, expired responses are dropped
+        self.entries.retain(|_, entry| {
+            entry.try_lock().is_none_or(|cached| {
+                cached
+                    .as_ref()
+                    .is_none_or(|cached| cached.posted_at.elapsed() < window)
+            })
+        });
+
+        let entry = Arc::clone(&self.entries.entry(key.to_owned()).or_default());
+        let mut cached = entry.lock().await;
+        if let Some(cached) = cached.as_ref()
+            && cached.posted_at.elapsed() < window
+        {
+            return Ok(cached.response.clone());
+        }
+
+        let response = post.await?;
+        *cached = Some(CachedPost {
+            posted_at: Instant::now(),
+            response: response.clone(),
+        });
+
+        Ok(response)
+    }
+}
+
+/// Returns the headers carrying `idempotency_key` for an idempotent order post.
+fn idempotency_headers(idempotency_key: &str) -> Result<HeaderMap> {
+    let value = HeaderValue::from_str(idempotency_key)
+        .map_err(|e| Error::invalid_input_with_source(idempotency_key, e))?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert("Idempotency-Key", value);
+    Ok(headers)
+}
+
+/// Returns `true` if `error` suggests the API is unhealthy: failures to connect or send,
+/// timeouts, and `5xx` responses. Client errors such as `4xx` responses and bodies that fail to
+/// parse do not count.
+fn is_breaker_failure(error: &Error) -> bool {
+    match error.kind() {
+        ErrorKind::Internal => error
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(|e| e.is_connect() || e.is_timeout() || e.is_request()),
+        ErrorKind::Status => error
+            .downcast_ref::<Status>()
+            .is_some_and(|status| status.status_code.is_server_error()),
+        _ => false,
+    }
+}
+
+/// Cache of the mapping between condition IDs and the token IDs of their outcomes.
+///
+/// Every [`Client`] keeps one, filled by [`Client::token_ids`] and [`Client::condition_id`] and
+/// shared by all clones of that client. Cloning a [`TokenIndex`] shares the underlying cache.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default)]
+pub struct TokenIndex {
+    token_ids: Arc<DashMap<String, Vec<String>>>,
+    condition_ids: Arc<DashMap<String, String>>,
+}
+
+impl TokenIndex {
+    /// Returns the cached token IDs of the market with `condition_id`.
+    #[must_use]
+    pub fn token_ids(&self, condition_id: &str) -> Option<Vec<String>> {
+        self.token_ids.get(condition_id).map(|ids| ids.clone())
+    }
+
+    /// Returns the cached condition ID of the market that `token_id` belongs to.
+    #[must_use]
+    pub fn condition_id(&self, token_id: &str) -> Option<String> {
+        self.condition_ids.get(token_id).map(|id| id.clone())
+    }
+
+    /// Caches both directions of the mapping for `market`.
+    pub fn insert(&self, market: &MarketResponse) {
+        let token_ids: Vec<String> = market
+            .tokens
+            .iter()
+            .map(|token| token.token_id.clone())
+            .collect();
+
+        for token_id in &token_ids {
+            self.condition_ids
+                .insert(token_id.clone(), market.condition_id.clone());
+        }
+        self.token_ids
+            .insert(market.condition_id.clone(), token_ids);
+    }
+
+    /// Removes all cached mappings.
+    pub fn clear(&self) {
+        self.token_ids.clear();
+        self.condition_ids.clear();
+    }
+}
+
+impl<St: State> ClientInner<St> {
     pub async fn create_api_key<S: Signer>(
         &self,
         signer: &S,
@@ -349,7 +943,7 @@ impl ClientInner<Unauthenticated> {
             .build()?;
         let headers = self.create_headers(signer, nonce).await?;
 
-        crate::request(&self.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn derive_api_key<S: Signer>(
@@ -363,7 +957,7 @@ impl ClientInner<Unauthenticated> {
             .build()?;
         let headers = self.create_headers(signer, nonce).await?;
 
-        crate::request(&self.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     async fn create_or_derive_api_key<S: Signer>(
@@ -382,11 +976,7 @@ impl ClientInner<Unauthenticated> {
             "Chain id not set, be sure to provide one on the signer",
         ))?;
 
-        let timestamp = if self.config.use_server_time {
-            self.server_time().await?
-        } else {
-            Utc::now().timestamp()
-        };
+        let timestamp = self.auth_timestamp().await?;
 
         auth::l1::create_headers(signer, chain_id, timestamp, nonce).await
     }
@@ -398,10 +988,90 @@ impl<S: State> Client<S> {
         &self.inner.host
     }
 
+    /// Returns a handle to this client that sends `headers` with every request, e.g. a correlation
+    /// id for a single call: `client.with_headers(headers).order_book(&request)`.
+    ///
+    /// `headers` are merged over the authentication and client default headers, replacing any
+    /// header of the same name, and over those of earlier `with_headers` calls on the handle. The
+    /// handle shares the connection pool, caches and state of this client.
+    #[must_use]
+    pub fn with_headers(&self, headers: HeaderMap) -> Self {
+        let headers = match &self.headers {
+            Some(current) => {
+                let mut merged = HeaderMap::clone(current);
+                merged.extend(headers);
+                merged
+            }
+            None => headers,
+        };
+
+        Self {
+            inner: Arc::clone(&self.inner),
+            headers: Some(Arc::new(headers)),
+        }
+    }
+
     pub fn invalidate_internal_caches(&self) {
         self.inner.tick_sizes.clear();
         self.inner.fee_rate_bps.clear();
         self.inner.neg_risk.clear();
+        self.inner.token_index.clear();
+    }
+
+    /// Returns the current state of the circuit breaker, which is always
+    /// [`CircuitState::Closed`] unless the `circuit_breaker` of [`Config`] is set. The breaker is
+    /// shared by all clones of this client.
+    #[must_use]
+    pub fn circuit_state(&self) -> CircuitState {
+        self.inner
+            .circuit_breaker
+            .as_ref()
+            .map_or(CircuitState::Closed, CircuitBreaker::state)
+    }
+
+    /// Returns the response-time percentiles of the latest requests to the endpoint of
+    /// `path_pattern`, e.g. `/book` or `/markets/{id}`, or `None` if none were recorded. A path
+    /// with IDs, such as `/markets/0x…`, is collapsed to its pattern first, see
+    /// [`path_pattern`](crate::clob::latency::path_pattern).
+    ///
+    /// Response times are only kept when the `latency_window` of [`Config`] is set. They cover
+    /// every request that got a response or a transport error, and are shared by all clones of
+    /// this client.
+    #[must_use]
+    pub fn latency_stats(&self, path_pattern: &str) -> Option<LatencyStats> {
+        self.inner.latency.as_ref()?.stats(path_pattern)
+    }
+
+    /// Returns the cache used by [`Client::token_ids`] and [`Client::condition_id`].
+    #[must_use]
+    pub fn token_index(&self) -> &TokenIndex {
+        &self.inner.token_index
+    }
+
+    /// Opens a pooled connection to the host ahead of time, so the first real request does not
+    /// pay for TCP and TLS setup. Sends a `HEAD` request and accepts any response status; only a
+    /// failure to connect is returned as an error.
+    ///
+    /// Construction never connects, so call this once after [`Client::new`] in latency-sensitive
+    /// code. Failing to warm up is harmless, as requests connect on demand anyway.
+    pub async fn warm_up(&self) -> Result<()> {
+        let request = self
+            .client()
+            .request(Method::HEAD, self.host().to_owned())
+            .build()?;
+
+        let result = self.client().execute(request).await;
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(response) => {
+                tracing::debug!(host = %self.host(), status = %response.status(), "connection warmed up");
+            }
+            Err(e) => tracing::warn!(host = %self.host(), error = %e, "connection warm-up failed"),
+        }
+
+        result?;
+        Ok(())
     }
 
     pub async fn ok(&self) -> Result<String> {
@@ -410,34 +1080,99 @@ impl<S: State> Client<S> {
             .request(Method::GET, self.host().to_owned())
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn server_time(&self) -> Result<Timestamp> {
         self.inner.server_time().await
     }
 
+    /// Fetches the server time and applies its offset from the local clock to the timestamps of
+    /// subsequent auth headers. Returns the offset in seconds.
+    ///
+    /// With [`Config`]'s `server_time_sync_interval` set this happens automatically.
+    pub async fn sync_time(&self) -> Result<i64> {
+        self.inner.sync_time().await
+    }
+
+    /// Returns the offset in seconds between the server clock and the local clock, as last
+    /// measured by [`Client::sync_time`]. This is `0` until the time has been synced.
+    #[must_use]
+    pub fn time_offset(&self) -> i64 {
+        self.inner.clock.offset()
+    }
+
     pub async fn midpoint(&self, request: &MidpointRequest) -> Result<MidpointResponse> {
+        if let Some(coalescer) = &self.inner.midpoint_coalescer {
+            let batch = coalescer
+                .run(request.token_id.clone(), |token_ids| async move {
+                    let requests: Vec<_> = token_ids
+                        .into_iter()
+                        .map(|token_id| MidpointRequest::builder().token_id(token_id).build())
+                        .collect();
+                    self.midpoints(&requests).await
+                })
+                .await;
+
+            match batch {
+                Some(Ok(midpoints)) => {
+                    if let Some(&mid) = midpoints.midpoints.get(&request.token_id) {
+                        return Ok(MidpointResponse::builder().mid(mid).build());
+                    }
+                }
+                Some(Err(e)) => return Err(coalesce::into_error(e)),
+                None => {}
+            }
+        }
+
         let request = self
             .client()
             .request(Method::GET, format!("{}midpoint", self.host()))
             .query(&[("token_id", request.token_id.as_str())])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn midpoints(&self, requests: &[MidpointRequest]) -> Result<MidpointsResponse> {
-        let request = self
-            .client()
-            .request(Method::POST, format!("{}midpoints", self.host()))
-            .json(requests)
-            .build()?;
+        let request = self.batch_request("midpoints", requests)?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn price(&self, request: &PriceRequest) -> Result<PriceResponse> {
+        if let Some(coalescer) = &self.inner.price_coalescer {
+            let key = (request.token_id.clone(), request.side);
+            let batch = coalescer
+                .run(key, |keys| async move {
+                    let requests: Vec<_> = keys
+                        .into_iter()
+                        .map(|(token_id, side)| {
+                            PriceRequest::builder()
+                                .token_id(token_id)
+                                .side(side)
+                                .build()
+                        })
+                        .collect();
+                    self.prices(&requests).await
+                })
+                .await;
+
+            match batch {
+                Some(Ok(prices)) => {
+                    let price = prices
+                        .prices
+                        .as_ref()
+                        .and_then(|prices| prices.get(&request.token_id)?.get(&request.side));
+                    if let Some(&price) = price {
+                        return Ok(PriceResponse::builder().price(price).build());
+                    }
+                }
+                Some(Err(e)) => return Err(coalesce::into_error(e)),
+                None => {}
+            }
+        }
+
         let request = self
             .client()
             .request(Method::GET, format!("{}price", self.host()))
@@ -447,17 +1182,13 @@ impl<S: State> Client<S> {
             ])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn prices(&self, requests: &[PriceRequest]) -> Result<PricesResponse> {
-        let request = self
-            .client()
-            .request(Method::POST, format!("{}prices", self.host()))
-            .json(requests)
-            .build()?;
+        let request = self.batch_request("prices", requests)?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn all_prices(&self) -> Result<PricesResponse> {
@@ -466,7 +1197,7 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}prices", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn price_history(
@@ -493,7 +1224,79 @@ impl<S: State> Client<S> {
             req = req.query(&[("fidelity", fidelity)]);
         }
 
-        crate::request(&self.inner.client, req.build()?, None).await
+        self.request(req.build()?, None).await
+    }
+
+    /// Gets the midpoint of the token `token_id` over time, ordered by timestamp, e.g. to chart
+    /// it. The `market` of `request` is replaced by `token_id`, while its time range and
+    /// fidelity apply.
+    ///
+    /// There is no midpoint history endpoint, but the CLOB computes the price history of a token
+    /// from the midpoint of its order book, so this is that history from
+    /// [`Client::price_history`]. It is not combined with the history of the complementary
+    /// token, whose midpoint already mirrors this one. Points sharing a timestamp are collapsed
+    /// into the last one returned.
+    pub async fn midpoint_history(
+        &self,
+        token_id: &str,
+        request: &PriceHistoryRequest,
+    ) -> Result<Vec<PricePoint>> {
+        let request = PriceHistoryRequest {
+            market: token_id.to_owned(),
+            time_range: request.time_range,
+            fidelity: request.fidelity,
+        };
+        let mut history = self.price_history(&request).await?.history;
+
+        // Stable, so that the last point of a timestamp stays last
+        history.sort_by_key(|point| point.t);
+        history.reverse();
+        history.dedup_by_key(|point| point.t);
+        history.reverse();
+
+        Ok(history)
+    }
+
+    /// Gets the price history of several tokens, running up to `concurrency` requests at a time.
+    /// The `market` of `request` is replaced by each token ID, while its time range and fidelity
+    /// apply to all of them.
+    ///
+    /// With `align` set, every series is put on the union of all timestamps, filling each gap
+    /// with the token's previous price. Timestamps before a token's first point are left out of
+    /// its series, as there is no price to carry forward.
+    ///
+    /// Returns the first error of any request, or a validation error if `concurrency` is zero.
+    pub async fn price_history_multi<ID: AsRef<str>>(
+        &self,
+        token_ids: &[ID],
+        request: &PriceHistoryRequest,
+        concurrency: usize,
+        align: bool,
+    ) -> Result<HashMap<String, Vec<PricePoint>>> {
+        if concurrency == 0 {
+            return Err(Error::validation("concurrency must be greater than zero"));
+        }
+
+        let mut histories: HashMap<String, Vec<PricePoint>> = stream::iter(token_ids)
+            .map(|token_id| async move {
+                let token_id = token_id.as_ref();
+                let request = PriceHistoryRequest {
+                    market: token_id.to_owned(),
+                    time_range: request.time_range,
+                    fidelity: request.fidelity,
+                };
+                let response = self.price_history(&request).await?;
+                Ok::<_, Error>((token_id.to_owned(), response.history))
+            })
+            .buffer_unordered(concurrency)
+            .try_collect()
+            .await?;
+
+        if align {
+            align_price_histories(&mut histories);
+        }
+
+        Ok(histories)
     }
 
     pub async fn spread(&self, request: &SpreadRequest) -> Result<SpreadResponse> {
@@ -503,17 +1306,13 @@ impl<S: State> Client<S> {
             .query(&[("token_id", request.token_id.as_str())])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn spreads(&self, requests: &[SpreadRequest]) -> Result<SpreadsResponse> {
-        let request = self
-            .client()
-            .request(Method::POST, format!("{}spreads", self.host()))
-            .json(requests)
-            .build()?;
+        let request = self.batch_request("spreads", requests)?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn tick_size(&self, token_id: &str) -> Result<TickSizeResponse> {
@@ -534,8 +1333,10 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id)])
             .build()?;
 
-        let response =
-            crate::request::<TickSizeResponse>(&self.inner.client, request, None).await?;
+        let response = self
+            .inner
+            .request::<TickSizeResponse>(request, None)
+            .await?;
 
         self.inner
             .tick_sizes
@@ -565,7 +1366,7 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id)])
             .build()?;
 
-        let response = crate::request::<NegRiskResponse>(&self.inner.client, request, None).await?;
+        let response = self.request::<NegRiskResponse>(request, None).await?;
 
         self.inner
             .neg_risk
@@ -595,7 +1396,7 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id)])
             .build()?;
 
-        let response = crate::request::<FeeRateResponse>(&self.inner.client, request, None).await?;
+        let response = self.request::<FeeRateResponse>(request, None).await?;
 
         self.inner
             .fee_rate_bps
@@ -660,7 +1461,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn order_book(
@@ -673,20 +1474,16 @@ impl<S: State> Client<S> {
             .query(&[("token_id", request.token_id.as_str())])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn order_books(
         &self,
         requests: &[OrderBookSummaryRequest],
     ) -> Result<Vec<OrderBookSummaryResponse>> {
-        let request = self
-            .client()
-            .request(Method::POST, format!("{}books", self.host()))
-            .json(requests)
-            .build()?;
+        let request = self.batch_request("books", requests)?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn last_trade_price(
@@ -699,20 +1496,35 @@ impl<S: State> Client<S> {
             .query(&[("token_id", request.token_id.as_str())])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
+    }
+
+    /// Returns the latest trades of the market with `condition_id` from its public activity
+    /// feed, newest first, at most `limit` of them if set. This does not require
+    /// authentication, unlike [`Client::trades`], which only returns the trades of the user.
+    pub async fn market_trades(
+        &self,
+        condition_id: &str,
+        limit: Option<u32>,
+    ) -> Result<Vec<PublicTrade>> {
+        let mut request = self.client().request(
+            Method::GET,
+            format!("{}live-activity/events/{condition_id}", self.host()),
+        );
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", limit)]);
+        }
+
+        self.request(request.build()?, None).await
     }
 
     pub async fn last_trades_prices(
         &self,
         token_ids: &[LastTradePriceRequest],
     ) -> Result<Vec<LastTradesPricesResponse>> {
-        let request = self
-            .client()
-            .request(Method::GET, format!("{}last-trades-prices", self.host()))
-            .json(token_ids)
-            .build()?;
+        let request = self.batch_request("last-trades-prices", token_ids)?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn market(&self, condition_id: &str) -> Result<MarketResponse> {
@@ -724,24 +1536,135 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
-    pub async fn markets(&self, next_cursor: Option<String>) -> Result<Page<MarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+    /// Gets several markets by condition ID, running up to `concurrency` requests at a time.
+    ///
+    /// The returned vector has one entry per ID, in the same order as `condition_ids`, so a failed
+    /// lookup (e.g. a `404` for an unknown market) does not affect the others. Each request counts
+    /// against the API rate limit, so choose `concurrency` with that limit in mind.
+    ///
+    /// Returns a validation error without making any request if `concurrency` is zero or an ID is
+    /// not a `0x`-prefixed 32-byte hex string.
+    pub async fn markets_by_condition_ids<Id: AsRef<str>>(
+        &self,
+        condition_ids: &[Id],
+        concurrency: usize,
+    ) -> Result<Vec<Result<MarketResponse>>> {
+        if concurrency == 0 {
+            return Err(Error::validation("concurrency must be greater than zero"));
+        }
+        if let Some(invalid) = condition_ids
+            .iter()
+            .map(AsRef::as_ref)
+            .find(|id| !is_condition_id(id))
+        {
+            return Err(Error::validation(format!(
+                "{invalid:?} is not a condition ID, expected 0x followed by 64 hex digits"
+            )));
+        }
+
+        let results = stream::iter(condition_ids)
+            .map(|id| self.market(id.as_ref()))
+            .buffered(concurrency)
+            .collect()
+            .await;
+
+        Ok(results)
+    }
+
+    /// Returns the token IDs of the market with `condition_id`, fetching the market on the first
+    /// lookup and serving later ones from the [`TokenIndex`].
+    pub async fn token_ids(&self, condition_id: &str) -> Result<Vec<String>> {
+        if let Some(token_ids) = self.inner.token_index.token_ids(condition_id) {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(condition_id = %condition_id, "cache hit: token_ids");
+            return Ok(token_ids);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(condition_id = %condition_id, "cache miss: token_ids");
+
+        let market = self.market(condition_id).await?;
+        self.inner.token_index.insert(&market);
+
+        Ok(market
+            .tokens
+            .into_iter()
+            .map(|token| token.token_id)
+            .collect())
+    }
+
+    /// Returns the condition ID of the market that `token_id` belongs to, fetching its order book
+    /// on the first lookup and serving later ones from the [`TokenIndex`].
+    pub async fn condition_id(&self, token_id: &str) -> Result<String> {
+        if let Some(condition_id) = self.inner.token_index.condition_id(token_id) {
+            #[cfg(feature = "tracing")]
+            tracing::trace!(token_id = %token_id, "cache hit: condition_id");
+            return Ok(condition_id);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(token_id = %token_id, "cache miss: condition_id");
+
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(token_id)
+            .build();
+        let book = self.order_book(&request).await?;
+        self.inner
+            .token_index
+            .condition_ids
+            .insert(token_id.to_owned(), book.market.clone());
+
+        Ok(book.market)
+    }
+
+    pub async fn markets(&self, next_cursor: Option<Cursor>) -> Result<Page<MarketResponse>> {
+        self.markets_with_filter(&MarketsRequest::default(), next_cursor)
+            .await
+    }
+
+    /// Like [`Client::markets`], but only returns the markets matching the filters of `request`,
+    /// which are applied by the server.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt as _;
+    /// use polymarket_client_sdk::clob::Client;
+    /// use polymarket_client_sdk::clob::types::request::MarketsRequest;
+    ///
+    /// # async fn example() -> polymarket_client_sdk::Result<()> {
+    /// let client = Client::default();
+    /// let request = MarketsRequest::builder().active(true).closed(false).build();
+    ///
+    /// let markets: Vec<_> = client
+    ///     .stream_data(|client, cursor| client.markets_with_filter(&request, cursor))
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn markets_with_filter(
+        &self,
+        request: &MarketsRequest,
+        next_cursor: Option<Cursor>,
+    ) -> Result<Page<MarketResponse>> {
+        let params = request.query_params(next_cursor.as_ref().map(Cursor::as_str));
         let request = self
             .client()
-            .request(Method::GET, format!("{}markets{cursor}", self.host()))
+            .request(Method::GET, format!("{}markets{params}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn sampling_markets(
         &self,
-        next_cursor: Option<String>,
+        next_cursor: Option<Cursor>,
     ) -> Result<Page<MarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        let cursor = ().query_params(next_cursor.as_ref().map(Cursor::as_str));
         let request = self
             .client()
             .request(
@@ -750,14 +1673,14 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn simplified_markets(
         &self,
-        next_cursor: Option<String>,
+        next_cursor: Option<Cursor>,
     ) -> Result<Page<SimplifiedMarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        let cursor = ().query_params(next_cursor.as_ref().map(Cursor::as_str));
         let request = self
             .client()
             .request(
@@ -766,14 +1689,14 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
     }
 
     pub async fn sampling_simplified_markets(
         &self,
-        next_cursor: Option<String>,
+        next_cursor: Option<Cursor>,
     ) -> Result<Page<SimplifiedMarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        let cursor = ().query_params(next_cursor.as_ref().map(Cursor::as_str));
         let request = self
             .client()
             .request(
@@ -782,7 +1705,30 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.request(request, None).await
+    }
+
+    /// Like [`Client::sampling_simplified_markets`], but only returns the markets whose rewards
+    /// pass `filter`, e.g. to find the best paying markets to provide liquidity to.
+    ///
+    /// The filter is applied to the fetched page, so a page may come back with fewer markets
+    /// than its `limit`, or none, while later pages still have matches. Its `count` is that of
+    /// the filtered markets, and its `next_cursor` is unchanged.
+    pub async fn sampling_simplified_markets_with_filter(
+        &self,
+        filter: &SamplingFilter,
+    ) -> Result<Page<SimplifiedMarketResponse>> {
+        let mut page = self
+            .sampling_simplified_markets(filter.next_cursor.clone())
+            .await?;
+
+        if let Some(min_daily_rate) = filter.min_daily_rate {
+            page.data
+                .retain(|market| market.rewards.total_daily_rate() >= min_daily_rate);
+            page.count = u64::try_from(page.data.len()).unwrap_or(u64::MAX);
+        }
+
+        Ok(page)
     }
 
     /// Returns a stream of results, using `self` to repeatedly invoke the provided closure,
@@ -793,12 +1739,12 @@ impl<S: State> Client<S> {
         call: Call,
     ) -> impl Stream<Item = Result<Data>> + 'client
     where
-        Call: Fn(&'client Client<S>, Option<String>) -> Fut + 'client,
+        Call: Fn(&'client Client<S>, Option<Cursor>) -> Fut + 'client,
         Fut: Future<Output = Result<Page<Data>>> + 'client,
         Data: 'client,
     {
         try_stream! {
-            let mut cursor: Option<String> = None;
+            let mut cursor: Option<Cursor> = None;
 
             loop {
                 let page = call(self, mem::take(&mut cursor)).await?;
@@ -807,11 +1753,12 @@ impl<S: State> Client<S> {
                     yield item
                 }
 
-                if page.next_cursor == TERMINAL_CURSOR {
+                let next = Cursor::from(page.next_cursor);
+                if next.is_end() {
                     break;
                 }
 
-                cursor = Some(page.next_cursor);
+                cursor = Some(next);
             }
         }
     }
@@ -819,39 +1766,171 @@ impl<S: State> Client<S> {
     fn client(&self) -> &ReqwestClient {
         &self.inner.client
     }
+
+    /// Sends `request` through the layers of the inner client, with the headers of
+    /// [`Client::with_headers`] merged over `headers`, or over those of `request` if there are
+    /// none.
+    async fn request<Response: FromResponse>(
+        &self,
+        mut request: Request,
+        mut headers: Option<HeaderMap>,
+    ) -> Result<Response> {
+        let Some(extra) = &self.headers else {
+            return self.inner.request(request, headers).await;
+        };
+
+        headers
+            .as_mut()
+            .unwrap_or_else(|| request.headers_mut())
+            .extend(HeaderMap::clone(extra));
+
+        self.inner.request(request, headers).await
+    }
+
+    /// Builds a `POST` of `items` to `path` as a JSON array, streamed if the batch is larger than
+    /// the `batch_stream_threshold` of [`Config`].
+    /// Buffered batches are serialized from the borrowed slice; only a streamed batch copies its
+    /// entries, as the body has to own them while it is sent.
+    fn batch_request<T: Serialize + Clone + Send + Sync + 'static>(
+        &self,
+        path: &str,
+        items: &[T],
+    ) -> Result<Request> {
+        let builder = self
+            .client()
+            .request(Method::POST, format!("{}{path}", self.host()));
+
+        let request = match self.inner.config.batch_stream_threshold {
+            Some(threshold) if items.len() > threshold => builder
+                .header(CONTENT_TYPE, "application/json")
+                .body(streamed_json_array(items.to_vec())),
+            _ => builder.json(items),
+        };
+
+        Ok(request.build()?)
+    }
+}
+
+/// Returns `true` if `id` is a `0x`-prefixed 32-byte hex string.
+fn is_condition_id(id: &str) -> bool {
+    id.strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+/// Checks that the `resolve_override` of [`Config`] maps a host name to an address that can be
+/// connected to.
+fn validate_resolve_override(domain: &str, addr: SocketAddr) -> Result<()> {
+    let parsed = crate::parse_host(&format!("https://{domain}"))?;
+    if parsed.host_str() != Some(domain) {
+        return Err(Error::invalid_host(
+            domain,
+            "resolve override expects a bare host name",
+        ));
+    }
+    if addr.ip().is_unspecified() {
+        return Err(Error::validation(format!(
+            "resolve override for {domain} points to unspecified address {addr}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Returns a body that serializes `items` as a JSON array lazily, one entry per chunk.
+fn streamed_json_array<T: Serialize + Send + Sync + 'static>(items: Vec<T>) -> Body {
+    let entries = items.into_iter().enumerate().map(|(index, item)| {
+        let mut chunk = if index == 0 { Vec::new() } else { vec![b','] };
+        serde_json::to_writer(&mut chunk, &item)?;
+        Ok(Bytes::from(chunk))
+    });
+    let chunks = iter::once(Ok(Bytes::from_static(b"[")))
+        .chain(entries)
+        .chain(iter::once(Ok(Bytes::from_static(b"]"))))
+        .map(|chunk: serde_json::Result<Bytes>| chunk.map(Frame::data));
+
+    Body::wrap(StreamBody::new(stream::iter(chunks)))
 }
 
 impl Client<Unauthenticated> {
+    /// Returns a [`Config`] builder that creates the client once done, with
+    /// [`build_client`](ConfigBuilder::build_client).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use polymarket_client_sdk::clob::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .use_server_time(true)
+    ///     .server_time_sync_interval(Duration::from_secs(300))
+    ///     .build_client("https://clob.polymarket.com")?;
+    /// # Ok::<(), polymarket_client_sdk::error::Error>(())
+    /// ```
+    pub fn builder() -> ConfigBuilder {
+        Config::builder()
+    }
+
     pub fn new(host: &str, config: Config) -> Result<Client<Unauthenticated>> {
         let mut headers = HeaderMap::new();
 
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
+        headers.insert("User-Agent", crate::user_agent(config.user_agent.clone())?);
         headers.insert("Accept", HeaderValue::from_static("*/*"));
         headers.insert("Connection", HeaderValue::from_static("keep-alive"));
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
 
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+        let mut builder = ReqwestClient::builder()
+            .default_headers(headers)
+            .tcp_nodelay(config.tcp_nodelay)
+            .redirect(config.redirect.to_reqwest());
+        if config.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if let Some(interval) = config.http2_keep_alive_interval {
+            builder = builder
+                .http2_keep_alive_interval(interval)
+                .http2_keep_alive_while_idle(true);
+        }
+        if let Some((domain, addr)) = &config.resolve_override {
+            validate_resolve_override(domain, *addr)?;
+            builder = builder.resolve(domain, *addr);
+        }
+        let client = builder.build()?;
 
-        let geoblock_host = Url::parse(
+        let geoblock_host = crate::parse_host(
             config
                 .geoblock_host
                 .as_deref()
                 .unwrap_or(DEFAULT_GEOBLOCK_HOST),
         )?;
+        let circuit_breaker = config.circuit_breaker.map(CircuitBreaker::new);
+        let latency = config.latency_window.map(LatencyTracker::new);
 
         Ok(Self {
+            headers: None,
             inner: Arc::new(ClientInner {
-                config,
-                host: Url::parse(host)?,
+                host: crate::parse_host(host)?,
                 geoblock_host,
                 client,
                 tick_sizes: DashMap::new(),
                 neg_risk: DashMap::new(),
                 fee_rate_bps: DashMap::new(),
+                token_index: TokenIndex::default(),
                 state: Unauthenticated,
                 funder: None,
                 signature_type: SignatureType::Eoa,
                 salt_generator: generate_seed,
+                clock: ServerClock::default(),
+                circuit_breaker,
+                idempotency: IdempotencyCache::default(),
+                latency,
+                price_coalescer: config.coalesce_window.map(Coalescer::new),
+                midpoint_coalescer: config.coalesce_window.map(Coalescer::new),
+                backpressure: config
+                    .backpressure
+                    .then(|| Backpressure::new(config.max_backpressure_pause)),
+                config,
             }),
         })
     }
@@ -909,6 +1988,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     pub fn deauthenticate(self) -> Result<Client<Unauthenticated>> {
         let inner = Arc::into_inner(self.inner).ok_or(Synchronization)?;
         Ok(Client::<Unauthenticated> {
+            headers: self.headers,
             inner: Arc::new(ClientInner {
                 state: Unauthenticated,
                 host: inner.host,
@@ -918,10 +1998,18 @@ impl<K: Kind> Client<Authenticated<K>> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                token_index: inner.token_index,
                 // Reset the order parameters that were previously stored on the client
                 funder: None,
                 signature_type: SignatureType::Eoa,
                 salt_generator: generate_seed,
+                clock: inner.clock,
+                circuit_breaker: inner.circuit_breaker,
+                idempotency: inner.idempotency,
+                latency: inner.latency,
+                price_coalescer: inner.price_coalescer,
+                midpoint_coalescer: inner.midpoint_coalescer,
+                backpressure: inner.backpressure,
             }),
         })
     }
@@ -945,7 +2033,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn delete_api_key(&self) -> Result<serde_json::Value> {
@@ -955,7 +2043,96 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
+    }
+
+    /// Returns how long ago the current API credentials were obtained, i.e. when this client was
+    /// authenticated or when its API key was last rotated.
+    #[must_use]
+    pub fn api_key_age(&self) -> Duration {
+        self.state().credentials.age()
+    }
+
+    /// Creates a fresh API key with `signer`, signs all subsequent requests with it, and then
+    /// deletes the previous key on the server, so that a rotation never leaves a valid key
+    /// behind. Requests that are already in flight complete with the credentials they were
+    /// signed with.
+    ///
+    /// Returns a validation error if `signer` is not the one this client was authenticated
+    /// with. If deleting the previous key fails, its error is returned even though the new key
+    /// is already in use, so that the previous key can be deleted by other means.
+    pub async fn rotate_api_key<S: Signer>(&self, signer: &S) -> Result<Credentials> {
+        if signer.address() != self.address() {
+            return Err(Error::validation(format!(
+                "Cannot rotate the API key of {} with a signer for {}",
+                self.address(),
+                signer.address()
+            )));
+        }
+
+        let credentials = self.inner.create_api_key(signer, None).await?;
+        let previous = self.state().credentials.get();
+        self.state().credentials.replace(credentials.clone());
+
+        let request = self
+            .client()
+            .request(Method::DELETE, format!("{}auth/api-key", self.host()))
+            .build()?;
+        let timestamp = self.inner.auth_timestamp().await?;
+        let headers =
+            auth::l2::create_headers_with(self.state(), &previous, &request, timestamp).await?;
+        self.inner
+            .request::<serde_json::Value>(request, Some(headers))
+            .await?;
+
+        Ok(credentials)
+    }
+
+    /// Rotates the API key with [`Client::rotate_api_key`] if the current one is at least
+    /// `interval` old, returning whether it was rotated.
+    ///
+    /// Call this periodically, e.g. from the main loop of a long-running bot, to rotate keys on a
+    /// schedule. A call made while another rotation is in progress returns `false` without
+    /// creating another key.
+    pub async fn rotate_api_key_if_older_than<S: Signer>(
+        &self,
+        signer: &S,
+        interval: Duration,
+    ) -> Result<bool> {
+        let credentials = &self.state().credentials;
+        if credentials.age() < interval || !credentials.begin_rotation() {
+            return Ok(false);
+        }
+
+        let result = self.rotate_api_key(signer).await;
+        credentials.end_rotation();
+
+        result.map(|_| true)
+    }
+
+    /// Returns a task that rotates the API key with [`Client::rotate_api_key`] whenever the
+    /// current one is `interval` old, for as long as it runs. Spawn it on the runtime of the
+    /// application, e.g. with `tokio::spawn`, and drop or abort it to stop rotating.
+    ///
+    /// The task ends with the error of the first rotation that fails.
+    pub fn api_key_rotation<S: Signer + Send + Sync + 'static>(
+        &self,
+        signer: S,
+        interval: Duration,
+    ) -> impl Future<Output = Result<()>> + Send + use<K, S>
+    where
+        K: Clone + Send + Sync + 'static,
+    {
+        let client = self.clone();
+
+        async move {
+            loop {
+                Delay::new(interval.saturating_sub(client.api_key_age())).await;
+                client
+                    .rotate_api_key_if_older_than(&signer, interval)
+                    .await?;
+            }
+        }
     }
 
     pub async fn closed_only_mode(&self) -> Result<BanStatusResponse> {
@@ -968,19 +2145,28 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     /// Creates an [`OrderBuilder<Limit, K>`] used to construct a limit order.
     #[must_use]
     pub fn limit_order(&self) -> OrderBuilder<Limit, K> {
-        self.order_builder()
+        self.new_order_builder()
+    }
+
+    /// Creates an [`OrderBuilder<Limit, K>`] for `token_id` that rounds its price to the token's
+    /// tick size, see [`OrderBuilder::snap_to_tick`]. The tick size and fee rate are fetched, and
+    /// cached, when the order is built, and neg-risk when it is signed, so errors fetching them
+    /// surface from [`OrderBuilder::build`] and [`Client::sign`].
+    #[must_use]
+    pub fn order_builder<ID: Into<String>>(&self, token_id: ID) -> OrderBuilder<Limit, K> {
+        self.limit_order().token_id(token_id).snap_to_tick()
     }
 
     /// Creates an [`OrderBuilder<Market, K>`] used to construct a market order.
     #[must_use]
     pub fn market_order(&self) -> OrderBuilder<Market, K> {
-        self.order_builder()
+        self.new_order_builder()
     }
 
     /// Attempts to sign the provided [`SignableOrder`] using the inner signer of [`Authenticated<K>`]
@@ -1020,30 +2206,121 @@ impl<K: Kind> Client<Authenticated<K>> {
             order,
             signature,
             order_type,
-            owner: self.state().credentials.key,
+            owner: self.state().credentials.get().key,
         })
     }
 
     pub async fn post_order(&self, order: SignedOrder) -> Result<PostOrderResponse> {
+        self.post_order_with_headers(order, HeaderMap::new()).await
+    }
+
+    /// Posts `order`, sending `headers` along with the request.
+    ///
+    /// `headers` are merged over the authentication and client default headers, replacing any
+    /// header of the same name. This is useful for one-off headers such as idempotency keys or
+    /// correlation ids.
+    pub async fn post_order_with_headers(
+        &self,
+        order: SignedOrder,
+        headers: HeaderMap,
+    ) -> Result<PostOrderResponse> {
         let request = self
             .client()
             .request(Method::POST, format!("{}order", self.host()))
             .json(&order)
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        let mut auth_headers = self.create_headers(&request).await?;
+        auth_headers.extend(headers);
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(auth_headers)).await
     }
 
     pub async fn post_orders(&self, orders: Vec<SignedOrder>) -> Result<Vec<PostOrderResponse>> {
+        self.post_orders_with_headers(orders, HeaderMap::new())
+            .await
+    }
+
+    /// Posts `orders`, sending `headers` along with the request.
+    ///
+    /// See [`Client::post_order_with_headers`] for how `headers` are merged.
+    pub async fn post_orders_with_headers(
+        &self,
+        orders: Vec<SignedOrder>,
+        headers: HeaderMap,
+    ) -> Result<Vec<PostOrderResponse>> {
         let request = self
             .client()
             .request(Method::POST, format!("{}orders", self.host()))
             .json(&orders)
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        let mut auth_headers = self.create_headers(&request).await?;
+        auth_headers.extend(headers);
+
+        self.request(request, Some(auth_headers)).await
+    }
+
+    /// Posts `order` at most once per `idempotency_key`, so that it can be retried safely.
+    ///
+    /// The key is sent in the `Idempotency-Key` header. Independently of whether the server
+    /// honors it, the client keeps the response for the `idempotency_window` of [`Config`]: a
+    /// post with the same key within that window returns the kept response without sending
+    /// anything, and a post while the first one is in flight waits for it. A post that fails
+    /// with an error is not kept, so its retry is sent. The deduplication is local to this
+    /// client and its clones.
+    ///
+    /// Returns a validation error if `idempotency_key` is not a valid header value, or if it was
+    /// last used with [`Client::post_orders_idempotent`].
+    pub async fn post_order_idempotent(
+        &self,
+        order: SignedOrder,
+        idempotency_key: &str,
+    ) -> Result<PostOrderResponse> {
+        let headers = idempotency_headers(idempotency_key)?;
+        let post = async {
+            self.post_order_with_headers(order, headers)
+                .await
+                .map(CachedResponse::Order)
+        };
+
+        match self
+            .inner
+            .idempotency
+            .run(idempotency_key, self.inner.config.idempotency_window, post)
+            .await?
+        {
+            CachedResponse::Order(response) => Ok(response),
+            CachedResponse::Orders(_) => Err(Error::validation(format!(
+                "idempotency key {idempotency_key} was used for a batch of orders"
+            ))),
+        }
+    }
+
+    /// Posts `orders` at most once per `idempotency_key`, so that they can be retried safely.
+    ///
+    /// See [`Client::post_order_idempotent`] for how the key is used.
+    pub async fn post_orders_idempotent(
+        &self,
+        orders: Vec<SignedOrder>,
+        idempotency_key: &str,
+    ) -> Result<Vec<PostOrderResponse>> {
+        let headers = idempotency_headers(idempotency_key)?;
+        let post = async {
+            self.post_orders_with_headers(orders, headers)
+                .await
+                .map(CachedResponse::Orders)
+        };
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        match self
+            .inner
+            .idempotency
+            .run(idempotency_key, self.inner.config.idempotency_window, post)
+            .await?
+        {
+            CachedResponse::Orders(responses) => Ok(responses),
+            CachedResponse::Order(_) => Err(Error::validation(format!(
+                "idempotency key {idempotency_key} was used for a single order"
+            ))),
+        }
     }
 
     /// Attempts to return the corresponding order at the provided `order_id`
@@ -1054,22 +2331,32 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn orders(
         &self,
         request: &OrdersRequest,
-        next_cursor: Option<String>,
+        next_cursor: Option<Cursor>,
     ) -> Result<Page<OpenOrderResponse>> {
-        let params = request.query_params(next_cursor.as_deref());
+        let params = request.query_params(next_cursor.as_ref().map(Cursor::as_str));
         let request = self
             .client()
             .request(Method::GET, format!("{}data/orders{params}", self.host()))
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
+    }
+
+    /// Returns a stream of the open orders matching `request`, following `next_cursor` through
+    /// every page with [`Client::stream_data`], so that accounts with more resting orders than
+    /// fit in one page are not truncated.
+    pub fn orders_stream<'client>(
+        &'client self,
+        request: &'client OrdersRequest,
+    ) -> impl Stream<Item = Result<OpenOrderResponse>> + 'client {
+        self.stream_data(move |client, next_cursor| client.orders(request, next_cursor))
     }
 
     pub async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
@@ -1080,7 +2367,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn cancel_orders(&self, order_ids: &[&str]) -> Result<CancelOrdersResponse> {
@@ -1091,7 +2378,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn cancel_all_orders(&self) -> Result<CancelOrdersResponse> {
@@ -1101,7 +2388,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     /// Attempts to cancel all open orders for a particular [`CancelMarketOrderRequest::market`]
@@ -1120,22 +2407,22 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn trades(
         &self,
         request: &TradesRequest,
-        next_cursor: Option<String>,
+        next_cursor: Option<Cursor>,
     ) -> Result<Page<TradeResponse>> {
-        let params = request.query_params(next_cursor.as_deref());
+        let params = request.query_params(next_cursor.as_ref().map(Cursor::as_str));
         let request = self
             .client()
             .request(Method::GET, format!("{}data/trades{params}", self.host()))
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn notifications(&self) -> Result<Vec<NotificationResponse>> {
@@ -1146,12 +2433,12 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn delete_notifications(&self, request: &DeleteNotificationsRequest) -> Result<()> {
         let params = request.query_params(None);
-        let mut request = self
+        let request = self
             .client()
             .request(
                 Method::DELETE,
@@ -1160,11 +2447,10 @@ impl<K: Kind> Client<Authenticated<K>> {
             .json(&request)
             .build()?;
         let headers = self.create_headers(&request).await?;
-        *request.headers_mut() = headers;
 
-        // We have to send the request separately from `self.request` because this endpoint does
-        // not return anything in the response body. Otherwise, we would get an EOF error from reqwest
-        self.client().execute(request).await?;
+        self.inner
+            .request::<NoContent>(request, Some(headers))
+            .await?;
 
         Ok(())
     }
@@ -1187,7 +2473,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn update_balance_allowance(
@@ -1199,7 +2485,7 @@ impl<K: Kind> Client<Authenticated<K>> {
         }
 
         let params = request.query_params(None);
-        let mut request = self
+        let request = self
             .client()
             .request(
                 Method::GET,
@@ -1208,15 +2494,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        *request.headers_mut() = headers;
-
-        // We have to send the request separately from `self.request` because this endpoint does
-        // not return anything in the response body. Otherwise, we would get an EOF error from reqwest
-        self.client().execute(request).await?;
+        self.inner
+            .request::<NoContent>(request, Some(headers))
+            .await?;
 
         Ok(())
     }
 
+    /// Returns whether the live order `order_id` currently qualifies for liquidity rewards.
     pub async fn is_order_scoring(&self, order_id: &str) -> Result<OrderScoringResponse> {
         let request = self
             .client()
@@ -1225,9 +2510,11 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
+    /// Returns whether each of the live orders `order_ids` currently qualifies for liquidity
+    /// rewards, keyed by order ID.
     pub async fn are_orders_scoring(&self, order_ids: &[&str]) -> Result<OrdersScoringResponse> {
         let request = self
             .client()
@@ -1236,15 +2523,15 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn earnings_for_user_for_day(
         &self,
         date: NaiveDate,
-        next_cursor: Option<String>,
+        next_cursor: Option<Cursor>,
     ) -> Result<Page<UserEarningResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("&next_cursor={c}"));
+        let cursor = ().query_params(next_cursor.as_ref().map(Cursor::as_str));
         let request = self
             .client()
             .request(Method::GET, format!("{}rewards/user{cursor}", self.host()))
@@ -1258,7 +2545,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn total_earnings_for_user_for_day(
@@ -1278,15 +2565,15 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn user_earnings_and_markets_config(
         &self,
         request: &UserRewardsEarningRequest,
-        next_cursor: Option<String>,
+        next_cursor: Option<Cursor>,
     ) -> Result<Vec<UserRewardsEarningResponse>> {
-        let params = request.query_params(next_cursor.as_deref());
+        let params = request.query_params(next_cursor.as_ref().map(Cursor::as_str));
         let request = self
             .client()
             .request(
@@ -1300,7 +2587,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn reward_percentages(&self) -> Result<RewardsPercentagesResponse> {
@@ -1317,14 +2604,14 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn current_rewards(
         &self,
-        next_cursor: Option<String>,
+        next_cursor: Option<Cursor>,
     ) -> Result<Page<CurrentRewardResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("&next_cursor={c}"));
+        let cursor = ().query_params(next_cursor.as_ref().map(Cursor::as_str));
         let request = self
             .client()
             .request(
@@ -1334,15 +2621,15 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn raw_rewards_for_market(
         &self,
         condition_id: &str,
-        next_cursor: Option<String>,
+        next_cursor: Option<Cursor>,
     ) -> Result<Page<MarketRewardResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        let cursor = ().query_params(next_cursor.as_ref().map(Cursor::as_str));
         let request = self
             .client()
             .request(
@@ -1352,7 +2639,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn create_builder_api_key(&self) -> Result<Credentials> {
@@ -1362,20 +2649,16 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     async fn create_headers(&self, request: &Request) -> Result<HeaderMap> {
-        let timestamp = if self.inner.config.use_server_time {
-            self.server_time().await?
-        } else {
-            Utc::now().timestamp()
-        };
+        let timestamp = self.inner.auth_timestamp().await?;
 
         auth::l2::create_headers(self.state(), request, timestamp).await
     }
 
-    fn order_builder<OrderKind>(&self) -> OrderBuilder<OrderKind, K> {
+    fn new_order_builder<OrderKind>(&self) -> OrderBuilder<OrderKind, K> {
         OrderBuilder {
             signer: self.address(),
             signature_type: self.inner.signature_type,
@@ -1390,8 +2673,10 @@ impl<K: Kind> Client<Authenticated<K>> {
             expiration: None,
             taker: None,
             order_type: None,
+            snap_to_tick: false,
             client: Client {
                 inner: Arc::clone(&self.inner),
+                headers: self.headers.clone(),
             },
             _kind: PhantomData,
         }
@@ -1399,6 +2684,57 @@ impl<K: Kind> Client<Authenticated<K>> {
 }
 
 impl Client<Authenticated<Normal>> {
+    /// Creates a client for `host` that is already authenticated with existing `credentials` for
+    /// `address`, e.g. API credentials stored by a trading script.
+    ///
+    /// No signer is needed: the L2 headers of authenticated requests are derived from
+    /// `credentials`, and public endpoints work as on an unauthenticated client. Orders are
+    /// assumed to be signed by `address` itself, i.e. with [`SignatureType::Eoa`] and no funder.
+    /// Signing orders still requires the signer, see [`Client::sign`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `host` is not a valid URL or the HTTP client cannot be built.
+    pub fn authenticated(
+        host: &str,
+        config: Config,
+        credentials: Credentials,
+        address: Address,
+    ) -> Result<Self> {
+        let inner = Arc::into_inner(Client::new(host, config)?.inner).ok_or(Synchronization)?;
+
+        let state = Authenticated {
+            address,
+            credentials: CurrentCredentials::new(credentials),
+            kind: Normal,
+        };
+
+        Ok(Client {
+            headers: None,
+            inner: Arc::new(ClientInner {
+                state,
+                config: inner.config,
+                host: inner.host,
+                geoblock_host: inner.geoblock_host,
+                client: inner.client,
+                tick_sizes: inner.tick_sizes,
+                neg_risk: inner.neg_risk,
+                fee_rate_bps: inner.fee_rate_bps,
+                token_index: inner.token_index,
+                funder: inner.funder,
+                signature_type: inner.signature_type,
+                salt_generator: inner.salt_generator,
+                clock: inner.clock,
+                circuit_breaker: inner.circuit_breaker,
+                idempotency: inner.idempotency,
+                latency: inner.latency,
+                price_coalescer: inner.price_coalescer,
+                midpoint_coalescer: inner.midpoint_coalescer,
+                backpressure: inner.backpressure,
+            }),
+        })
+    }
+
     pub fn promote_to_builder(
         self,
         config: BuilderConfig,
@@ -1423,13 +2759,22 @@ impl Client<Authenticated<Normal>> {
             tick_sizes: inner.tick_sizes,
             neg_risk: inner.neg_risk,
             fee_rate_bps: inner.fee_rate_bps,
+            token_index: inner.token_index,
             funder: inner.funder,
             signature_type: inner.signature_type,
             salt_generator: inner.salt_generator,
+            clock: inner.clock,
+            circuit_breaker: inner.circuit_breaker,
+            idempotency: inner.idempotency,
+            latency: inner.latency,
+            price_coalescer: inner.price_coalescer,
+            midpoint_coalescer: inner.midpoint_coalescer,
+            backpressure: inner.backpressure,
         };
 
         Ok(Client {
             inner: Arc::new(new_inner),
+            headers: self.headers,
         })
     }
 }
@@ -1442,11 +2787,11 @@ impl Client<Authenticated<Builder>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 
     pub async fn revoke_builder_api_key(&self) -> Result<()> {
-        let mut request = self
+        let request = self
             .client()
             .request(
                 Method::DELETE,
@@ -1455,11 +2800,9 @@ impl Client<Authenticated<Builder>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        *request.headers_mut() = headers;
-
-        // We have to send the request separately from `self.request` because this endpoint does
-        // not return anything in the response body. Otherwise, we would get an EOF error from reqwest
-        self.client().execute(request).await?;
+        self.inner
+            .request::<NoContent>(request, Some(headers))
+            .await?;
 
         Ok(())
     }
@@ -1467,9 +2810,9 @@ impl Client<Authenticated<Builder>> {
     pub async fn builder_trades(
         &self,
         request: &TradesRequest,
-        next_cursor: Option<String>,
+        next_cursor: Option<Cursor>,
     ) -> Result<Page<BuilderTradeResponse>> {
-        let params = request.query_params(next_cursor.as_deref());
+        let params = request.query_params(next_cursor.as_ref().map(Cursor::as_str));
 
         let request = self
             .client()
@@ -1480,7 +2823,7 @@ impl Client<Authenticated<Builder>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.request(request, Some(headers)).await
     }
 }
 
@@ -1504,7 +2847,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.request(http_request, Some(headers)).await
     }
 
     /// Cancels an RFQ request.
@@ -1539,16 +2882,16 @@ impl<K: Kind> Client<Authenticated<K>> {
     pub async fn requests(
         &self,
         request: &crate::clob::types::RfqRequestsRequest,
-        next_cursor: Option<&str>,
+        next_cursor: Option<Cursor>,
     ) -> Result<crate::clob::types::response::Page<crate::clob::types::RfqRequest>> {
-        let params = request.query_params(next_cursor);
+        let params = request.query_params(next_cursor.as_ref().map(Cursor::as_str));
         let http_request = self
             .client()
             .request(Method::GET, format!("{}rfq/request{params}", self.host()))
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.request(http_request, Some(headers)).await
     }
 
     /// Creates an RFQ Quote in response to a Request.
@@ -1567,7 +2910,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.request(http_request, Some(headers)).await
     }
 
     /// Cancels an RFQ quote.
@@ -1600,16 +2943,16 @@ impl<K: Kind> Client<Authenticated<K>> {
     pub async fn quotes(
         &self,
         request: &crate::clob::types::RfqQuotesRequest,
-        next_cursor: Option<&str>,
+        next_cursor: Option<Cursor>,
     ) -> Result<crate::clob::types::response::Page<crate::clob::types::RfqQuote>> {
-        let params = request.query_params(next_cursor);
+        let params = request.query_params(next_cursor.as_ref().map(Cursor::as_str));
         let http_request = self
             .client()
             .request(Method::GET, format!("{}rfq/quote{params}", self.host()))
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.request(http_request, Some(headers)).await
     }
 
     /// Requester accepts an RFQ Quote.
@@ -1653,32 +2996,22 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&http_request).await?;
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.request(http_request, Some(headers)).await
     }
 
     /// Helper method for RFQ endpoints that return plain text instead of JSON.
     ///
     /// This is used for cancel operations (`cancel_request`, `cancel_quote`)
-    /// and accept quote which return "OK" as plain text rather than a JSON response.
-    /// The standard `crate::request` helper expects JSON responses and would fail
-    /// to deserialize plain text.
+    /// and accept quote which return "OK" as plain text rather than a JSON response,
+    /// so the body is read as [`NoContent`] instead of being deserialized.
     async fn rfq_request_text(
         &self,
-        mut request: reqwest::Request,
+        request: reqwest::Request,
         headers: reqwest::header::HeaderMap,
     ) -> Result<()> {
-        let method = request.method().clone();
-        let path = request.url().path().to_owned();
-
-        *request.headers_mut() = headers;
-
-        let response = self.inner.client.execute(request).await?;
-        let status = response.status();
-
-        if !status.is_success() {
-            let message = response.text().await.unwrap_or_default();
-            return Err(crate::error::Error::status(status, method, path, message));
-        }
+        self.inner
+            .request::<NoContent>(request, Some(headers))
+            .await?;
 
         Ok(())
     }
@@ -1686,10 +3019,93 @@ impl<K: Kind> Client<Authenticated<K>> {
 
 #[cfg(test)]
 mod tests {
+    use rust_decimal_macros::dec;
+
     use super::*;
 
+    #[test]
+    fn align_price_histories_should_fill_gaps() {
+        let point = |t, p| PricePoint { t, p };
+        let mut histories = HashMap::from([
+            (
+                "a".to_owned(),
+                vec![point(3, dec!(0.3)), point(1, dec!(0.1))],
+            ),
+            (
+                "b".to_owned(),
+                vec![point(2, dec!(0.7)), point(4, dec!(0.9))],
+            ),
+        ]);
+
+        align_price_histories(&mut histories);
+
+        assert_eq!(
+            histories["a"],
+            vec![
+                point(1, dec!(0.1)),
+                point(2, dec!(0.1)),
+                point(3, dec!(0.3)),
+                point(4, dec!(0.3)),
+            ]
+        );
+        assert_eq!(
+            histories["b"],
+            vec![
+                point(2, dec!(0.7)),
+                point(3, dec!(0.7)),
+                point(4, dec!(0.9))
+            ]
+        );
+    }
+
     #[test]
     fn client_default_should_succeed() {
         _ = Client::default();
     }
+
+    #[test]
+    fn config_default_should_succeed() {
+        let config = Config::default();
+
+        assert!(!config.use_server_time, "server time should be off");
+        assert_eq!(config.server_time_sync_interval, None);
+        assert_eq!(config.redirect, RedirectPolicy::None);
+        assert!(config.should_retry.is_none(), "retries should be off");
+        assert_eq!(config.circuit_breaker, None);
+        assert_eq!(config.batch_stream_threshold, None);
+        assert_eq!(config.user_agent, None);
+        assert_eq!(config.idempotency_window, Duration::from_secs(60));
+        assert_eq!(config.resolve_override, None);
+        assert_eq!(config.latency_window, None);
+        assert_eq!(config.coalesce_window, None);
+        assert!(!config.backpressure, "backpressure should be opt-in");
+        assert_eq!(config.max_backpressure_pause, Duration::from_secs(60));
+        assert!(
+            !config.http2_prior_knowledge,
+            "prior knowledge should be off"
+        );
+        assert_eq!(config.http2_keep_alive_interval, None);
+        assert!(config.tcp_nodelay, "TCP_NODELAY should be on");
+    }
+
+    #[test]
+    fn config_connection_options_should_succeed() {
+        for prior_knowledge in [false, true] {
+            for keep_alive in [None, Some(Duration::from_secs(30))] {
+                for nodelay in [false, true] {
+                    let config = Config::builder()
+                        .http2_prior_knowledge(prior_knowledge)
+                        .maybe_http2_keep_alive_interval(keep_alive)
+                        .tcp_nodelay(nodelay)
+                        .build();
+
+                    assert_eq!(config.http2_prior_knowledge, prior_knowledge);
+                    assert_eq!(config.http2_keep_alive_interval, keep_alive);
+                    assert_eq!(config.tcp_nodelay, nodelay);
+
+                    Client::new("https://clob.polymarket.com", config).unwrap();
+                }
+            }
+        }
+    }
 }
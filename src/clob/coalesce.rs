@@ -0,0 +1,215 @@
+//! Coalescing of single requests into batch requests, enabled by the `coalesce_window` of
+//! [`Config`](crate::clob::Config).
+//!
+//! The first caller to arrive when no batch is open becomes its leader: it waits for the window,
+//! closes the batch, sends one batch request with every key that joined in the meantime, and
+//! hands the response to the other callers. If the leader is dropped before the response
+//! arrives, the callers that joined its batch are told to send their requests on their own.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use futures::channel::oneshot;
+use futures_timer::Delay;
+
+use crate::Result;
+use crate::error::{Error, Status};
+
+/// Response of a batch request, shared by every caller that joined the batch.
+pub(crate) type Outcome<V> = std::result::Result<Arc<V>, Arc<Error>>;
+
+/// Batches of single requests for the keys `K`, answered by batch responses `V`.
+#[derive(Debug)]
+pub(crate) struct Coalescer<K, V> {
+    window: Duration,
+    open: Mutex<Option<Batch<K, V>>>,
+}
+
+#[derive(Debug)]
+struct Batch<K, V> {
+    keys: Vec<K>,
+    waiters: Vec<oneshot::Sender<Outcome<V>>>,
+}
+
+impl<K: PartialEq, V> Coalescer<K, V> {
+    pub(crate) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            open: Mutex::new(None),
+        }
+    }
+
+    /// Adds `key` to the open batch, opening one if there is none, and returns the response of
+    /// the batch request that `dispatch` sends for it. Returns `None` if the batch was abandoned,
+    /// in which case the caller should send its request on its own.
+    pub(crate) async fn run<F, Fut>(&self, key: K, dispatch: F) -> Option<Outcome<V>>
+    where
+        F: FnOnce(Vec<K>) -> Fut,
+        Fut: Future<Output = Result<V>>,
+    {
+        let waiter = {
+            let mut open = self.lock();
+            if let Some(batch) = open.as_mut() {
+                if !batch.keys.contains(&key) {
+                    batch.keys.push(key);
+                }
+                let (sender, receiver) = oneshot::channel();
+                batch.waiters.push(sender);
+                Some(receiver)
+            } else {
+                *open = Some(Batch {
+                    keys: vec![key],
+                    waiters: Vec::new(),
+                });
+                None
+            }
+        };
+
+        if let Some(receiver) = waiter {
+            // The sender is dropped if the leader is, which leaves the caller on its own
+            return receiver.await.ok();
+        }
+
+        // Abandons the batch if this leader is dropped while waiting for the window
+        let mut guard = AbandonOnDrop(Some(self));
+        Delay::new(self.window).await;
+        let Batch { keys, waiters } = self.lock().take()?;
+        // The batch is closed, so a batch opened from now on belongs to another leader
+        guard.0 = None;
+
+        let outcome = dispatch(keys).await.map(Arc::new).map_err(Arc::new);
+        for waiter in waiters {
+            // A waiter that went away does not need the response
+            _ = waiter.send(outcome.clone());
+        }
+
+        Some(outcome)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<Batch<K, V>>> {
+        // The batch is only ever replaced whole, so it is still consistent after a panic
+        self.open.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Closes the open batch when dropped while still set, so that the callers that joined it stop
+/// waiting.
+struct AbandonOnDrop<'coalescer, K: PartialEq, V>(Option<&'coalescer Coalescer<K, V>>);
+
+impl<K: PartialEq, V> Drop for AbandonOnDrop<'_, K, V> {
+    fn drop(&mut self) {
+        if let Some(coalescer) = self.0 {
+            coalescer.lock().take();
+        }
+    }
+}
+
+/// Returns the error of a failed batch to one of its callers: the error itself if no other
+/// caller holds it anymore, a copy of its [`Status`] if it has one, so that every caller can
+/// inspect the response, or an error of the same kind caused by it otherwise.
+pub(crate) fn into_error(error: Arc<Error>) -> Error {
+    let shared = match Arc::try_unwrap(error) {
+        Ok(error) => return error,
+        Err(shared) => shared,
+    };
+
+    match shared.downcast_ref::<Status>() {
+        Some(status) => Status {
+            status_code: status.status_code,
+            method: status.method.clone(),
+            path: status.path.clone(),
+            message: status.message.clone(),
+            retry_after: status.retry_after,
+            body: status.body.clone(),
+        }
+        .into(),
+        None => Error::with_source(shared.kind(), shared),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::executor::block_on;
+    use futures::future::{self, join_all};
+    use reqwest::{Method, StatusCode};
+
+    use super::*;
+
+    #[test]
+    fn concurrent_keys_should_share_one_dispatch() {
+        let coalescer = Coalescer::new(Duration::from_millis(10));
+        let dispatches = AtomicUsize::new(0);
+
+        let outcomes = block_on(join_all([1, 2, 2, 3].map(|key| {
+            coalescer.run(key, |keys| {
+                dispatches.fetch_add(1, Ordering::Relaxed);
+                future::ready(Ok(keys))
+            })
+        })));
+
+        assert_eq!(dispatches.load(Ordering::Relaxed), 1);
+        for outcome in outcomes {
+            assert_eq!(*outcome.unwrap().unwrap(), vec![1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn abandoned_batch_should_release_waiters() {
+        let coalescer = Coalescer::<u8, ()>::new(Duration::from_secs(60));
+
+        let outcome = block_on(async {
+            let mut leader = Box::pin(coalescer.run(1, |_| future::ready(Ok(()))));
+            let mut follower = Box::pin(coalescer.run(2, |_| future::ready(Ok(()))));
+            // Polls each once, so that the leader opens the batch and the follower joins it
+            assert!(
+                futures::poll!(leader.as_mut()).is_pending(),
+                "the leader should wait for the window"
+            );
+            assert!(
+                futures::poll!(follower.as_mut()).is_pending(),
+                "the follower should wait for the leader"
+            );
+            drop(leader);
+            follower.await
+        });
+
+        assert!(outcome.is_none(), "the follower should be on its own");
+    }
+
+    #[test]
+    fn shared_status_should_reach_every_waiter() {
+        let coalescer = Coalescer::<u8, ()>::new(Duration::from_millis(10));
+
+        let outcomes = block_on(join_all([1, 2, 3].map(|key| {
+            coalescer.run(key, |_| {
+                future::ready(Err(Error::status(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    Method::GET,
+                    "/prices".to_owned(),
+                    "down",
+                )))
+            })
+        })));
+
+        for outcome in outcomes {
+            let error = into_error(outcome.unwrap().unwrap_err());
+            let status = error.downcast_ref::<Status>().unwrap();
+            assert_eq!(status.status_code, StatusCode::SERVICE_UNAVAILABLE);
+            assert_eq!(status.path, "/prices");
+        }
+    }
+
+    #[test]
+    fn shared_error_should_keep_its_kind() {
+        let error = Arc::new(Error::validation("bad batch"));
+        let other = Arc::clone(&error);
+
+        let error = into_error(error);
+
+        assert_eq!(error.kind(), other.kind());
+        assert_eq!(into_error(other).kind(), error.kind());
+    }
+}
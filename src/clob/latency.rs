@@ -0,0 +1,205 @@
+//! Response-time percentiles per endpoint, enabled by the `latency_window` of
+//! [`Config`](crate::clob::Config).
+//!
+//! Requests are grouped by their path pattern, where segments that look like IDs are collapsed
+//! to `{id}`, so that e.g. `/markets/0xab…` and `/markets/0xcd…` share `/markets/{id}`. See
+//! [`path_pattern`] for what counts as an ID.
+
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`LatencyStats` names the statistics it holds"
+)]
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use dashmap::DashMap;
+
+/// Placeholder replacing ID segments in a path pattern.
+const ID: &str = "{id}";
+
+/// Segments at least this long made of letters, digits, `-` and `_`, with at least one digit,
+/// are taken to be opaque IDs such as UUIDs.
+const OPAQUE_ID_LEN: usize = 20;
+
+/// Response-time percentiles of the latest requests to an endpoint, see
+/// [`Client::latency_stats`](crate::clob::Client::latency_stats).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LatencyStats {
+    /// Number of requests the percentiles are computed over, at most the `latency_window`
+    pub count: usize,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl LatencyStats {
+    /// Computes the nearest-rank percentiles of `samples`, or `None` if there are none.
+    fn from_samples<'sample, I: IntoIterator<Item = &'sample Duration>>(
+        samples: I,
+    ) -> Option<Self> {
+        let mut sorted: Vec<Duration> = samples.into_iter().copied().collect();
+        sorted.sort_unstable();
+
+        let max = *sorted.last()?;
+        let percentile = |p: usize| {
+            // Nearest rank: the smallest sample with at least p% of the samples at or below it
+            let rank = (p * sorted.len()).div_ceil(100).max(1);
+            sorted[rank - 1]
+        };
+
+        Some(Self {
+            count: sorted.len(),
+            p50: percentile(50),
+            p95: percentile(95),
+            p99: percentile(99),
+            max,
+        })
+    }
+}
+
+/// Returns the pattern of `path` that its latencies are grouped under: each segment made of
+/// digits, of `0x` and hex digits, or with at least [`OPAQUE_ID_LEN`] letters, digits, `-` and
+/// `_` including a digit, is replaced with `{id}`. The query, if any, is dropped.
+#[must_use]
+pub fn path_pattern(path: &str) -> String {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+
+    path.split('/')
+        .map(|segment| if is_id(segment) { ID } else { segment })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn is_id(segment: &str) -> bool {
+    if segment.is_empty() {
+        return false;
+    }
+    if segment.bytes().all(|b| b.is_ascii_digit()) {
+        return true;
+    }
+    if let Some(hex) = segment.strip_prefix("0x") {
+        return !hex.is_empty() && hex.bytes().all(|b| b.is_ascii_hexdigit());
+    }
+
+    segment.len() >= OPAQUE_ID_LEN
+        && segment.bytes().any(|b| b.is_ascii_digit())
+        && segment
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+}
+
+/// Latest response times per path pattern, keeping at most `window` per pattern.
+#[derive(Debug)]
+pub(crate) struct LatencyTracker {
+    window: usize,
+    samples: DashMap<String, VecDeque<Duration>>,
+}
+
+impl LatencyTracker {
+    pub(crate) fn new(window: usize) -> Self {
+        Self {
+            window: window.max(1),
+            samples: DashMap::new(),
+        }
+    }
+
+    /// Records that a request to `path` took `elapsed`, evicting the oldest sample of its pattern
+    /// once the window is full.
+    pub(crate) fn record(&self, path: &str, elapsed: Duration) {
+        let mut samples = self.samples.entry(path_pattern(path)).or_default();
+        if samples.len() == self.window {
+            samples.pop_front();
+        }
+        samples.push_back(elapsed);
+    }
+
+    /// Returns the percentiles of the requests to the pattern of `path`, which may be a path or
+    /// already a pattern.
+    pub(crate) fn stats(&self, path: &str) -> Option<LatencyStats> {
+        let samples = self.samples.get(&path_pattern(path))?;
+        LatencyStats::from_samples(samples.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn stats_should_compute_percentiles() {
+        let tracker = LatencyTracker::new(100);
+        // Recorded out of order, so that the percentiles cannot rely on arrival order
+        for millis in (1..=100).rev() {
+            tracker.record("/book", ms(millis));
+        }
+
+        let stats = tracker.stats("/book").unwrap();
+
+        assert_eq!(stats.count, 100);
+        assert_eq!(stats.p50, ms(50));
+        assert_eq!(stats.p95, ms(95));
+        assert_eq!(stats.p99, ms(99));
+        assert_eq!(stats.max, ms(100));
+    }
+
+    #[test]
+    fn stats_should_slide_over_latest_samples() {
+        let tracker = LatencyTracker::new(3);
+        for millis in [900, 10, 20, 30] {
+            tracker.record("/price", ms(millis));
+        }
+
+        let stats = tracker.stats("/price").unwrap();
+
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.p50, ms(20));
+        assert_eq!(stats.max, ms(30));
+    }
+
+    #[test]
+    fn single_sample_should_be_every_percentile() {
+        let tracker = LatencyTracker::new(10);
+        tracker.record("/", ms(7));
+
+        let stats = tracker.stats("/").unwrap();
+
+        assert_eq!((stats.p50, stats.p95, stats.p99), (ms(7), ms(7), ms(7)));
+        assert_eq!(tracker.stats("/time"), None);
+    }
+
+    #[test]
+    fn ids_should_share_a_pattern() {
+        let tracker = LatencyTracker::new(10);
+        tracker.record(&format!("/markets/0x{}", "ab".repeat(32)), ms(10));
+        tracker.record(&format!("/markets/0x{}", "cd".repeat(32)), ms(30));
+
+        let stats = tracker.stats("/markets/{id}").unwrap();
+
+        assert_eq!(stats.count, 2);
+        assert_eq!(tracker.stats("/markets/0x01"), Some(stats));
+    }
+
+    #[test]
+    fn path_pattern_should_collapse_ids() {
+        assert_eq!(path_pattern("/markets/0xAbC123"), "/markets/{id}");
+        assert_eq!(path_pattern("/rewards/markets/42"), "/rewards/markets/{id}");
+        assert_eq!(
+            path_pattern("/data/order/123e4567-e89b-12d3-a456-426614174000"),
+            "/data/order/{id}"
+        );
+        assert_eq!(path_pattern("/book?token_id=1"), "/book");
+        assert_eq!(
+            path_pattern("/sampling-simplified-markets"),
+            "/sampling-simplified-markets"
+        );
+        assert_eq!(path_pattern("/markets/{id}"), "/markets/{id}");
+        assert_eq!(path_pattern("/"), "/");
+    }
+}
@@ -1,7 +1,16 @@
+mod backpressure;
 pub mod client;
+mod coalesce;
+pub mod latency;
+pub mod odds;
 pub mod order_builder;
+pub mod pagination;
+pub mod rounding;
+pub mod sizing;
 pub mod types;
 #[cfg(feature = "ws")]
 pub mod ws;
 
-pub use client::{Client, Config};
+pub use client::{
+    CircuitBreakerConfig, CircuitState, Client, Config, ConfigBuilder, RetryClassifier, TokenIndex,
+};
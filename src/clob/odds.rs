@@ -0,0 +1,129 @@
+//! Conversions from token prices to probabilities and betting odds.
+//!
+//! A token's price is the market's implied probability of its outcome, so a price of `0.8` means
+//! an 80% chance. The odds formats are those used by sportsbooks:
+//!
+//! | Price | Probability | Decimal odds | American odds |
+//! |-------|-------------|--------------|---------------|
+//! | `0.5` | 50% | `2` | `+100` |
+//! | `0.8` | 80% | `1.25` | `-400` |
+//! | `0.2` | 20% | `5` | `+400` |
+//!
+//! Prices of `0` and `1` leave no chance for one side, so there are no finite odds for them and
+//! the odds functions return `None`. Prices outside `[0, 1]` also return `None`, as do prices so
+//! close to `0` or `1` that their odds overflow a [`Decimal`].
+
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`decimal_odds` and `american_odds` name the odds formats"
+)]
+
+use crate::types::Decimal;
+
+const HALF: Decimal = Decimal::from_parts(5, 0, 0, false, 1);
+
+/// Returns the implied probability of `price` as a percentage, e.g. `80` for `0.8`.
+#[must_use]
+pub fn price_to_probability(price: Decimal) -> Option<Decimal> {
+    (Decimal::ZERO..=Decimal::ONE)
+        .contains(&price)
+        .then(|| price * Decimal::ONE_HUNDRED)
+}
+
+/// Returns the decimal odds of `price`, the total payout per unit staked, e.g. `1.25` for `0.8`.
+#[must_use]
+pub fn price_to_decimal_odds(price: Decimal) -> Option<Decimal> {
+    if !is_tradable(price) {
+        return None;
+    }
+
+    Decimal::ONE.checked_div(price)
+}
+
+/// Returns the American odds of `price`: the profit on a `100` stake for underdogs (positive),
+/// or the stake needed to profit `100` for favorites (negative). Even odds at `0.5` are `+100`.
+#[must_use]
+pub fn price_to_american_odds(price: Decimal) -> Option<Decimal> {
+    if !is_tradable(price) {
+        return None;
+    }
+
+    let against = Decimal::ONE - price;
+    if price > HALF {
+        price
+            .checked_div(against)?
+            .checked_mul(Decimal::ONE_HUNDRED)
+            .map(|odds| -odds)
+    } else {
+        against
+            .checked_div(price)?
+            .checked_mul(Decimal::ONE_HUNDRED)
+    }
+}
+
+/// Returns `true` if `price` is strictly between `0` and `1`, where odds are finite.
+fn is_tradable(price: Decimal) -> bool {
+    price > Decimal::ZERO && price < Decimal::ONE
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn mid_price_should_be_even_odds() {
+        assert_eq!(price_to_probability(dec!(0.5)), Some(dec!(50)));
+        assert_eq!(price_to_decimal_odds(dec!(0.5)), Some(dec!(2)));
+        assert_eq!(price_to_american_odds(dec!(0.5)), Some(dec!(100)));
+    }
+
+    #[test]
+    fn favorite_should_have_negative_american_odds() {
+        assert_eq!(price_to_probability(dec!(0.8)), Some(dec!(80)));
+        assert_eq!(price_to_decimal_odds(dec!(0.8)), Some(dec!(1.25)));
+        assert_eq!(price_to_american_odds(dec!(0.8)), Some(dec!(-400)));
+    }
+
+    #[test]
+    fn underdog_should_have_positive_american_odds() {
+        assert_eq!(price_to_decimal_odds(dec!(0.2)), Some(dec!(5)));
+        assert_eq!(price_to_american_odds(dec!(0.2)), Some(dec!(400)));
+    }
+
+    #[test]
+    fn degenerate_prices_should_have_no_odds() {
+        for price in [Decimal::ZERO, Decimal::ONE] {
+            assert_eq!(price_to_decimal_odds(price), None);
+            assert_eq!(price_to_american_odds(price), None);
+        }
+
+        assert_eq!(price_to_probability(Decimal::ZERO), Some(Decimal::ZERO));
+        assert_eq!(
+            price_to_probability(Decimal::ONE),
+            Some(Decimal::ONE_HUNDRED)
+        );
+    }
+
+    #[test]
+    fn out_of_range_prices_should_be_none() {
+        for price in [dec!(-0.1), dec!(1.1)] {
+            assert_eq!(price_to_probability(price), None);
+            assert_eq!(price_to_decimal_odds(price), None);
+            assert_eq!(price_to_american_odds(price), None);
+        }
+    }
+
+    #[test]
+    fn extreme_prices_should_be_none_on_overflow() {
+        let tiny = Decimal::new(1, 28);
+
+        assert_eq!(
+            price_to_decimal_odds(tiny),
+            Some(Decimal::from_i128_with_scale(10_i128.pow(28), 0))
+        );
+        assert_eq!(price_to_american_odds(tiny), None);
+        assert_eq!(price_to_american_odds(Decimal::ONE - tiny), None);
+    }
+}
@@ -3,7 +3,7 @@ use std::str::FromStr as _;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use alloy::primitives::U256;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use rand::Rng as _;
 use rust_decimal::prelude::ToPrimitive as _;
 
@@ -11,6 +11,7 @@ use crate::Result;
 use crate::auth::Kind as AuthKind;
 use crate::auth::state::Authenticated;
 use crate::clob::Client;
+use crate::clob::rounding::{round_amount, round_price};
 use crate::clob::types::request::OrderBookSummaryRequest;
 use crate::clob::types::{
     Amount, AmountInner, Order, OrderType, Side, SignableOrder, SignatureType,
@@ -50,6 +51,7 @@ pub struct OrderBuilder<OrderKind, K: AuthKind> {
     pub(crate) taker: Option<Address>,
     pub(crate) order_type: Option<OrderType>,
     pub(crate) funder: Option<Address>,
+    pub(crate) snap_to_tick: bool,
     pub(crate) _kind: PhantomData<OrderKind>,
 }
 
@@ -68,6 +70,18 @@ impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
         self
     }
 
+    /// Sets the [`Side`] to [`Side::Buy`]. Shorthand for `side(Side::Buy)`.
+    #[must_use]
+    pub fn buy(self) -> Self {
+        self.side(Side::Buy)
+    }
+
+    /// Sets the [`Side`] to [`Side::Sell`]. Shorthand for `side(Side::Sell)`.
+    #[must_use]
+    pub fn sell(self) -> Self {
+        self.side(Side::Sell)
+    }
+
     /// Sets the [`Side`] for this builder.
     #[must_use]
     pub fn nonce(mut self, nonce: u64) -> Self {
@@ -75,12 +89,33 @@ impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
         self
     }
 
+    /// Sets the expiration for this builder. It is sent to the exchange as unix seconds, so any
+    /// sub-second precision is dropped. Only [`OrderType::GTD`] orders may have an expiration.
     #[must_use]
     pub fn expiration(mut self, expiration: DateTime<Utc>) -> Self {
         self.expiration = Some(expiration);
         self
     }
 
+    /// Sets the expiration to `duration` from now. See [`OrderBuilder::expiration`].
+    #[must_use]
+    pub fn expires_in(mut self, duration: TimeDelta) -> Self {
+        self.expiration = Some(
+            Utc::now()
+                .checked_add_signed(duration)
+                .unwrap_or(DateTime::<Utc>::MAX_UTC),
+        );
+        self
+    }
+
+    /// Explicitly sets no expiration, which is sent as `0`. This is the default, and the only
+    /// valid choice for non-GTD orders.
+    #[must_use]
+    pub fn no_expiration(mut self) -> Self {
+        self.expiration = Some(DateTime::<Utc>::UNIX_EPOCH);
+        self
+    }
+
     #[must_use]
     pub fn taker(mut self, taker: Address) -> Self {
         self.taker = Some(taker);
@@ -109,6 +144,15 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         self
     }
 
+    /// Rounds the price to the token's tick size in [`build`](Self::build), rather than
+    /// rejecting a price with more decimal places than the tick size. Prices that are out of
+    /// range after rounding are still rejected.
+    #[must_use]
+    pub fn snap_to_tick(mut self) -> Self {
+        self.snap_to_tick = true;
+        self
+    }
+
     /// Validates and transforms this limit builder into a [`SignableOrder`]
     #[cfg_attr(
         feature = "tracing",
@@ -140,14 +184,13 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         }
 
         let fee_rate = self.client.fee_rate_bps(&token_id).await?;
-        let minimum_tick_size = self
-            .client
-            .tick_size(&token_id)
-            .await?
-            .minimum_tick_size
-            .as_decimal();
-
-        let decimals = minimum_tick_size.scale();
+        let tick_size = self.client.tick_size(&token_id).await?.minimum_tick_size;
+        let minimum_tick_size = tick_size.as_decimal();
+        let price = if self.snap_to_tick {
+            round_price(price, tick_size)
+        } else {
+            price
+        };
 
         if price.scale() > minimum_tick_size.scale() {
             return Err(Error::validation(format!(
@@ -204,14 +247,8 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         // This means they will take/receive 100 `YES` tokens, make/give up 34 USDC. This means that
         // the `taker_amount` is `100000000` and the `maker_amount` of `34000000`.
         let (taker_amount, maker_amount) = match side {
-            Side::Buy => (
-                size,
-                (size * price).trunc_with_scale(decimals + LOT_SIZE_SCALE),
-            ),
-            Side::Sell => (
-                (size * price).trunc_with_scale(decimals + LOT_SIZE_SCALE),
-                size,
-            ),
+            Side::Buy => (size, round_amount(size * price, tick_size)),
+            Side::Sell => (round_amount(size * price, tick_size), size),
             side => return Err(Error::validation(format!("Invalid side: {side}"))),
         };
 
@@ -354,12 +391,8 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             None => self.calculate_price(order_type).await?,
         };
 
-        let minimum_tick_size = self
-            .client
-            .tick_size(&token_id)
-            .await?
-            .minimum_tick_size
-            .as_decimal();
+        let tick_size = self.client.tick_size(&token_id).await?.minimum_tick_size;
+        let minimum_tick_size = tick_size.as_decimal();
         let fee_rate = self.client.fee_rate_bps(&token_id).await?;
 
         let decimals = minimum_tick_size.scale();
@@ -393,19 +426,19 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         let (taker_amount, maker_amount) = match (side, amount.0) {
             // Spend USDC to buy shares
             (Side::Buy, AmountInner::Usdc(_)) => {
-                let shares = (raw_amount / price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
+                let shares = round_amount(raw_amount / price, tick_size);
                 (shares, raw_amount)
             }
 
             // Buy N shares: use cutoff `price` derived from ask depth
             (Side::Buy, AmountInner::Shares(_)) => {
-                let usdc = (raw_amount * price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
+                let usdc = round_amount(raw_amount * price, tick_size);
                 (raw_amount, usdc)
             }
 
             // Sell N shares for USDC
             (Side::Sell, AmountInner::Shares(_)) => {
-                let usdc = (raw_amount * price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
+                let usdc = round_amount(raw_amount * price, tick_size);
                 (usdc, raw_amount)
             }
 
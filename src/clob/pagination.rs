@@ -0,0 +1,156 @@
+//! Cursor handling for paginated CLOB endpoints.
+//!
+//! The CLOB paginates with an opaque `next_cursor` that is, in practice, the base64 encoding of a
+//! numeric offset. An empty cursor requests the first page and the sentinel `"LTE="`
+//! (`base64("-1")`) signals that there are no more pages.
+
+use std::fmt;
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Serialize};
+
+/// The cursor value returned by the server once the last page has been reached.
+pub const END_CURSOR: &str = "LTE="; // base64("-1")
+
+/// A pagination cursor as used by the CLOB `next_cursor` parameter and response field.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// The cursor that requests the first page.
+    #[must_use]
+    pub const fn start() -> Self {
+        Self(String::new())
+    }
+
+    /// The end-of-pagination sentinel cursor.
+    #[must_use]
+    pub fn end() -> Self {
+        Self(END_CURSOR.to_owned())
+    }
+
+    /// Encode a cursor pointing at `offset`. A negative offset produces the end sentinel.
+    #[must_use]
+    pub fn from_offset(offset: i64) -> Self {
+        if offset < 0 {
+            return Self::end();
+        }
+
+        Self(STANDARD.encode(offset.to_string()))
+    }
+
+    /// Returns `true` if this cursor requests the first page.
+    #[must_use]
+    pub const fn is_start(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if this cursor marks the end of pagination.
+    #[must_use]
+    pub fn is_end(&self) -> bool {
+        self.0 == END_CURSOR
+    }
+
+    /// Decode the numeric offset this cursor points at.
+    ///
+    /// The start cursor decodes to `0` and the end sentinel to `-1`. Returns `None` if the cursor
+    /// is not a base64-encoded integer, in which case it should be treated as opaque.
+    #[must_use]
+    pub fn offset(&self) -> Option<i64> {
+        if self.is_start() {
+            return Some(0);
+        }
+
+        let bytes = STANDARD.decode(&self.0).ok()?;
+        std::str::from_utf8(&bytes).ok()?.parse().ok()
+    }
+
+    /// The raw cursor value as sent on the wire.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consume the cursor, returning the raw wire value.
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for Cursor {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Cursor {
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+impl From<Cursor> for String {
+    fn from(value: Cursor) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_cursor_should_succeed() {
+        let cursor = Cursor::from("");
+
+        assert_eq!(cursor, Cursor::start());
+        assert!(cursor.is_start(), "empty cursor should be the start");
+        assert!(!cursor.is_end(), "empty cursor should not be the end");
+        assert_eq!(cursor.offset(), Some(0));
+    }
+
+    #[test]
+    fn mid_cursor_should_succeed() {
+        let cursor = Cursor::from("MTAw"); // base64("100")
+
+        assert!(!cursor.is_start(), "mid cursor should not be the start");
+        assert!(!cursor.is_end(), "mid cursor should not be the end");
+        assert_eq!(cursor.offset(), Some(100));
+        assert_eq!(Cursor::from_offset(100), cursor);
+    }
+
+    #[test]
+    fn end_cursor_should_succeed() {
+        let cursor = Cursor::from(END_CURSOR);
+
+        assert_eq!(cursor, Cursor::end());
+        assert!(cursor.is_end(), "LTE= should be the end");
+        assert_eq!(cursor.offset(), Some(-1));
+        assert_eq!(Cursor::from_offset(-1), cursor);
+    }
+
+    #[test]
+    fn opaque_cursor_offset_should_be_none() {
+        let cursor = Cursor::from("not-base64!");
+
+        assert_eq!(cursor.offset(), None);
+        assert_eq!(cursor.as_str(), "not-base64!");
+    }
+
+    #[test]
+    fn cursor_serde_is_transparent() {
+        let cursor: Cursor = serde_json::from_str("\"MTAw\"").unwrap();
+
+        assert_eq!(cursor.offset(), Some(100));
+        assert_eq!(serde_json::to_string(&cursor).unwrap(), "\"MTAw\"");
+    }
+}
@@ -0,0 +1,111 @@
+//! Rounding rules for order prices, sizes, and notional amounts.
+//!
+//! The order builders apply these rules when computing maker and taker amounts. They are exposed
+//! so that callers pre-computing orders arrive at exactly the values the builders would sign.
+//!
+//! | Value | Precision | Rule |
+//! |-------|-----------|------|
+//! | price | tick size decimals | half-even |
+//! | size | [`LOT_SIZE_SCALE`] decimals | toward zero |
+//! | amount (`size * price`) | tick size decimals + [`LOT_SIZE_SCALE`] | toward zero |
+
+use rust_decimal::RoundingStrategy;
+
+use crate::clob::order_builder::LOT_SIZE_SCALE;
+use crate::clob::types::TickSize;
+use crate::types::Decimal;
+
+/// Rounds `price` to the number of decimal places of `tick_size`, rounding half to even.
+///
+/// The result is not clamped to the valid price range of `tick_size`.
+#[must_use]
+pub fn round_price(price: Decimal, tick_size: TickSize) -> Decimal {
+    price.round_dp_with_strategy(
+        tick_size.as_decimal().scale(),
+        RoundingStrategy::MidpointNearestEven,
+    )
+}
+
+/// Rounds `size` toward zero to [`LOT_SIZE_SCALE`] decimal places.
+#[must_use]
+pub fn round_size(size: Decimal) -> Decimal {
+    size.trunc_with_scale(LOT_SIZE_SCALE)
+}
+
+/// Rounds a notional `amount`, such as `size * price`, toward zero to the combined precision of
+/// `tick_size` and [`LOT_SIZE_SCALE`].
+///
+/// This makes amounts "snap" to the precision of resting orders on the book.
+#[must_use]
+pub fn round_amount(amount: Decimal, tick_size: TickSize) -> Decimal {
+    amount.trunc_with_scale(tick_size.as_decimal().scale() + LOT_SIZE_SCALE)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn round_price_should_succeed() {
+        assert_eq!(round_price(dec!(0.25), TickSize::Tenth), dec!(0.2));
+        assert_eq!(round_price(dec!(0.35), TickSize::Tenth), dec!(0.4));
+        assert_eq!(round_price(dec!(0.36), TickSize::Tenth), dec!(0.4));
+        assert_eq!(round_price(dec!(0.345), TickSize::Hundredth), dec!(0.34));
+        assert_eq!(round_price(dec!(0.355), TickSize::Hundredth), dec!(0.36));
+        assert_eq!(round_price(dec!(0.3451), TickSize::Hundredth), dec!(0.35));
+        assert_eq!(round_price(dec!(0.0125), TickSize::Thousandth), dec!(0.012));
+        assert_eq!(round_price(dec!(0.0135), TickSize::Thousandth), dec!(0.014));
+        assert_eq!(
+            round_price(dec!(0.00015), TickSize::TenThousandth),
+            dec!(0.0002)
+        );
+        assert_eq!(
+            round_price(dec!(0.00025), TickSize::TenThousandth),
+            dec!(0.0002)
+        );
+    }
+
+    #[test]
+    fn round_price_on_grid_should_be_unchanged() {
+        assert_eq!(round_price(dec!(0.1), TickSize::Tenth), dec!(0.1));
+        assert_eq!(round_price(dec!(0.99), TickSize::Hundredth), dec!(0.99));
+        assert_eq!(round_price(dec!(0.001), TickSize::Thousandth), dec!(0.001));
+        assert_eq!(
+            round_price(dec!(0.9999), TickSize::TenThousandth),
+            dec!(0.9999)
+        );
+    }
+
+    #[test]
+    fn round_size_should_succeed() {
+        assert_eq!(round_size(dec!(100)), dec!(100));
+        assert_eq!(round_size(dec!(10.999)), dec!(10.99));
+        assert_eq!(round_size(dec!(10.005)), dec!(10.00));
+        assert_eq!(round_size(dec!(0.009)), Decimal::ZERO);
+    }
+
+    #[test]
+    fn round_amount_should_succeed() {
+        assert_eq!(round_amount(dec!(14.728), TickSize::Tenth), dec!(14.728));
+        assert_eq!(round_amount(dec!(14.7289), TickSize::Tenth), dec!(14.728));
+        // 21.04 shares at $0.58 with a 0.01 tick size
+        assert_eq!(
+            round_amount(dec!(12.2032), TickSize::Hundredth),
+            dec!(12.2032)
+        );
+        assert_eq!(
+            round_amount(dec!(12.20329), TickSize::Hundredth),
+            dec!(12.2032)
+        );
+        assert_eq!(
+            round_amount(dec!(0.123456), TickSize::Thousandth),
+            dec!(0.12345)
+        );
+        assert_eq!(
+            round_amount(dec!(0.1234567), TickSize::TenThousandth),
+            dec!(0.123456)
+        );
+    }
+}
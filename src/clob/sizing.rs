@@ -0,0 +1,138 @@
+//! Conversions between order sizes, prices, and dollar amounts.
+//!
+//! Sizes are in shares and amounts in the collateral token, so buying `size` shares at `price`
+//! costs `size * price`. The sizes returned here are rounded down with
+//! [`round_size`](crate::clob::rounding::round_size), so that they can be placed as they are and
+//! never cost more than the amount they were computed from.
+
+use crate::clob::rounding::{round_price, round_size};
+use crate::clob::types::TickSize;
+use crate::types::Decimal;
+
+const BPS_PER_UNIT: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Returns the notional value of `size` shares at `price`, i.e. `size * price`.
+#[must_use]
+pub fn notional(size: Decimal, price: Decimal) -> Decimal {
+    size * price
+}
+
+/// Returns the largest placeable number of shares at `price` whose notional fits in `budget`.
+///
+/// Returns `None` if `price` is not a valid price for `tick_size`: it must be a multiple of the
+/// tick size, strictly between `0` and `1`. A `budget` too small for the smallest lot returns
+/// `0`, and a negative `budget` returns `None`.
+#[must_use]
+pub fn shares_for_budget(budget: Decimal, price: Decimal, tick_size: TickSize) -> Option<Decimal> {
+    if budget.is_sign_negative() || !is_valid_price(price, tick_size) {
+        return None;
+    }
+
+    Some(round_size(budget / price))
+}
+
+/// Returns the largest placeable number of shares that a buy at `price` can afford with
+/// `balance`, once the fee of `fee_rate_bps` is reserved.
+///
+/// The fee per share is `fee_rate_bps / 10_000 * min(price, 1 - price)`, as charged by the
+/// exchange, and is reserved on top of the price so that the order cannot fail for lack of
+/// funds. Returns `None` if `price` is not strictly between `0` and `1`, or if `balance` is
+/// negative.
+#[must_use]
+pub fn max_shares_for_balance(
+    balance: Decimal,
+    price: Decimal,
+    fee_rate_bps: u32,
+) -> Option<Decimal> {
+    if balance.is_sign_negative() || price <= Decimal::ZERO || price >= Decimal::ONE {
+        return None;
+    }
+
+    let fee_rate = Decimal::from(fee_rate_bps) / BPS_PER_UNIT;
+    let fee_per_share = fee_rate * price.min(Decimal::ONE - price);
+
+    Some(round_size(balance / (price + fee_per_share)))
+}
+
+/// Returns `true` if `price` is on the grid of `tick_size`, strictly between `0` and `1`.
+fn is_valid_price(price: Decimal, tick_size: TickSize) -> bool {
+    price > Decimal::ZERO && price < Decimal::ONE && round_price(price, tick_size) == price
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn notional_should_multiply() {
+        assert_eq!(notional(dec!(21.04), dec!(0.58)), dec!(12.2032));
+        assert_eq!(notional(Decimal::ZERO, dec!(0.5)), Decimal::ZERO);
+    }
+
+    #[test]
+    fn uneven_budget_should_round_down_to_placeable_size() {
+        let shares = shares_for_budget(dec!(10), dec!(0.3), TickSize::Tenth).unwrap();
+
+        // 10 / 0.3 = 33.333...
+        assert_eq!(shares, dec!(33.33));
+        assert!(
+            notional(shares, dec!(0.3)) <= dec!(10),
+            "the size should fit in the budget"
+        );
+        assert!(
+            notional(shares + dec!(0.01), dec!(0.3)) > dec!(10),
+            "the next lot should not fit in the budget"
+        );
+    }
+
+    #[test]
+    fn even_budget_should_be_exact() {
+        assert_eq!(
+            shares_for_budget(dec!(12.2032), dec!(0.58), TickSize::Hundredth),
+            Some(dec!(21.04))
+        );
+    }
+
+    #[test]
+    fn tiny_budget_should_have_no_shares() {
+        assert_eq!(
+            shares_for_budget(dec!(0.001), dec!(0.5), TickSize::Tenth),
+            Some(Decimal::ZERO)
+        );
+    }
+
+    #[test]
+    fn invalid_prices_should_be_rejected() {
+        for price in [Decimal::ZERO, Decimal::ONE, dec!(-0.1), dec!(0.55)] {
+            assert_eq!(shares_for_budget(dec!(10), price, TickSize::Tenth), None);
+        }
+        assert_eq!(
+            shares_for_budget(dec!(-1), dec!(0.5), TickSize::Tenth),
+            None
+        );
+        assert_eq!(max_shares_for_balance(dec!(10), Decimal::ONE, 0), None);
+        assert_eq!(max_shares_for_balance(dec!(-1), dec!(0.5), 0), None);
+    }
+
+    #[test]
+    fn balance_without_fee_should_match_budget() {
+        assert_eq!(
+            max_shares_for_balance(dec!(10), dec!(0.3), 0),
+            Some(dec!(33.33))
+        );
+    }
+
+    #[test]
+    fn balance_should_reserve_fee() {
+        // 2% of min(0.4, 0.6) adds 0.008 per share, so 100 / 0.408 = 245.098...
+        let shares = max_shares_for_balance(dec!(100), dec!(0.4), 200).unwrap();
+
+        assert_eq!(shares, dec!(245.09));
+        assert!(
+            shares * dec!(0.408) <= dec!(100),
+            "the size and its fee should fit in the balance"
+        );
+    }
+}
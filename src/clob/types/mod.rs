@@ -1,8 +1,11 @@
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
 use alloy::core::sol;
 use alloy::primitives::{Signature, U256};
 use bon::Builder;
+use chrono::{DateTime, TimeDelta, Utc};
 use rust_decimal::prelude::ToPrimitive as _;
 use rust_decimal_macros::dec;
 use serde::ser::{Error as _, SerializeStruct as _};
@@ -35,7 +38,18 @@ pub use response::{
 
 #[non_exhaustive]
 #[derive(
-    Clone, Copy, Debug, Display, Default, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Display,
+    Default,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
 )]
 pub enum OrderType {
     /// Good 'til Cancelled; If not fully filled, the order rests on the book until it is explicitly
@@ -74,6 +88,55 @@ pub enum Side {
     Unknown = 255,
 }
 
+impl Side {
+    /// Returns the other side of the book, e.g. the side that closes a position. `Unknown` stays
+    /// `Unknown`.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+            Side::Unknown => Side::Unknown,
+        }
+    }
+
+    /// Returns the sign of this side in position math: `1` for buys, `-1` for sells and `0` for
+    /// `Unknown`.
+    #[must_use]
+    pub const fn sign(self) -> i8 {
+        match self {
+            Side::Buy => 1,
+            Side::Sell => -1,
+            Side::Unknown => 0,
+        }
+    }
+
+    /// Returns the side with the sign of `sign`, or `None` for `0`.
+    #[must_use]
+    pub const fn from_sign(sign: i8) -> Option<Self> {
+        match sign.signum() {
+            1 => Some(Side::Buy),
+            -1 => Some(Side::Sell),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Side {
+    type Err = Error;
+
+    /// Parses the `BUY` and `SELL` wire forms, ignoring case.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("BUY") {
+            Ok(Side::Buy)
+        } else if s.eq_ignore_ascii_case("SELL") {
+            Ok(Side::Sell)
+        } else {
+            Err(Error::validation(format!("Unable to create Side from {s}")))
+        }
+    }
+}
+
 impl TryFrom<u8> for Side {
     type Error = Error;
 
@@ -152,6 +215,23 @@ impl TimeRange {
     pub const fn from_range(start_ts: i64, end_ts: i64) -> Self {
         Self::Range { start_ts, end_ts }
     }
+
+    /// Create a time range between two instants, converted to Unix seconds.
+    #[must_use]
+    pub const fn from_datetimes(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self::from_range(start.timestamp(), end.timestamp())
+    }
+
+    /// Create a time range covering the `duration` leading up to now.
+    #[must_use]
+    pub fn last(duration: TimeDelta) -> Self {
+        let end = Utc::now();
+        let start = end
+            .checked_sub_signed(duration)
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+
+        Self::from_datetimes(start, end)
+    }
 }
 
 impl From<Interval> for TimeRange {
@@ -226,6 +306,7 @@ impl Amount {
     Debug,
     Default,
     Eq,
+    Hash,
     Ord,
     PartialEq,
     PartialOrd,
@@ -284,18 +365,24 @@ pub enum RfqSortDir {
 }
 
 #[non_exhaustive]
-#[derive(Clone, Copy, Display, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[derive(
+    Clone, Copy, Display, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize,
+)]
 #[serde(rename_all = "UPPERCASE")]
 #[strum(serialize_all = "UPPERCASE")]
 pub enum OrderStatusType {
+    /// Resting on the book
     #[serde(alias = "live")]
     Live,
+    /// Matched against a resting order
     #[serde(alias = "matched")]
     Matched,
     #[serde(alias = "canceled")]
     Canceled,
+    /// Marketable, but subject to the matching engine's delay
     #[serde(alias = "delayed")]
     Delayed,
+    /// Marketable, but the delay failed; the order was still placed
     #[serde(alias = "unmatched")]
     Unmatched,
     #[serde(other)]
@@ -304,7 +391,18 @@ pub enum OrderStatusType {
 
 #[non_exhaustive]
 #[derive(
-    Clone, Copy, Debug, Default, Display, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Display,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
 )]
 #[serde(rename_all = "UPPERCASE")]
 #[strum(serialize_all = "UPPERCASE")]
@@ -317,7 +415,7 @@ pub enum AssetType {
 }
 
 #[non_exhaustive]
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum TraderSide {
     Taker,
@@ -326,6 +424,64 @@ pub enum TraderSide {
     Unknown,
 }
 
+/// Lifecycle state of a market, derived from the flags returned by the CLOB and Gamma APIs.
+///
+/// The APIs report a market's lifecycle as several independent flags, which can conflict (e.g. a
+/// market that is both `closed` and `accepting_orders`). They are resolved with the following
+/// precedence, from highest to lowest:
+///
+/// 1. [`MarketState::Resolved`] if an outcome has been determined
+/// 2. [`MarketState::Closed`] if the market is `closed` or `archived`
+/// 3. [`MarketState::AcceptingOrders`] if the market is `active` and `accepting_orders`
+/// 4. [`MarketState::Open`] if the market is `active` but has never accepted orders
+/// 5. [`MarketState::Paused`] otherwise
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum MarketState {
+    /// Active, but not yet accepting orders
+    Open,
+    /// Active and accepting orders
+    AcceptingOrders,
+    /// Previously accepting orders, or inactive, but not closed
+    Paused,
+    /// An outcome has been determined
+    Resolved,
+    /// Closed or archived without a determined outcome
+    Closed,
+}
+
+impl MarketState {
+    #[expect(
+        clippy::fn_params_excessive_bools,
+        reason = "Mirrors the boolean flags returned by the API"
+    )]
+    pub(crate) const fn from_flags(
+        resolved: bool,
+        closed: bool,
+        active: bool,
+        accepting_orders: bool,
+        has_accepted_orders: bool,
+    ) -> Self {
+        if resolved {
+            Self::Resolved
+        } else if closed {
+            Self::Closed
+        } else if active && accepting_orders {
+            Self::AcceptingOrders
+        } else if active && !has_accepted_orders {
+            Self::Open
+        } else {
+            Self::Paused
+        }
+    }
+
+    /// Returns `true` if orders can currently be placed on the market.
+    #[must_use]
+    pub const fn is_tradable(self) -> bool {
+        matches!(self, Self::AcceptingOrders)
+    }
+}
+
 /// Represents the maximum number of decimal places for an order's price field
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
@@ -389,6 +545,14 @@ impl PartialEq for TickSize {
     }
 }
 
+impl Eq for TickSize {}
+
+impl Hash for TickSize {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_decimal().hash(state);
+    }
+}
+
 impl<'de> Deserialize<'de> for TickSize {
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -490,11 +654,195 @@ impl Serialize for SignedOrder {
     }
 }
 
+impl fmt::Display for SignableOrder {
+    /// Formats the order as e.g. `BUY 100 @ 0.52 GTC token=1234`, see [`SignedOrder`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_order(&self.order, self.order_type, f)
+    }
+}
+
+impl fmt::Display for SignedOrder {
+    /// Formats the order as `{side} {size} @ {price} {order_type} token={token_id}`, e.g.
+    /// `BUY 100 @ 0.52 GTC token=1234`, with the size in shares and the price per share recovered
+    /// from the fixed-point amounts of the order. The signature and owner are left out.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_order(&self.order, self.order_type, f)
+    }
+}
+
+fn fmt_order(order: &Order, order_type: OrderType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let side = Side::try_from(order.side).unwrap_or(Side::Unknown);
+    // Buys pay collateral for shares and sells the other way around
+    let (size, collateral) = match side {
+        Side::Sell => (order.makerAmount, order.takerAmount),
+        _ => (order.takerAmount, order.makerAmount),
+    };
+
+    match (from_fixed(size), from_fixed(collateral)) {
+        (Some(size), Some(collateral)) if !size.is_zero() => {
+            let price = (collateral / size).round_dp(USDC_DECIMALS).normalize();
+            write!(
+                f,
+                "{side} {} @ {price} {order_type} token={}",
+                size.normalize(),
+                order.tokenId
+            )
+        }
+        _ => write!(f, "{side} {order_type} token={}", order.tokenId),
+    }
+}
+
+/// Converts an amount of an [`Order`] back from its fixed-point form with [`USDC_DECIMALS`].
+fn from_fixed(amount: U256) -> Option<Decimal> {
+    let amount = i64::try_from(amount).ok()?;
+    Some(Decimal::new(amount, USDC_DECIMALS))
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::{BTreeMap, HashSet};
+
     use super::*;
     use crate::error::Validation;
 
+    #[test]
+    fn orders_should_display_side_size_and_price() {
+        let order = Order {
+            tokenId: U256::from(1234),
+            makerAmount: U256::from(52_000_000),
+            takerAmount: U256::from(100_000_000),
+            side: Side::Buy as u8,
+            ..Order::default()
+        };
+        let signable = SignableOrder::builder()
+            .order(order)
+            .order_type(OrderType::GTC)
+            .build();
+        assert_eq!(signable.to_string(), "BUY 100 @ 0.52 GTC token=1234");
+
+        let sell = Order {
+            tokenId: U256::from(1234),
+            makerAmount: U256::from(25_500_000),
+            takerAmount: U256::from(10_000_000),
+            side: Side::Sell as u8,
+            ..Order::default()
+        };
+        let signed = SignedOrder::builder()
+            .order(sell)
+            .signature(Signature::new(U256::ZERO, U256::ZERO, false))
+            .order_type(OrderType::FOK)
+            .owner(ApiKey::nil())
+            .build();
+        assert_eq!(signed.to_string(), "SELL 25.5 @ 0.392157 FOK token=1234");
+
+        let empty = SignableOrder::builder()
+            .order(Order::default())
+            .order_type(OrderType::GTC)
+            .build();
+        assert_eq!(empty.to_string(), "BUY GTC token=0");
+    }
+
+    #[test]
+    fn enums_should_be_usable_as_collection_keys() {
+        let sides: HashSet<Side> = [Side::Buy, Side::Sell, Side::Buy].into_iter().collect();
+        assert_eq!(sides.len(), 2);
+
+        let tick_sizes: HashSet<TickSize> =
+            [TickSize::Hundredth, TickSize::Tenth].into_iter().collect();
+        assert!(
+            tick_sizes.contains(&TickSize::try_from(dec!(0.01)).unwrap()),
+            "tick sizes should hash by value"
+        );
+
+        let mut by_status = BTreeMap::new();
+        by_status.insert(OrderStatusType::Matched, 2);
+        by_status.insert(OrderStatusType::Live, 1);
+        assert_eq!(by_status.get(&OrderStatusType::Live), Some(&1));
+
+        let order_types: HashSet<(OrderType, SignatureType, AssetType, TraderSide)> = [
+            (
+                OrderType::GTC,
+                SignatureType::Eoa,
+                AssetType::Collateral,
+                TraderSide::Maker,
+            ),
+            (
+                OrderType::GTC,
+                SignatureType::Eoa,
+                AssetType::Collateral,
+                TraderSide::Maker,
+            ),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(order_types.len(), 1);
+    }
+
+    #[test]
+    fn time_range_from_datetimes_should_use_seconds() {
+        let start: DateTime<Utc> = "2024-01-20T00:00:00Z".parse().unwrap();
+        let end: DateTime<Utc> = "2024-01-21T00:00:00.999Z".parse().unwrap();
+
+        let TimeRange::Range { start_ts, end_ts } = TimeRange::from_datetimes(start, end) else {
+            panic!("expected a range");
+        };
+
+        assert_eq!(start_ts, 1_705_708_800);
+        assert_eq!(end_ts, 1_705_795_200);
+    }
+
+    #[test]
+    fn time_range_last_should_span_duration() {
+        let before = Utc::now().timestamp();
+        let TimeRange::Range { start_ts, end_ts } = TimeRange::last(TimeDelta::hours(6)) else {
+            panic!("expected a range");
+        };
+        let after = Utc::now().timestamp();
+
+        assert_eq!(end_ts - start_ts, 6 * 60 * 60);
+        assert!(
+            (before..=after).contains(&end_ts),
+            "range should end at the current time"
+        );
+    }
+
+    #[test]
+    fn market_state_from_flags_should_succeed() {
+        // (resolved, closed, active, accepting_orders, has_accepted_orders) => state
+        let cases = [
+            ((true, true, true, true, true), MarketState::Resolved),
+            ((true, false, false, false, false), MarketState::Resolved),
+            ((false, true, true, true, true), MarketState::Closed),
+            ((false, true, false, false, false), MarketState::Closed),
+            (
+                (false, false, true, true, true),
+                MarketState::AcceptingOrders,
+            ),
+            (
+                (false, false, true, true, false),
+                MarketState::AcceptingOrders,
+            ),
+            ((false, false, true, false, false), MarketState::Open),
+            ((false, false, true, false, true), MarketState::Paused),
+            ((false, false, false, false, false), MarketState::Paused),
+            ((false, false, false, true, true), MarketState::Paused),
+        ];
+
+        for ((resolved, closed, active, accepting, accepted), expected) in cases {
+            assert_eq!(
+                MarketState::from_flags(resolved, closed, active, accepting, accepted),
+                expected,
+                "flags: resolved={resolved} closed={closed} active={active} accepting={accepting} accepted={accepted}"
+            );
+        }
+
+        assert!(
+            MarketState::AcceptingOrders.is_tradable(),
+            "should be tradable"
+        );
+        assert!(!MarketState::Paused.is_tradable(), "should not be tradable");
+    }
+
     #[test]
     fn tick_size_decimals_should_succeed() {
         assert_eq!(TickSize::Tenth.as_decimal().scale(), 1);
@@ -584,4 +932,42 @@ mod tests {
         assert_eq!(Side::Buy.to_string(), "BUY");
         assert_eq!(Side::Sell.to_string(), "SELL");
     }
+
+    #[test]
+    fn side_opposite_should_flip() {
+        assert_eq!(Side::Buy.opposite(), Side::Sell);
+        assert_eq!(Side::Sell.opposite(), Side::Buy);
+        assert_eq!(Side::Unknown.opposite(), Side::Unknown);
+    }
+
+    #[test]
+    fn side_sign_should_round_trip() {
+        for side in [Side::Buy, Side::Sell] {
+            assert_eq!(Side::from_sign(side.sign()), Some(side));
+            assert_eq!(side.opposite().sign(), -side.sign());
+        }
+
+        assert_eq!(Side::Unknown.sign(), 0);
+        assert_eq!(Side::from_sign(0), None);
+        assert_eq!(Side::from_sign(5), Some(Side::Buy));
+        assert_eq!(Side::from_sign(i8::MIN), Some(Side::Sell));
+    }
+
+    #[test]
+    fn side_from_str_should_ignore_case() {
+        for (input, side) in [
+            ("BUY", Side::Buy),
+            ("buy", Side::Buy),
+            ("Sell", Side::Sell),
+            ("SELL", Side::Sell),
+        ] {
+            assert_eq!(input.parse::<Side>().unwrap(), side);
+        }
+
+        for side in [Side::Buy, Side::Sell] {
+            assert_eq!(side.to_string().parse::<Side>().unwrap(), side);
+        }
+
+        "hold".parse::<Side>().unwrap_err();
+    }
 }
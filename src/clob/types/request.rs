@@ -10,22 +10,21 @@ use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as, skip_se
 
 #[cfg(feature = "rfq")]
 use crate::auth::ApiKey;
+use crate::clob::pagination::Cursor;
 use crate::clob::types::{AssetType, Side, SignatureType, TimeRange};
 #[cfg(feature = "rfq")]
 use crate::clob::types::{RfqSortBy, RfqSortDir, RfqState};
-use crate::types::Address;
-#[cfg(feature = "rfq")]
-use crate::types::Decimal;
+use crate::types::{Address, Decimal};
 
 #[non_exhaustive]
-#[derive(Debug, Serialize, Builder)]
+#[derive(Debug, Clone, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct MidpointRequest {
     pub token_id: String,
 }
 
 #[non_exhaustive]
-#[derive(Debug, Serialize, Builder)]
+#[derive(Debug, Clone, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct PriceRequest {
     pub token_id: String,
@@ -34,7 +33,7 @@ pub struct PriceRequest {
 
 #[non_exhaustive]
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Builder)]
+#[derive(Debug, Clone, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct SpreadRequest {
     pub token_id: String,
@@ -43,7 +42,7 @@ pub struct SpreadRequest {
 
 #[non_exhaustive]
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Builder)]
+#[derive(Debug, Clone, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct OrderBookSummaryRequest {
     pub token_id: String,
@@ -51,7 +50,7 @@ pub struct OrderBookSummaryRequest {
 }
 
 #[non_exhaustive]
-#[derive(Debug, Serialize, Builder)]
+#[derive(Debug, Clone, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct LastTradePriceRequest {
     pub token_id: String,
@@ -96,6 +95,34 @@ pub struct TradesRequest {
     pub after: Option<i64>,
 }
 
+/// Server-side filters of [`Client::markets_with_filter`](crate::clob::Client::markets_with_filter).
+/// Unset filters are left out of the query, so that every market matches them.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy, Serialize, Builder)]
+pub struct MarketsRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+/// Client-side filter of
+/// [`Client::sampling_simplified_markets_with_filter`](crate::clob::Client::sampling_simplified_markets_with_filter),
+/// applied to each page after it is fetched since the server cannot filter by reward rate.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Builder)]
+#[builder(on(String, into))]
+pub struct SamplingFilter {
+    /// Keeps only the markets whose [`total_daily_rate`](crate::clob::types::response::Rewards::total_daily_rate)
+    /// is at least this. Unset keeps every market.
+    pub min_daily_rate: Option<Decimal>,
+    /// Cursor of the page to fetch, or `None` for the first page
+    #[builder(into)]
+    pub next_cursor: Option<Cursor>,
+}
+
 #[non_exhaustive]
 #[derive(Debug, Default, Serialize, Builder)]
 #[builder(on(String, into))]
@@ -394,6 +421,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn markets_request_as_params_should_skip_unset_filters() {
+        let flags = [None, Some(true), Some(false)];
+
+        for active in flags {
+            for closed in flags {
+                for archived in flags {
+                    let request = MarketsRequest {
+                        active,
+                        closed,
+                        archived,
+                    };
+                    let expected: Vec<_> = [("active", active), ("closed", closed)]
+                        .into_iter()
+                        .chain([("archived", archived)])
+                        .filter_map(|(key, value)| value.map(|value| format!("{key}={value}")))
+                        .collect();
+                    let expected = if expected.is_empty() {
+                        String::new()
+                    } else {
+                        format!("?{}", expected.join("&"))
+                    };
+
+                    assert_eq!(request.query_params(None), expected, "{request:?}");
+                }
+            }
+        }
+
+        let request = MarketsRequest::builder().active(true).build();
+        assert_eq!(
+            request.query_params(Some("MTAw")),
+            "?active=true&next_cursor=MTAw"
+        );
+        assert_eq!(
+            MarketsRequest::default().query_params(Some("MTAw")),
+            "?next_cursor=MTAw"
+        );
+        assert_eq!(
+            MarketsRequest::default().query_params(Some("a+b/c=")),
+            "?next_cursor=a%2Bb%2Fc%3D",
+            "base64 cursors should be percent-encoded"
+        );
+    }
+
     #[test]
     fn orders_request_as_params_should_succeed() {
         let request = OrdersRequest::builder()
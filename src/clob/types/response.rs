@@ -4,6 +4,8 @@
 )]
 
 use std::collections::HashMap;
+use std::ops::Deref;
+use std::str::FromStr as _;
 
 use bon::Builder;
 use chrono::{DateTime, NaiveDate, Utc};
@@ -13,8 +15,9 @@ use sha2::{Digest as _, Sha256};
 
 use crate::Result;
 use crate::auth::ApiKey;
-use crate::clob::types::{OrderStatusType, OrderType, Side, TickSize, TraderSide};
-use crate::serde_helpers::StringFromAny;
+use crate::clob::pagination::Cursor;
+use crate::clob::types::{MarketState, OrderStatusType, OrderType, Side, TickSize, TraderSide};
+use crate::serde_helpers::{BoolFromAny, StringFromAny, TimestampFromAny};
 use crate::types::{Address, Decimal};
 
 #[non_exhaustive]
@@ -76,8 +79,10 @@ pub struct TickSizeResponse {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Clone, Debug, Deserialize, Builder, PartialEq)]
 pub struct NegRiskResponse {
+    #[serde_as(as = "BoolFromAny")]
     pub neg_risk: bool,
 }
 
@@ -92,9 +97,11 @@ pub struct FeeRateResponse {
 /// This indicates whether the requesting IP address is blocked from placing orders
 /// due to geographic restrictions.
 #[non_exhaustive]
+#[serde_as]
 #[derive(Clone, Debug, Deserialize, Builder, PartialEq)]
 pub struct GeoblockResponse {
     /// Whether the user is blocked from placing orders
+    #[serde_as(as = "BoolFromAny")]
     pub blocked: bool,
     /// The detected IP address
     pub ip: String,
@@ -124,6 +131,7 @@ pub struct OrderBookSummaryResponse {
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub asks: Vec<OrderSummary>,
     pub min_order_size: Decimal,
+    #[serde_as(as = "BoolFromAny")]
     pub neg_risk: bool,
     #[serde_as(as = "TryFromInto<Decimal>")]
     pub tick_size: TickSize,
@@ -149,19 +157,53 @@ pub struct OrderSummary {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Deserialize, Builder, PartialEq)]
 pub struct LastTradePriceResponse {
     pub price: Decimal,
     pub side: Side,
+    /// Size of the last trade, when reported by the server
+    pub size: Option<Decimal>,
+    /// Time of the last trade, when reported by the server
+    #[serde(default)]
+    #[serde_as(as = "Option<TimestampFromAny>")]
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+/// A recent trade of a market as shown in its public activity feed, see
+/// [`Client::market_trades`](crate::clob::Client::market_trades). The counterparties are not
+/// included.
+#[non_exhaustive]
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
+pub struct PublicTrade {
+    pub price: Decimal,
+    pub size: Decimal,
+    /// Side of the taker of the trade
+    pub side: Side,
+    /// Outcome that was traded, e.g. `Yes`
+    #[builder(into)]
+    pub outcome: Option<String>,
+    #[builder(into)]
+    pub transaction_hash: Option<String>,
+    #[serde_as(as = "TimestampFromAny")]
+    pub timestamp: DateTime<Utc>,
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct LastTradesPricesResponse {
     pub token_id: String,
     pub price: Decimal,
     pub side: Side,
+    /// Size of the last trade, when reported by the server
+    pub size: Option<Decimal>,
+    /// Time of the last trade, when reported by the server
+    #[serde(default)]
+    #[serde_as(as = "Option<TimestampFromAny>")]
+    pub timestamp: Option<DateTime<Utc>>,
 }
 
 #[expect(
@@ -173,10 +215,15 @@ pub struct LastTradesPricesResponse {
 #[derive(Debug, Deserialize, Clone, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct MarketResponse {
+    #[serde_as(as = "BoolFromAny")]
     pub enable_order_book: bool,
+    #[serde_as(as = "BoolFromAny")]
     pub active: bool,
+    #[serde_as(as = "BoolFromAny")]
     pub closed: bool,
+    #[serde_as(as = "BoolFromAny")]
     pub archived: bool,
+    #[serde_as(as = "BoolFromAny")]
     pub accepting_orders: bool,
     pub accepting_order_timestamp: Option<DateTime<Utc>>,
     pub minimum_order_size: Decimal,
@@ -192,13 +239,16 @@ pub struct MarketResponse {
     pub fpmm: String,
     pub maker_base_fee: Decimal,
     pub taker_base_fee: Decimal,
+    #[serde_as(as = "BoolFromAny")]
     pub notifications_enabled: bool,
+    #[serde_as(as = "BoolFromAny")]
     pub neg_risk: bool,
     pub neg_risk_market_id: String,
     pub neg_risk_request_id: String,
     pub icon: String,
     pub image: String,
     pub rewards: Rewards,
+    #[serde_as(as = "BoolFromAny")]
     pub is_50_50_outcome: bool,
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
@@ -208,7 +258,41 @@ pub struct MarketResponse {
     pub tags: Vec<String>,
 }
 
+impl MarketResponse {
+    /// Returns the lifecycle state of this market. A market is considered resolved once one of
+    /// its tokens has been marked as the winner. See [`MarketState`] for how conflicting flags
+    /// are resolved.
+    #[must_use]
+    pub fn state(&self) -> MarketState {
+        MarketState::from_flags(
+            self.tokens.iter().any(|token| token.winner),
+            self.closed || self.archived,
+            self.active,
+            self.accepting_orders,
+            self.accepting_order_timestamp.is_some(),
+        )
+    }
+
+    /// Returns the token to trade and the side to place for buying or selling `outcome`,
+    /// matched case-insensitively against the outcomes of [`Self::tokens`], or `None` if the
+    /// market has no such outcome.
+    ///
+    /// The mapping is literal: selling `"No"` sells the `"No"` token and is never flipped to
+    /// buying the `"Yes"` token, even though both positions are economically equivalent.
+    #[must_use]
+    pub fn order_token_and_side(&self, outcome: &str, buy: bool) -> Option<(String, Side)> {
+        let token = self
+            .tokens
+            .iter()
+            .find(|token| token.outcome.eq_ignore_ascii_case(outcome.trim()))?;
+        let side = if buy { Side::Buy } else { Side::Sell };
+
+        Some((token.token_id.clone(), side))
+    }
+}
+
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct Token {
@@ -216,6 +300,7 @@ pub struct Token {
     pub outcome: String,
     pub price: Decimal,
     #[serde(default)]
+    #[serde_as(as = "BoolFromAny")]
     pub winner: bool,
 }
 
@@ -233,9 +318,13 @@ pub struct SimplifiedMarketResponse {
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub tokens: Vec<Token>,
     pub rewards: Rewards,
+    #[serde_as(as = "BoolFromAny")]
     pub active: bool,
+    #[serde_as(as = "BoolFromAny")]
     pub closed: bool,
+    #[serde_as(as = "BoolFromAny")]
     pub archived: bool,
+    #[serde_as(as = "BoolFromAny")]
     pub accepting_orders: bool,
 }
 
@@ -247,8 +336,10 @@ pub struct ApiKeysResponse {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 pub struct BanStatusResponse {
+    #[serde_as(as = "BoolFromAny")]
     pub closed_only: bool,
 }
 
@@ -266,6 +357,7 @@ pub struct PostOrderResponse {
     #[serde(rename = "orderID")]
     pub order_id: String,
     pub status: OrderStatusType,
+    #[serde_as(as = "BoolFromAny")]
     pub success: bool,
     #[builder(default)]
     #[serde(default)]
@@ -278,6 +370,45 @@ pub struct PostOrderResponse {
     pub trade_ids: Vec<String>,
 }
 
+impl PostOrderResponse {
+    /// Returns how the matching engine handled the order.
+    #[must_use]
+    pub const fn status(&self) -> OrderStatusType {
+        self.status
+    }
+
+    /// Returns the ID of the posted order.
+    #[must_use]
+    pub fn order_id(&self) -> &str {
+        &self.order_id
+    }
+
+    /// Returns `true` if the order crossed the book when it was posted, i.e. it was matched, is
+    /// waiting out a matching delay, or could not be delayed.
+    #[must_use]
+    pub const fn is_marketable(&self) -> bool {
+        matches!(
+            self.status,
+            OrderStatusType::Matched | OrderStatusType::Delayed | OrderStatusType::Unmatched
+        )
+    }
+
+    /// Returns `true` if the order is marketable but held back by the matching engine's delay,
+    /// e.g. on sports markets. Its fills are only known once the delay has passed, so poll the
+    /// order or watch the user channel instead of treating it as resting or filled.
+    #[must_use]
+    pub const fn is_delayed(&self) -> bool {
+        matches!(self.status, OrderStatusType::Delayed)
+    }
+
+    /// Returns `true` if the order was accepted and matched, at least partially, when it was
+    /// posted. The matched amounts are `making_amount` and `taking_amount`.
+    #[must_use]
+    pub const fn was_filled(&self) -> bool {
+        self.success && matches!(self.status, OrderStatusType::Matched)
+    }
+}
+
 pub fn empty_string_as_zero<'de, D>(deserializer: D) -> std::result::Result<Decimal, D::Error>
 where
     D: Deserializer<'de>,
@@ -365,11 +496,22 @@ pub struct TradeResponse {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 pub struct NotificationResponse {
+    /// ID to acknowledge the notification with, see
+    /// [`DeleteNotificationsRequest`](crate::clob::types::request::DeleteNotificationsRequest)
+    #[serde(default)]
+    #[serde_as(as = "Option<StringFromAny>")]
+    #[builder(into)]
+    pub id: Option<String>,
     pub r#type: u32,
     pub owner: ApiKey,
     pub payload: NotificationPayload,
+    /// When the notification was created, when reported by the server
+    #[serde(default)]
+    #[serde_as(as = "Option<TimestampFromAny>")]
+    pub timestamp: Option<DateTime<Utc>>,
 }
 
 #[non_exhaustive]
@@ -420,8 +562,10 @@ pub struct BalanceAllowanceResponse {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 pub struct OrderScoringResponse {
+    #[serde_as(as = "BoolFromAny")]
     pub scoring: bool,
 }
 
@@ -450,9 +594,49 @@ pub struct Rewards {
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub rates: Vec<RewardRate>,
     pub min_size: Decimal,
+    /// Maximum distance from the midpoint, in cents, for an order to earn rewards
     pub max_spread: Decimal,
 }
 
+impl Rewards {
+    /// Returns the daily reward rate paid in the asset at `asset_address`, if this market pays
+    /// rewards in that asset. The address is compared case-insensitively.
+    #[must_use]
+    pub fn daily_rate(&self, asset_address: &str) -> Option<Decimal> {
+        let asset_address = Address::from_str(asset_address).ok()?;
+
+        self.rates
+            .iter()
+            .find(|rate| rate.asset_address == asset_address)
+            .map(|rate| rate.rewards_daily_rate)
+    }
+
+    /// Returns the sum of the daily reward rates of this market across all assets, which is
+    /// `0` for a market without rewards.
+    #[must_use]
+    pub fn total_daily_rate(&self) -> Decimal {
+        self.rates.iter().map(|rate| rate.rewards_daily_rate).sum()
+    }
+
+    /// Returns `true` if a resting order at `order_price` for `order_size` shares would be
+    /// eligible for liquidity rewards given the current `midpoint`.
+    ///
+    /// An order is eligible when its size is at least `min_size` and its distance from the
+    /// midpoint is strictly less than `max_spread`, which is quoted in cents. Orders exactly at
+    /// the maximum spread score zero, so they are not considered eligible.
+    #[must_use]
+    pub fn is_eligible(
+        &self,
+        order_price: Decimal,
+        order_size: Decimal,
+        midpoint: Decimal,
+    ) -> bool {
+        let max_spread = self.max_spread / Decimal::ONE_HUNDRED;
+
+        order_size >= self.min_size && (order_price - midpoint).abs() < max_spread
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
@@ -668,6 +852,75 @@ pub struct Page<T> {
     pub count: u64,
 }
 
+/// A page derefs to the slice of its `data` and iterates over it, so it can be used like a
+/// collection directly:
+///
+/// ```
+/// use polymarket_client_sdk::clob::types::response::Page;
+///
+/// let page = Page::builder()
+///     .data(vec![1_u64, 2, 3])
+///     .next_cursor("LTE=")
+///     .limit(3)
+///     .count(3)
+///     .build();
+///
+/// assert_eq!(page.len(), 3);
+/// assert_eq!(page[0], 1);
+/// assert!(page.cursor().is_end());
+///
+/// let mut sum = 0;
+/// for value in &page {
+///     sum += value;
+/// }
+/// assert_eq!(sum, page.into_iter().sum::<u64>());
+/// ```
+impl<T> Page<T> {
+    /// Returns the number of items on this page.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if this page holds no items.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the [`Cursor`] to request the next page with.
+    #[must_use]
+    pub fn cursor(&self) -> Cursor {
+        Cursor::from(self.next_cursor.as_str())
+    }
+}
+
+impl<T> Deref for Page<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'page, T> IntoIterator for &'page Page<T> {
+    type Item = &'page T;
+    type IntoIter = std::slice::Iter<'page, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
 /// Response from creating an RFQ request.
 #[cfg(feature = "rfq")]
 #[non_exhaustive]
@@ -772,3 +1025,216 @@ pub struct RfqQuote {
     /// Quoted price.
     pub price: Decimal,
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::types::address;
+
+    const USDC: Address = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+
+    fn rewards() -> Rewards {
+        Rewards::builder()
+            .rates(vec![
+                RewardRate::builder()
+                    .asset_address(USDC)
+                    .rewards_daily_rate(dec!(25))
+                    .build(),
+            ])
+            .min_size(dec!(50))
+            .max_spread(dec!(3.5))
+            .build()
+    }
+
+    fn binary_market() -> MarketResponse {
+        let token = |token_id: &str, outcome: &str| {
+            Token::builder()
+                .token_id(token_id)
+                .outcome(outcome)
+                .price(dec!(0.5))
+                .winner(false)
+                .build()
+        };
+
+        MarketResponse::builder()
+            .enable_order_book(true)
+            .active(true)
+            .closed(false)
+            .archived(false)
+            .accepting_orders(true)
+            .minimum_order_size(Decimal::ONE)
+            .minimum_tick_size(dec!(0.01))
+            .condition_id("0xcondition")
+            .question_id("0xquestion")
+            .question("Will it rain tomorrow?")
+            .description("")
+            .market_slug("rain-tomorrow")
+            .seconds_delay(0)
+            .fpmm("")
+            .maker_base_fee(Decimal::ZERO)
+            .taker_base_fee(Decimal::ZERO)
+            .notifications_enabled(false)
+            .neg_risk(false)
+            .neg_risk_market_id("")
+            .neg_risk_request_id("")
+            .icon("")
+            .image("")
+            .rewards(rewards())
+            .is_50_50_outcome(false)
+            .tokens(vec![token("YES_TOKEN", "Yes"), token("NO_TOKEN", "No")])
+            .tags(vec![])
+            .build()
+    }
+
+    fn post_order_response(status: &str) -> PostOrderResponse {
+        serde_json::from_value(serde_json::json!({
+            "errorMsg": "",
+            "makingAmount": "",
+            "takingAmount": "",
+            "orderID": "0xorder",
+            "status": status,
+            "success": true
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn order_token_and_side_should_map_each_outcome() {
+        let market = binary_market();
+
+        for (outcome, buy, token_id, side) in [
+            ("Yes", true, "YES_TOKEN", Side::Buy),
+            ("Yes", false, "YES_TOKEN", Side::Sell),
+            ("No", true, "NO_TOKEN", Side::Buy),
+            ("No", false, "NO_TOKEN", Side::Sell),
+        ] {
+            assert_eq!(
+                market.order_token_and_side(outcome, buy),
+                Some((token_id.to_owned(), side)),
+                "{outcome} with buy = {buy}"
+            );
+        }
+    }
+
+    #[test]
+    fn order_token_and_side_should_ignore_case() {
+        let market = binary_market();
+
+        assert_eq!(
+            market.order_token_and_side(" YES ", true),
+            Some(("YES_TOKEN".to_owned(), Side::Buy))
+        );
+        assert_eq!(market.order_token_and_side("Maybe", true), None);
+    }
+
+    #[test]
+    fn post_order_response_should_parse_each_status() {
+        let cases = [
+            ("live", OrderStatusType::Live),
+            ("matched", OrderStatusType::Matched),
+            ("delayed", OrderStatusType::Delayed),
+            ("unmatched", OrderStatusType::Unmatched),
+            ("CANCELED", OrderStatusType::Canceled),
+            ("paused", OrderStatusType::Unknown),
+        ];
+
+        for (raw, expected) in cases {
+            let response = post_order_response(raw);
+            assert_eq!(response.status(), expected, "status {raw}");
+            assert_eq!(response.order_id(), "0xorder");
+        }
+    }
+
+    #[test]
+    fn post_order_response_should_interpret_status() {
+        let live = post_order_response("live");
+        assert!(!live.is_marketable(), "a resting order is not marketable");
+        assert!(!live.was_filled(), "a resting order is not filled");
+
+        let matched = post_order_response("matched");
+        assert!(matched.is_marketable(), "a matched order is marketable");
+        assert!(matched.was_filled(), "a matched order is filled");
+
+        let delayed = post_order_response("delayed");
+        assert!(delayed.is_marketable(), "a delayed order is marketable");
+        assert!(delayed.is_delayed(), "a delayed order should be reported");
+        assert!(!delayed.was_filled(), "a delayed order is not filled yet");
+
+        let unmatched = post_order_response("unmatched");
+        assert!(
+            unmatched.is_marketable(),
+            "an unmatched order is marketable"
+        );
+        assert!(!unmatched.was_filled(), "an unmatched order is not filled");
+    }
+
+    #[test]
+    fn total_daily_rate_should_sum_assets() {
+        let mut rewards = rewards();
+        rewards.rates.push(
+            RewardRate::builder()
+                .asset_address(Address::ZERO)
+                .rewards_daily_rate(dec!(5.5))
+                .build(),
+        );
+
+        assert_eq!(rewards.total_daily_rate(), dec!(30.5));
+        rewards.rates.clear();
+        assert_eq!(rewards.total_daily_rate(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn daily_rate_should_succeed() {
+        let rewards = rewards();
+
+        assert_eq!(
+            rewards.daily_rate("0x2791bca1f2de4661ed88a30c99a7a9449aa84174"),
+            Some(dec!(25))
+        );
+        assert_eq!(
+            rewards.daily_rate("0x0000000000000000000000000000000000000001"),
+            None
+        );
+        assert_eq!(rewards.daily_rate("not an address"), None);
+    }
+
+    #[test]
+    fn is_eligible_inside_spread_band() {
+        let rewards = rewards();
+
+        assert!(
+            rewards.is_eligible(dec!(0.48), dec!(100), dec!(0.50)),
+            "2c below the midpoint should be eligible"
+        );
+        assert!(
+            rewards.is_eligible(dec!(0.53), dec!(50), dec!(0.50)),
+            "3c above the midpoint at exactly min size should be eligible"
+        );
+    }
+
+    #[test]
+    fn is_eligible_outside_spread_band() {
+        let rewards = rewards();
+
+        assert!(
+            !rewards.is_eligible(dec!(0.46), dec!(100), dec!(0.50)),
+            "4c from the midpoint should not be eligible"
+        );
+        assert!(
+            !rewards.is_eligible(dec!(0.535), dec!(100), dec!(0.50)),
+            "exactly the max spread should not be eligible"
+        );
+    }
+
+    #[test]
+    fn is_eligible_below_min_size() {
+        let rewards = rewards();
+
+        assert!(
+            !rewards.is_eligible(dec!(0.50), dec!(49.99), dec!(0.50)),
+            "below the minimum size should not be eligible"
+        );
+    }
+}
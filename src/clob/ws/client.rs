@@ -7,19 +7,20 @@ use futures::StreamExt as _;
 use once_cell::sync::OnceCell;
 
 use super::interest::InterestTracker;
-use super::subscription::{ChannelType, SubscriptionManager};
+use super::subscription::{ChannelType, MarketChannel, SubscriptionManager};
 use super::types::response::{
     BestBidAsk, BookUpdate, MarketResolved, MidpointUpdate, NewMarket, OrderMessage, PriceChange,
     TradeMessage, WsMessage,
 };
 use crate::Result;
 use crate::auth::state::{Authenticated, State, Unauthenticated};
-use crate::auth::{Credentials, Kind as AuthKind, Normal};
+use crate::auth::{Credentials, CurrentCredentials, Kind as AuthKind, Normal};
 use crate::error::Error;
 use crate::types::{Address, Decimal};
 use crate::ws::ConnectionManager;
 use crate::ws::config::Config;
 use crate::ws::connection::ConnectionState;
+use crate::ws::stats::WsStats;
 
 /// WebSocket client for real-time market data and user updates.
 ///
@@ -136,7 +137,7 @@ impl Client<Unauthenticated> {
             inner: Arc::new(ClientInner {
                 state: Authenticated {
                     address,
-                    credentials,
+                    credentials: CurrentCredentials::new(credentials),
                     kind: Normal,
                 },
                 config,
@@ -271,6 +272,16 @@ impl<S: State> Client<S> {
         }))
     }
 
+    /// Subscribe to market data for specific assets through a [`MarketChannel`].
+    ///
+    /// Send [`WsCommand`](super::WsCommand)s through [`MarketChannel::command_sender`] to add and
+    /// remove assets while the channel runs, without tearing down the socket.
+    pub fn market_channel(&self, asset_ids: Vec<String>) -> Result<MarketChannel> {
+        self.market_resources()?
+            .subscriptions
+            .subscribe_market_channel(asset_ids, false)
+    }
+
     /// Get the current connection state.
     ///
     /// Returns [`ConnectionState::Disconnected`] if the connection has not been
@@ -283,6 +294,17 @@ impl<S: State> Client<S> {
         )
     }
 
+    /// Get a snapshot of the traffic on a channel, e.g. to check that a stream is healthy.
+    ///
+    /// Returns empty stats if the connection has not been initialized yet (no subscriptions
+    /// have been made).
+    #[must_use]
+    pub fn stats(&self, channel_type: ChannelType) -> WsStats {
+        self.inner
+            .channel(channel_type)
+            .map_or_else(WsStats::default, ChannelHandles::stats)
+    }
+
     /// Check if the WebSocket connection has been initialized.
     ///
     /// Returns `false` if no subscriptions have been made yet.
@@ -351,9 +373,10 @@ impl<K: AuthKind> Client<Authenticated<K>> {
     ) -> Result<impl Stream<Item = Result<WsMessage>>> {
         let resources = self.user_resources()?;
 
-        resources
-            .subscriptions
-            .subscribe_user(markets, &self.inner.state.credentials)
+        resources.subscriptions.subscribe_user(
+            markets,
+            Credentials::clone(&self.inner.state.credentials.get()),
+        )
     }
 
     /// Subscribe to user's order updates.
@@ -511,6 +534,14 @@ impl ChannelHandles {
             .get()
             .map_or(ConnectionState::Disconnected, |r| r.connection.state())
     }
+
+    fn stats(&self) -> WsStats {
+        self.resources.get().map_or_else(WsStats::default, |r| {
+            let mut stats = r.connection.stats();
+            stats.subscriptions = r.subscriptions.subscription_count();
+            stats
+        })
+    }
 }
 
 fn normalize_base_endpoint(endpoint: &str) -> String {
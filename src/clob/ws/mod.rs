@@ -12,7 +12,9 @@ pub mod types;
 // Re-export commonly used types
 pub use client::Client;
 pub use error::WsError;
-pub use subscription::{ChannelType, SubscriptionInfo, SubscriptionTarget};
+pub use subscription::{
+    ChannelType, MarketChannel, SubscriptionInfo, SubscriptionTarget, WsCommand,
+};
 pub use types::request::SubscriptionRequest;
 pub use types::response::{
     BestBidAsk, BookUpdate, EventMessage, LastTradePrice, MakerOrder, MarketResolved,
@@ -4,13 +4,17 @@
 )]
 
 use std::collections::{HashMap, HashSet};
-use std::sync::{Arc, PoisonError, RwLock};
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+use std::task::{Context, Poll};
 use std::time::Instant;
 
-use async_stream::try_stream;
+use async_stream::stream;
 use dashmap::{DashMap, Entry};
 use futures::Stream;
 use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::mpsc;
 
 use super::error::WsError;
 use super::interest::{InterestTracker, MessageInterest};
@@ -19,7 +23,7 @@ use super::types::response::WsMessage;
 use crate::Result;
 use crate::auth::Credentials;
 use crate::ws::ConnectionManager;
-use crate::ws::connection::ConnectionState;
+use crate::ws::connection::{AbortOnDrop, ConnectionState, Frame};
 
 /// What a subscription is targeting.
 #[non_exhaustive]
@@ -69,6 +73,93 @@ pub enum ChannelType {
     User,
 }
 
+/// Capacity of the command channel of a [`MarketChannel`].
+const COMMAND_CAPACITY: usize = 32;
+
+/// A command changing the assets of a running [`MarketChannel`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WsCommand {
+    /// Start receiving market data for these assets.
+    Subscribe(Vec<String>),
+    /// Stop receiving market data for these assets.
+    Unsubscribe(Vec<String>),
+    /// Unsubscribe from every asset of the channel and end its stream.
+    Close,
+}
+
+/// A market data subscription whose assets can be changed while it runs.
+///
+/// `MarketChannel` is a [`Stream`] of market messages. Its loop selects between incoming frames
+/// and [`WsCommand`]s sent through [`MarketChannel::command_sender`], so assets can be added and
+/// removed without tearing down the socket. Commands are applied while the stream is polled.
+/// Dropping the channel unsubscribes from its assets, as [`WsCommand::Close`] does.
+pub struct MarketChannel {
+    commands: mpsc::Sender<WsCommand>,
+    stream: Pin<Box<dyn Stream<Item = Result<WsMessage>> + Send>>,
+}
+
+impl MarketChannel {
+    /// Returns a sender for commands controlling this channel.
+    #[must_use]
+    pub fn command_sender(&self) -> mpsc::Sender<WsCommand> {
+        self.commands.clone()
+    }
+}
+
+impl Stream for MarketChannel {
+    type Item = Result<WsMessage>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
+
+impl fmt::Debug for MarketChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MarketChannel").finish_non_exhaustive()
+    }
+}
+
+/// The next event handled by a [`MarketChannel`] loop.
+#[expect(
+    clippy::large_enum_variant,
+    reason = "Events are short-lived and matched immediately; boxing frames would add an allocation per message"
+)]
+enum ChannelEvent {
+    Frame(std::result::Result<Frame<WsMessage>, RecvError>),
+    Command(Option<WsCommand>),
+}
+
+/// The assets of a running [`MarketChannel`], unsubscribed from when its stream ends or the
+/// channel is dropped.
+struct ChannelAssets {
+    manager: Arc<SubscriptionManager>,
+    assets: HashSet<String>,
+}
+
+impl ChannelAssets {
+    /// Unsubscribes from every asset still held, leaving none.
+    fn unsubscribe_remaining(&mut self) -> Result<()> {
+        let remaining: Vec<String> = self.assets.drain().collect();
+        if remaining.is_empty() {
+            return Ok(());
+        }
+        self.manager.unsubscribe_market(&remaining)
+    }
+}
+
+impl Drop for ChannelAssets {
+    fn drop(&mut self) {
+        if let Err(e) = self.unsubscribe_remaining() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %e, "Failed to unsubscribe dropped market channel");
+            #[cfg(not(feature = "tracing"))]
+            let _ = &e;
+        }
+    }
+}
+
 /// Manages active subscriptions and routes messages to subscribers.
 pub struct SubscriptionManager {
     connection: ConnectionManager<WsMessage, Arc<InterestTracker>>,
@@ -79,6 +170,8 @@ pub struct SubscriptionManager {
     /// Subscribed markets with reference counts (for multiplexing)
     subscribed_markets: DashMap<String, usize>,
     last_auth: Arc<RwLock<Option<Credentials>>>,
+    /// Aborts the reconnection handler along with the manager
+    reconnection_handler: OnceLock<AbortOnDrop>,
 }
 
 impl SubscriptionManager {
@@ -95,15 +188,19 @@ impl SubscriptionManager {
             subscribed_assets: DashMap::new(),
             subscribed_markets: DashMap::new(),
             last_auth: Arc::new(RwLock::new(None)),
+            reconnection_handler: OnceLock::new(),
         }
     }
 
     /// Start the reconnection handler that re-subscribes on connection recovery.
+    ///
+    /// The handler only holds a weak reference to the manager, so it does not keep the
+    /// connection alive, and it is aborted once the manager is dropped.
     pub fn start_reconnection_handler(self: &Arc<Self>) {
-        let this = Arc::clone(self);
+        let this = Arc::downgrade(self);
+        let mut state_rx = self.connection.state_receiver();
 
-        tokio::spawn(async move {
-            let mut state_rx = this.connection.state_receiver();
+        let handle = tokio::spawn(async move {
             let mut was_connected = state_rx.borrow().is_connected();
 
             loop {
@@ -118,6 +215,9 @@ impl SubscriptionManager {
                 match state {
                     ConnectionState::Connected { .. } => {
                         if was_connected {
+                            let Some(this) = this.upgrade() else {
+                                break;
+                            };
                             // Reconnect to subscriptions
                             #[cfg(feature = "tracing")]
                             tracing::debug!("WebSocket reconnected, re-establishing subscriptions");
@@ -135,6 +235,8 @@ impl SubscriptionManager {
                 }
             }
         });
+
+        _ = self.reconnection_handler.set(AbortOnDrop::new(&handle));
     }
 
     /// Re-send subscription requests for all tracked assets and markets.
@@ -208,6 +310,130 @@ impl SubscriptionManager {
         asset_ids: Vec<String>,
         custom_features: bool,
     ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        self.register_market(&asset_ids, custom_features)?;
+
+        // Create filtered stream with its own receiver
+        let mut rx = self.connection.subscribe();
+        let asset_ids_set: HashSet<String> = asset_ids.into_iter().collect();
+
+        Ok(stream! {
+            loop {
+                match rx.recv().await {
+                    Ok(Ok(msg)) => {
+                        if is_for_assets(&msg, &asset_ids_set) {
+                            yield Ok(msg);
+                        }
+                    }
+                    // Undecodable frames are reported without ending the stream
+                    Ok(Err(e)) => {
+                        yield Err(WsError::InvalidMessage(e.to_string()).into());
+                    }
+                    Err(RecvError::Lagged(n)) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!("Subscription lagged, missed {n} messages");
+                        yield Err(WsError::Lagged { count: n }.into());
+                        break;
+                    }
+                    Err(RecvError::Closed) => {
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Subscribe to public market data through a [`MarketChannel`], whose assets can be changed
+    /// with [`WsCommand`]s while it runs.
+    ///
+    /// This will fail if `asset_ids` is empty.
+    pub fn subscribe_market_channel(
+        self: &Arc<Self>,
+        asset_ids: Vec<String>,
+        custom_features: bool,
+    ) -> Result<MarketChannel> {
+        self.register_market(&asset_ids, custom_features)?;
+
+        let (commands, mut command_rx) = mpsc::channel(COMMAND_CAPACITY);
+        let mut rx = self.connection.subscribe();
+        // Moved into the stream, so that dropping the channel unsubscribes even if it was never
+        // polled
+        let mut channel = ChannelAssets {
+            manager: Arc::clone(self),
+            assets: asset_ids.into_iter().collect(),
+        };
+
+        let stream = stream! {
+            loop {
+                let event = tokio::select! {
+                    frame = rx.recv() => ChannelEvent::Frame(frame),
+                    command = command_rx.recv() => ChannelEvent::Command(command),
+                };
+
+                match event {
+                    ChannelEvent::Frame(Ok(Ok(msg))) => {
+                        if is_for_assets(&msg, &channel.assets) {
+                            yield Ok(msg);
+                        }
+                    }
+                    // Undecodable frames are reported without ending the stream
+                    ChannelEvent::Frame(Ok(Err(e))) => {
+                        yield Err(WsError::InvalidMessage(e.to_string()).into());
+                    }
+                    ChannelEvent::Frame(Err(RecvError::Lagged(n))) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!("Subscription lagged, missed {n} messages");
+                        yield Err(WsError::Lagged { count: n }.into());
+                        break;
+                    }
+                    ChannelEvent::Frame(Err(RecvError::Closed)) => {
+                        break;
+                    }
+                    ChannelEvent::Command(Some(WsCommand::Subscribe(ids))) => {
+                        let mut new_ids: Vec<String> = ids
+                            .into_iter()
+                            .filter(|id| !channel.assets.contains(id))
+                            .collect();
+                        new_ids.sort_unstable();
+                        new_ids.dedup();
+
+                        if !new_ids.is_empty() {
+                            match channel.manager.register_market(&new_ids, custom_features) {
+                                Ok(()) => channel.assets.extend(new_ids),
+                                Err(e) => yield Err(e),
+                            }
+                        }
+                    }
+                    ChannelEvent::Command(Some(WsCommand::Unsubscribe(ids))) => {
+                        let removed: Vec<String> =
+                            ids.into_iter().filter(|id| channel.assets.remove(id)).collect();
+
+                        if !removed.is_empty()
+                            && let Err(e) = channel.manager.unsubscribe_market(&removed)
+                        {
+                            yield Err(e);
+                        }
+                    }
+                    // The channel itself holds a sender, so this only ends once every sender but
+                    // its own is dropped after the channel is
+                    ChannelEvent::Command(Some(WsCommand::Close) | None) => {
+                        if let Err(e) = channel.unsubscribe_remaining() {
+                            yield Err(e);
+                        }
+                        break;
+                    }
+                }
+            }
+        };
+
+        Ok(MarketChannel {
+            commands,
+            stream: Box::pin(stream),
+        })
+    }
+
+    /// Validates `asset_ids`, increments their reference counts, sends a subscription request for
+    /// the assets that are new, and registers the subscription.
+    fn register_market(&self, asset_ids: &[String], custom_features: bool) -> Result<()> {
         if asset_ids.is_empty() {
             return Err(WsError::SubscriptionFailed(
                 "asset_ids cannot be empty: at least one asset ID must be provided for subscription"
@@ -257,72 +483,22 @@ impl SubscriptionManager {
         self.active_subs.insert(
             sub_id,
             SubscriptionInfo {
-                target: SubscriptionTarget::Assets(asset_ids.clone()),
+                target: SubscriptionTarget::Assets(asset_ids.to_vec()),
                 created_at: Instant::now(),
             },
         );
 
-        // Create filtered stream with its own receiver
-        let mut rx = self.connection.subscribe();
-        let asset_ids_set: HashSet<String> = asset_ids.into_iter().collect();
-
-        Ok(try_stream! {
-            loop {
-                match rx.recv().await {
-                    Ok(msg) => {
-                        // Filter messages by asset_id
-                        let should_yield = match &msg {
-                            WsMessage::Book(book) => asset_ids_set.contains(&book.asset_id),
-                            WsMessage::PriceChange(price) => {
-                                price
-                                    .price_changes
-                                    .iter()
-                                    .any(|pc| asset_ids_set.contains(&pc.asset_id))
-                            },
-                            WsMessage::LastTradePrice(ltp) => asset_ids_set.contains(&ltp.asset_id),
-                            WsMessage::TickSizeChange(tsc) => asset_ids_set.contains(&tsc.asset_id),
-                            WsMessage::BestBidAsk(bba) => asset_ids_set.contains(&bba.asset_id),
-                            WsMessage::NewMarket(nm) => {
-                                nm.asset_ids.iter().any(|id| asset_ids_set.contains(id))
-                            },
-                            WsMessage::MarketResolved(mr) => {
-                                mr.asset_ids.iter().any(|id| asset_ids_set.contains(id))
-                            },
-                            _ => false,
-                        };
-
-                        if should_yield {
-                            yield msg
-                        }
-                    }
-                    Err(RecvError::Lagged(n)) => {
-                        #[cfg(feature = "tracing")]
-                        tracing::warn!("Subscription lagged, missed {n} messages");
-                        Err(WsError::Lagged { count: n })?;
-                    }
-                    Err(RecvError::Closed) => {
-                        break;
-                    }
-                }
-            }
-        })
+        Ok(())
     }
 
     /// Subscribe to authenticated user channel.
     pub fn subscribe_user(
         &self,
         markets: Vec<String>,
-        auth: &Credentials,
+        auth: Credentials,
     ) -> Result<impl Stream<Item = Result<WsMessage>>> {
         self.interest.add(MessageInterest::USER);
 
-        // Store auth for re-subscription on reconnect.
-        // We can recover from poisoned lock because Option<Credentials> has no inconsistent intermediate state.
-        *self
-            .last_auth
-            .write()
-            .unwrap_or_else(PoisonError::into_inner) = Some(auth.clone());
-
         // Increment refcounts and determine which markets are truly new
         let new_markets: Vec<String> = markets
             .iter()
@@ -350,9 +526,16 @@ impl SubscriptionManager {
                 "Subscribing to user channel"
             );
             let request = SubscriptionRequest::user(new_markets);
-            self.connection.send_authenticated(&request, auth)?;
+            self.connection.send_authenticated(&request, &auth)?;
         }
 
+        // Store auth for re-subscription on reconnect.
+        // We can recover from poisoned lock because Option<Credentials> has no inconsistent intermediate state.
+        *self
+            .last_auth
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(auth);
+
         // Register subscription
         let sub_id = format!("user:{}", markets.join(","));
         self.active_subs.insert(
@@ -366,18 +549,23 @@ impl SubscriptionManager {
         // Create stream for user messages
         let mut rx = self.connection.subscribe();
 
-        Ok(try_stream! {
+        Ok(stream! {
             loop {
                 match rx.recv().await {
-                    Ok(msg) => {
-                        if msg.is_user() {
-                            yield msg;
+                    Ok(Ok(msg)) => {
+                        if msg.is_user() || msg.is_unknown() {
+                            yield Ok(msg);
                         }
                     }
+                    // Undecodable frames are reported without ending the stream
+                    Ok(Err(e)) => {
+                        yield Err(WsError::InvalidMessage(e.to_string()).into());
+                    }
                     Err(RecvError::Lagged(n)) => {
                         #[cfg(feature = "tracing")]
                         tracing::warn!("Subscription lagged, missed {n} messages");
-                        Err(WsError::Lagged { count: n })?;
+                        yield Err(WsError::Lagged { count: n }.into());
+                        break;
                     }
                     Err(RecvError::Closed) => {
                         break;
@@ -531,3 +719,22 @@ impl SubscriptionManager {
         Ok(())
     }
 }
+
+/// Returns `true` if a market channel `msg` concerns one of `asset_ids`.
+fn is_for_assets(msg: &WsMessage, asset_ids: &HashSet<String>) -> bool {
+    match msg {
+        WsMessage::Book(book) => asset_ids.contains(&book.asset_id),
+        WsMessage::PriceChange(price) => price
+            .price_changes
+            .iter()
+            .any(|pc| asset_ids.contains(&pc.asset_id)),
+        WsMessage::LastTradePrice(ltp) => asset_ids.contains(&ltp.asset_id),
+        WsMessage::TickSizeChange(tsc) => asset_ids.contains(&tsc.asset_id),
+        WsMessage::BestBidAsk(bba) => asset_ids.contains(&bba.asset_id),
+        WsMessage::NewMarket(nm) => nm.asset_ids.iter().any(|id| asset_ids.contains(id)),
+        WsMessage::MarketResolved(mr) => mr.asset_ids.iter().any(|id| asset_ids.contains(id)),
+        // Unknown messages are forwarded unless they name an asset we are not subscribed to
+        WsMessage::Unknown { .. } => msg.asset_id().is_none_or(|id| asset_ids.contains(id)),
+        _ => false,
+    }
+}
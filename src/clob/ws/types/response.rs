@@ -1,8 +1,8 @@
 use std::fmt;
 
-use serde::de::{IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::de::{self, DeserializeOwned, IgnoredAny, MapAccess, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer as _, Serialize};
-use serde_json::Deserializer;
+use serde_json::{Deserializer, Value};
 use serde_with::{DisplayFromStr, serde_as};
 
 use crate::auth::ApiKey;
@@ -10,41 +10,41 @@ use crate::clob::types::{Side, TraderSide};
 use crate::clob::ws::interest::MessageInterest;
 use crate::error::Kind;
 use crate::types::Decimal;
+use crate::ws::MessageType;
 
 /// Top-level WebSocket message wrapper.
 ///
-/// All messages received from the WebSocket connection are deserialized into this enum.
+/// All messages received from the WebSocket connection are deserialized into this enum,
+/// dispatching on the `event_type` field. Event types this crate does not know about are kept as
+/// [`WsMessage::Unknown`] so that new server message types don't break existing consumers.
 #[non_exhaustive]
-#[derive(Debug, Clone, Deserialize)]
-#[serde(tag = "event_type")]
+#[derive(Debug, Clone)]
 pub enum WsMessage {
     /// Full or incremental orderbook update
-    #[serde(rename = "book")]
     Book(BookUpdate),
     /// Price change notification
-    #[serde(rename = "price_change")]
     PriceChange(PriceChange),
     /// Tick size change notification
-    #[serde(rename = "tick_size_change")]
     TickSizeChange(TickSizeChange),
     /// Last trade price update
-    #[serde(rename = "last_trade_price")]
     LastTradePrice(LastTradePrice),
     /// Best bid/ask update (requires `custom_feature_enabled`)
-    #[serde(rename = "best_bid_ask")]
     BestBidAsk(BestBidAsk),
     /// New market created (requires `custom_feature_enabled`)
-    #[serde(rename = "new_market")]
     NewMarket(NewMarket),
     /// Market resolved (requires `custom_feature_enabled`)
-    #[serde(rename = "market_resolved")]
     MarketResolved(MarketResolved),
     /// User trade execution (authenticated channel)
-    #[serde(rename = "trade")]
     Trade(TradeMessage),
     /// User order update (authenticated channel)
-    #[serde(rename = "order")]
     Order(OrderMessage),
+    /// Message with an `event_type` not recognized by this crate
+    Unknown {
+        /// The `event_type` sent by the server
+        event_type: String,
+        /// The full message as received
+        raw: Value,
+    },
 }
 
 impl WsMessage {
@@ -57,7 +57,86 @@ impl WsMessage {
     /// Check if the message is a market data message.
     #[must_use]
     pub const fn is_market(&self) -> bool {
-        !self.is_user()
+        !self.is_user() && !self.is_unknown()
+    }
+
+    /// Check if the message has an `event_type` not recognized by this crate.
+    #[must_use]
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self, WsMessage::Unknown { .. })
+    }
+
+    /// The `event_type` of this message as sent on the wire.
+    #[must_use]
+    pub fn event_type(&self) -> &str {
+        match self {
+            WsMessage::Book(_) => "book",
+            WsMessage::PriceChange(_) => "price_change",
+            WsMessage::TickSizeChange(_) => "tick_size_change",
+            WsMessage::LastTradePrice(_) => "last_trade_price",
+            WsMessage::BestBidAsk(_) => "best_bid_ask",
+            WsMessage::NewMarket(_) => "new_market",
+            WsMessage::MarketResolved(_) => "market_resolved",
+            WsMessage::Trade(_) => "trade",
+            WsMessage::Order(_) => "order",
+            WsMessage::Unknown { event_type, .. } => event_type,
+        }
+    }
+
+    /// The single asset this message refers to, if any.
+    ///
+    /// For [`WsMessage::Unknown`] this is the top-level `asset_id` field, when present.
+    #[must_use]
+    pub fn asset_id(&self) -> Option<&str> {
+        match self {
+            WsMessage::Book(book) => Some(&book.asset_id),
+            WsMessage::TickSizeChange(tsc) => Some(&tsc.asset_id),
+            WsMessage::LastTradePrice(ltp) => Some(&ltp.asset_id),
+            WsMessage::BestBidAsk(bba) => Some(&bba.asset_id),
+            WsMessage::Trade(trade) => Some(&trade.asset_id),
+            WsMessage::Order(order) => Some(&order.asset_id),
+            WsMessage::Unknown { raw, .. } => raw.get("asset_id").and_then(Value::as_str),
+            WsMessage::PriceChange(_) | WsMessage::NewMarket(_) | WsMessage::MarketResolved(_) => {
+                None
+            }
+        }
+    }
+}
+
+impl MessageType for WsMessage {
+    fn message_type(&self) -> &str {
+        self.event_type()
+    }
+}
+
+impl<'de> Deserialize<'de> for WsMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        fn decode<T: DeserializeOwned, E: de::Error>(raw: Value) -> Result<T, E> {
+            T::deserialize(raw).map_err(E::custom)
+        }
+
+        let raw = Value::deserialize(deserializer)?;
+        let event_type = match raw.get("event_type") {
+            Some(Value::String(event_type)) => event_type.clone(),
+            Some(_) => return Err(de::Error::custom("`event_type` must be a string")),
+            None => return Err(de::Error::missing_field("event_type")),
+        };
+
+        match event_type.as_str() {
+            "book" => decode(raw).map(Self::Book),
+            "price_change" => decode(raw).map(Self::PriceChange),
+            "tick_size_change" => decode(raw).map(Self::TickSizeChange),
+            "last_trade_price" => decode(raw).map(Self::LastTradePrice),
+            "best_bid_ask" => decode(raw).map(Self::BestBidAsk),
+            "new_market" => decode(raw).map(Self::NewMarket),
+            "market_resolved" => decode(raw).map(Self::MarketResolved),
+            "trade" => decode(raw).map(Self::Trade),
+            "order" => decode(raw).map(Self::Order),
+            _ => Ok(Self::Unknown { event_type, raw }),
+        }
     }
 }
 
@@ -492,6 +571,8 @@ fn matches_interest(msg: &WsMessage, interest: MessageInterest) -> bool {
         WsMessage::MarketResolved(_) => interest.contains(MessageInterest::MARKET_RESOLVED),
         WsMessage::Trade(_) => interest.contains(MessageInterest::TRADE),
         WsMessage::Order(_) => interest.contains(MessageInterest::ORDER),
+        // Unknown event types cannot be filtered and are always forwarded
+        WsMessage::Unknown { .. } => true,
     }
 }
 
@@ -504,12 +585,13 @@ pub fn parse_if_interested(
     interest: &MessageInterest,
 ) -> crate::Result<Vec<WsMessage>> {
     let shape = peek_message_shape(bytes)
-        .map_err(|e| crate::error::Error::with_source(Kind::Internal, Box::new(e)))?;
+        .map_err(|e| crate::error::Error::with_source(Kind::Internal, e))?;
 
     match shape {
         MessageShape::Single(None) => Ok(vec![]),
         MessageShape::Single(Some(event_type)) => {
-            if !interest.is_interested_in_event(&event_type) {
+            let known = !MessageInterest::from_event_type(&event_type).is_empty();
+            if known && !interest.is_interested_in_event(&event_type) {
                 return Ok(vec![]);
             }
             let msg: WsMessage = serde_json::from_slice(bytes)?;
@@ -990,4 +1072,87 @@ mod tests {
         assert!(matches_interest(&mr, MessageInterest::MARKET_RESOLVED));
         assert!(matches_interest(&mr, MessageInterest::MARKET));
     }
+
+    #[test]
+    fn parse_unknown_event_type() {
+        let json = r#"{
+            "event_type": "brand_new_thing",
+            "asset_id": "asset1",
+            "payload": {"value": 42}
+        }"#;
+
+        let msg: WsMessage = serde_json::from_str(json).unwrap();
+        match &msg {
+            WsMessage::Unknown { event_type, raw } => {
+                assert_eq!(event_type, "brand_new_thing");
+                assert_eq!(raw["payload"]["value"], 42);
+            }
+            _ => panic!("Expected Unknown message"),
+        }
+        assert!(
+            msg.is_unknown(),
+            "unrecognized event_type should be unknown"
+        );
+        assert!(
+            !msg.is_market(),
+            "unknown message should not be market data"
+        );
+        assert!(
+            !msg.is_user(),
+            "unknown message should not be a user message"
+        );
+        assert_eq!(msg.event_type(), "brand_new_thing");
+        assert_eq!(msg.asset_id(), Some("asset1"));
+    }
+
+    #[test]
+    fn parse_batch_mixed_known_and_unknown() {
+        let json = r#"[
+            {
+                "event_type": "book",
+                "asset_id": "asset1",
+                "market": "market1",
+                "timestamp": "1234567890",
+                "bids": [],
+                "asks": []
+            },
+            {
+                "event_type": "brand_new_thing",
+                "market": "market1"
+            },
+            {
+                "event_type": "last_trade_price",
+                "asset_id": "asset1",
+                "market": "market1",
+                "price": "0.6",
+                "timestamp": "1234567892"
+            }
+        ]"#;
+
+        let msgs = parse_if_interested(json.as_bytes(), &MessageInterest::BOOK).unwrap();
+        assert_eq!(msgs.len(), 2);
+        assert!(matches!(&msgs[0], WsMessage::Book(_)));
+        assert!(
+            matches!(&msgs[1], WsMessage::Unknown { event_type, .. } if event_type == "brand_new_thing")
+        );
+    }
+
+    #[test]
+    fn parse_single_unknown_is_always_forwarded() {
+        let json = r#"{"event_type": "brand_new_thing"}"#;
+
+        let msgs = parse_if_interested(json.as_bytes(), &MessageInterest::NONE).unwrap();
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].is_unknown(), "unknown frame should be forwarded");
+    }
+
+    #[test]
+    fn parse_malformed_known_message_should_fail() {
+        // `book` without the required `asset_id`
+        let json = r#"{"event_type": "book", "market": "market1", "timestamp": "1"}"#;
+
+        parse_if_interested(json.as_bytes(), &MessageInterest::ALL).unwrap_err();
+        serde_json::from_str::<WsMessage>(r#"{"asset_id": "asset1"}"#).unwrap_err();
+        serde_json::from_str::<WsMessage>(r#"{"event_type": 1}"#).unwrap_err();
+    }
 }
@@ -41,7 +41,7 @@ use super::types::response::{
     Activity, BuilderLeaderboardEntry, BuilderVolumeEntry, ClosedPosition, Health, LiveVolume,
     MetaHolder, OpenInterest, Position, Trade, Traded, TraderLeaderboardEntry, Value,
 };
-use crate::{Result, ToQueryParams as _};
+use crate::{RedirectPolicy, Result, ToQueryParams as _};
 
 /// HTTP client for the Polymarket Data API.
 ///
@@ -87,16 +87,34 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
+        Self::with_redirect(host, RedirectPolicy::default())
+    }
+
+    /// Creates a new Data API client with a custom host URL and redirect handling.
+    ///
+    /// With [`RedirectPolicy::None`], the default of [`Client::new`], a `3xx` response surfaces
+    /// as a [`Kind::Redirect`](crate::error::Kind::Redirect) error instead of being followed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or the HTTP client cannot be created.
+    pub fn with_redirect(host: &str, redirect: RedirectPolicy) -> Result<Client> {
         let mut headers = HeaderMap::new();
 
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
+        headers.insert(
+            "User-Agent",
+            HeaderValue::from_static(crate::DEFAULT_USER_AGENT),
+        );
         headers.insert("Accept", HeaderValue::from_static("*/*"));
         headers.insert("Connection", HeaderValue::from_static("keep-alive"));
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+        let client = ReqwestClient::builder()
+            .default_headers(headers)
+            .redirect(redirect.to_reqwest())
+            .build()?;
 
         Ok(Self {
-            host: Url::parse(host)?,
+            host: crate::parse_host(host)?,
             client,
         })
     }
@@ -2,10 +2,12 @@
 //!
 //! This module contains structs representing API responses from the Data API endpoints.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Deserializer};
 use serde_with::{DefaultOnNull, serde_as};
 
 use super::{ActivityType, Hash64, Side};
+use crate::serde_helpers::{BoolFromAny, TimestampFromAny};
 use crate::types::{Address, Decimal};
 
 /// Deserializes an optional Side, treating empty strings as None.
@@ -49,6 +51,7 @@ pub struct ApiError {
 ///
 /// Returned by the `/positions` endpoint. Represents holdings of outcome tokens
 /// with associated profit/loss calculations.
+#[serde_as]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -80,8 +83,10 @@ pub struct Position {
     /// Current market price of the outcome.
     pub cur_price: Decimal,
     /// Whether the position can be redeemed (market resolved).
+    #[serde_as(as = "BoolFromAny")]
     pub redeemable: bool,
     /// Whether the position can be merged with opposite outcome.
+    #[serde_as(as = "BoolFromAny")]
     pub mergeable: bool,
     /// Market title/question.
     pub title: String,
@@ -104,6 +109,7 @@ pub struct Position {
     /// Market end/resolution date.
     pub end_date: String,
     /// Whether this is a negative risk market.
+    #[serde_as(as = "BoolFromAny")]
     pub negative_risk: bool,
 }
 
@@ -155,6 +161,7 @@ pub struct ClosedPosition {
 ///
 /// Returned by the `/trades` endpoint. Represents an executed order where
 /// outcome tokens were bought or sold.
+#[serde_as]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -171,8 +178,9 @@ pub struct Trade {
     pub size: Decimal,
     /// Execution price per token.
     pub price: Decimal,
-    /// Unix timestamp when the trade occurred.
-    pub timestamp: i64,
+    /// When the trade occurred, sent as a Unix timestamp in seconds or milliseconds.
+    #[serde_as(as = "TimestampFromAny")]
+    pub timestamp: DateTime<Utc>,
     /// Market title/question.
     pub title: String,
     /// Market URL slug.
@@ -256,6 +264,7 @@ pub struct Activity {
 /// A holder of outcome tokens in a market.
 ///
 /// Represents a user who holds a position in a specific outcome.
+#[serde_as]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -271,6 +280,7 @@ pub struct Holder {
     /// Amount of tokens held.
     pub amount: Decimal,
     /// Whether the holder's username is publicly visible.
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub display_username_public: Option<bool>,
     /// Outcome index within the market (0 or 1 for binary markets).
     pub outcome_index: i32,
@@ -281,6 +291,7 @@ pub struct Holder {
     /// Holder's optimized profile image URL.
     pub profile_image_optimized: Option<String>,
     /// Whether the holder is verified.
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub verified: Option<bool>,
 }
 
@@ -365,6 +376,7 @@ pub struct LiveVolume {
 ///
 /// Returned by the `/v1/builders/leaderboard` endpoint. Builders are third-party
 /// applications that integrate with Polymarket.
+#[serde_as]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -378,6 +390,7 @@ pub struct BuilderLeaderboardEntry {
     /// Number of active users for this builder.
     pub active_users: i32,
     /// Whether the builder is verified.
+    #[serde_as(as = "BoolFromAny")]
     pub verified: bool,
     /// URL to the builder's logo image.
     pub builder_logo: Option<String>,
@@ -387,6 +400,7 @@ pub struct BuilderLeaderboardEntry {
 ///
 /// Returned by the `/v1/builders/volume` endpoint. Each entry represents
 /// a single day's volume and activity for a builder.
+#[serde_as]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -398,6 +412,7 @@ pub struct BuilderVolumeEntry {
     /// URL to the builder's logo image.
     pub builder_logo: Option<String>,
     /// Whether the builder is verified.
+    #[serde_as(as = "BoolFromAny")]
     pub verified: bool,
     /// Trading volume for this builder on this date.
     pub volume: Decimal,
@@ -411,6 +426,7 @@ pub struct BuilderVolumeEntry {
 ///
 /// Returned by the `/v1/leaderboard` endpoint. Shows trader rankings
 /// by profit/loss or volume.
+#[serde_as]
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -430,5 +446,6 @@ pub struct TraderLeaderboardEntry {
     /// The trader's X (Twitter) username.
     pub x_username: Option<String>,
     /// Whether the trader has a verified badge.
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub verified_badge: Option<bool>,
 }
@@ -1,6 +1,7 @@
 use std::backtrace::Backtrace;
 use std::error::Error as StdError;
 use std::fmt;
+use std::time::Duration;
 
 use alloy::primitives::ChainId;
 use alloy::primitives::ruint::ParseError;
@@ -26,6 +27,10 @@ pub enum Kind {
     WebSocket,
     /// Error related to geographic restrictions blocking access
     Geoblock,
+    /// Error related to an HTTP redirect that was not followed
+    Redirect,
+    /// Error related to a request short-circuited by an open circuit breaker
+    CircuitOpen,
 }
 
 #[derive(Debug)]
@@ -79,6 +84,88 @@ impl Error {
             method,
             path,
             message: message.into(),
+            retry_after: None,
+            body: None,
+        }
+        .into()
+    }
+
+    /// Returns the delay requested by the server through `Retry-After`, if this is a
+    /// [`Status`] error whose response carried the header, or the remaining cooldown of a
+    /// [`CircuitOpen`] error.
+    #[must_use]
+    pub fn retry_after(&self) -> Option<Duration> {
+        if let Some(open) = self.downcast_ref::<CircuitOpen>() {
+            return Some(open.retry_in);
+        }
+        self.downcast_ref::<Status>()?.retry_after
+    }
+
+    #[must_use]
+    pub fn circuit_open(retry_in: Duration) -> Self {
+        CircuitOpen { retry_in }.into()
+    }
+
+    #[must_use]
+    pub fn redirect(
+        status_code: StatusCode,
+        method: Method,
+        path: String,
+        location: Option<String>,
+    ) -> Self {
+        Redirect {
+            status_code,
+            method,
+            path,
+            location,
+        }
+        .into()
+    }
+
+    pub fn invalid_input<V: Into<String>, R: fmt::Display>(value: V, reason: R) -> Self {
+        InvalidInput {
+            value: value.into(),
+            reason: reason.to_string(),
+            source: None,
+        }
+        .into()
+    }
+
+    /// Like [`Error::invalid_input`], but uses `source` as the reason and keeps it as the cause
+    /// of the returned error.
+    pub fn invalid_input_with_source<V, E>(value: V, source: E) -> Self
+    where
+        V: Into<String>,
+        E: StdError + Send + Sync + 'static,
+    {
+        InvalidInput {
+            value: value.into(),
+            reason: source.to_string(),
+            source: Some(Box::new(source)),
+        }
+        .into()
+    }
+
+    pub fn invalid_host<H: Into<String>, R: fmt::Display>(host: H, reason: R) -> Self {
+        InvalidHost {
+            host: host.into(),
+            reason: reason.to_string(),
+            source: None,
+        }
+        .into()
+    }
+
+    /// Like [`Error::invalid_host`], but uses `source` as the reason and keeps it as the cause of
+    /// the returned error.
+    pub fn invalid_host_with_source<H, E>(host: H, source: E) -> Self
+    where
+        H: Into<String>,
+        E: StdError + Send + Sync + 'static,
+    {
+        InvalidHost {
+            host: host.into(),
+            reason: source.to_string(),
+            source: Some(Box::new(source)),
         }
         .into()
     }
@@ -112,20 +199,96 @@ pub struct Status {
     pub status_code: StatusCode,
     pub method: Method,
     pub path: String,
+    /// Raw response body
     pub message: String,
+    /// Delay requested by the server through `Retry-After`, typically sent with
+    /// `429 Too Many Requests` and `503 Service Unavailable`
+    pub retry_after: Option<Duration>,
+    /// Response body parsed as JSON, if the server sent it as `application/json`. Plain-text
+    /// bodies are only available through `message`
+    pub body: Option<serde_json::Value>,
+}
+
+impl Status {
+    /// Returns the error reported by the server: the `error` or `message` field of a JSON body,
+    /// or else the raw body.
+    #[must_use]
+    pub fn error_message(&self) -> &str {
+        self.body
+            .as_ref()
+            .and_then(|body| body.get("error").or_else(|| body.get("message")))
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or(&self.message)
+    }
 }
 
 impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.message.is_empty() {
+            write!(f, "{} {} ({})", self.method, self.path, self.status_code)
+        } else {
+            write!(
+                f,
+                "{} {}: {} ({})",
+                self.method, self.path, self.message, self.status_code
+            )
+        }
+    }
+}
+
+impl StdError for Status {}
+
+/// Error for a `3xx` response that was not followed under the configured
+/// [`RedirectPolicy`](crate::RedirectPolicy), usually because the endpoint has moved.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct Redirect {
+    pub status_code: StatusCode,
+    pub method: Method,
+    pub path: String,
+    /// Value of the `Location` header, if the response had one
+    pub location: Option<String>,
+}
+
+impl fmt::Display for Redirect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(
+                f,
+                "{} {} redirected to {location} ({})",
+                self.method, self.path, self.status_code
+            ),
+            None => write!(
+                f,
+                "{} {} redirected ({})",
+                self.method, self.path, self.status_code
+            ),
+        }
+    }
+}
+
+impl StdError for Redirect {}
+
+/// Error for a request that was not sent because the circuit breaker is open after repeated
+/// failures.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct CircuitOpen {
+    /// Time left until the breaker lets a probe request through
+    pub retry_in: Duration,
+}
+
+impl fmt::Display for CircuitOpen {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "error({}) making {} call to {} with {}",
-            self.status_code, self.method, self.path, self.message
+            "circuit breaker is open, retry in {}ms",
+            self.retry_in.as_millis()
         )
     }
 }
 
-impl StdError for Status {}
+impl StdError for CircuitOpen {}
 
 #[non_exhaustive]
 #[derive(Debug)]
@@ -141,6 +304,53 @@ impl fmt::Display for Validation {
 
 impl StdError for Validation {}
 
+/// Error for user-supplied input, such as a URL, that could not be parsed. Unlike [`Validation`],
+/// this keeps the offending value so that it is shown alongside the reason.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct InvalidInput {
+    pub value: String,
+    pub reason: String,
+    source: Option<Box<dyn StdError + Send + Sync + 'static>>,
+}
+
+impl fmt::Display for InvalidInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid input `{}`: {}", self.value, self.reason)
+    }
+}
+
+impl StdError for InvalidInput {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_deref()
+            .map(|e| e as &(dyn StdError + 'static))
+    }
+}
+
+/// Error for a client host that is not an absolute `http`, `https`, `ws` or `wss` URL.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct InvalidHost {
+    pub host: String,
+    pub reason: String,
+    source: Option<Box<dyn StdError + Send + Sync + 'static>>,
+}
+
+impl fmt::Display for InvalidHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid host `{}`: {}", self.host, self.reason)
+    }
+}
+
+impl StdError for InvalidHost {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_deref()
+            .map(|e| e as &(dyn StdError + 'static))
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug)]
 pub struct Synchronization;
@@ -268,12 +478,36 @@ impl From<Validation> for Error {
     }
 }
 
+impl From<InvalidInput> for Error {
+    fn from(err: InvalidInput) -> Self {
+        Error::with_source(Kind::Validation, err)
+    }
+}
+
+impl From<InvalidHost> for Error {
+    fn from(err: InvalidHost) -> Self {
+        Error::with_source(Kind::Validation, err)
+    }
+}
+
 impl From<Status> for Error {
     fn from(err: Status) -> Self {
         Error::with_source(Kind::Status, err)
     }
 }
 
+impl From<Redirect> for Error {
+    fn from(err: Redirect) -> Self {
+        Error::with_source(Kind::Redirect, err)
+    }
+}
+
+impl From<CircuitOpen> for Error {
+    fn from(err: CircuitOpen) -> Self {
+        Error::with_source(Kind::CircuitOpen, err)
+    }
+}
+
 impl From<Synchronization> for Error {
     fn from(err: Synchronization) -> Self {
         Error::with_source(Kind::Synchronization, err)
@@ -284,6 +518,107 @@ impl From<Synchronization> for Error {
 mod tests {
     use super::*;
 
+    #[test]
+    fn status_display_should_include_request_context() {
+        let error = Error::status(
+            StatusCode::BAD_REQUEST,
+            Method::POST,
+            "/order".to_owned(),
+            "not enough balance",
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "Status: POST /order: not enough balance (400 Bad Request)"
+        );
+
+        let error = Error::status(StatusCode::NOT_FOUND, Method::GET, "/book".to_owned(), "");
+
+        assert_eq!(error.to_string(), "Status: GET /book (404 Not Found)");
+    }
+
+    #[test]
+    fn invalid_input_display_should_include_value() {
+        let error = Error::invalid_input("clob.polymarket.com", "relative URL without a base");
+
+        assert_eq!(error.kind(), Kind::Validation);
+        assert_eq!(
+            error.to_string(),
+            "Validation: invalid input `clob.polymarket.com`: relative URL without a base"
+        );
+        assert_eq!(
+            error.downcast_ref::<InvalidInput>().unwrap().value,
+            "clob.polymarket.com"
+        );
+    }
+
+    #[test]
+    fn invalid_host_display_should_include_host() {
+        let error = Error::invalid_host("ftp://example.com", "unsupported scheme `ftp`");
+
+        assert_eq!(error.kind(), Kind::Validation);
+        assert_eq!(
+            error.to_string(),
+            "Validation: invalid host `ftp://example.com`: unsupported scheme `ftp`"
+        );
+    }
+
+    /// Collects the chain of causes below `error`.
+    fn sources(error: &Error) -> Vec<&(dyn StdError + 'static)> {
+        std::iter::successors(error.source(), |&e| e.source()).collect()
+    }
+
+    #[test]
+    fn reqwest_error_source_chain_should_reach_root_cause() {
+        let reqwest_error = reqwest::Client::new()
+            .get("http://[::1")
+            .build()
+            .unwrap_err();
+        let error: Error = reqwest_error.into();
+
+        let chain = sources(&error);
+
+        assert!(
+            chain[0].downcast_ref::<reqwest::Error>().is_some(),
+            "first cause should be the reqwest error"
+        );
+        assert!(
+            chain
+                .iter()
+                .any(|e| e.downcast_ref::<url::ParseError>().is_some()),
+            "chain should reach the URL parse error, got {chain:?}"
+        );
+    }
+
+    #[test]
+    fn invalid_host_with_source_should_chain_cause() {
+        let parse_error = url::Url::parse("http://[::1").unwrap_err();
+        let error = Error::invalid_host_with_source("http://[::1", parse_error);
+
+        let chain = sources(&error);
+
+        assert_eq!(chain.len(), 2);
+        assert!(
+            chain[0].downcast_ref::<InvalidHost>().is_some(),
+            "first cause should be the invalid host"
+        );
+        assert_eq!(
+            chain[1].downcast_ref::<url::ParseError>(),
+            Some(&url::ParseError::InvalidIpv6Address)
+        );
+        assert_eq!(
+            error.to_string(),
+            "Validation: invalid host `http://[::1`: invalid IPv6 address"
+        );
+    }
+
+    #[test]
+    fn invalid_input_without_source_should_end_chain() {
+        let error = Error::invalid_input("abc", "not a number");
+
+        assert_eq!(sources(&error).len(), 1);
+    }
+
     #[test]
     fn geoblock_display_should_succeed() {
         let geoblock = Geoblock {
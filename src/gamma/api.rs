@@ -0,0 +1,268 @@
+//! Trait abstraction over the Gamma API client.
+//!
+//! [`GammaApi`] captures the public request methods of [`Client`], so that code can be written
+//! generically over `impl GammaApi` and exercised against a hand-written test double instead of
+//! a live server. See the `gamma_mock` example for such a double.
+//!
+//! # Example
+//!
+//! ```
+//! use polymarket_client_sdk::Result;
+//! use polymarket_client_sdk::gamma::{Client, GammaApi};
+//!
+//! async fn sport_count(api: &impl GammaApi) -> Result<usize> {
+//!     Ok(api.sports().await?.len())
+//! }
+//!
+//! # async fn example() -> Result<()> {
+//! let count = sport_count(&Client::default()).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`GammaApi` names the API it abstracts over"
+)]
+
+use async_trait::async_trait;
+
+use super::Client;
+use super::types::request::{
+    CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest, EventByIdRequest,
+    EventBySlugRequest, EventTagsRequest, EventsRequest, MarketByIdRequest, MarketBySlugRequest,
+    MarketTagsRequest, MarketsRequest, PublicProfileRequest, RelatedTagsByIdRequest,
+    RelatedTagsBySlugRequest, SearchRequest, SeriesByIdRequest, SeriesListRequest,
+    SportsGamesRequest, TagByIdRequest, TagBySlugRequest, TagsRequest, TeamsRequest,
+};
+use super::types::response::{
+    Comment, Event, Game, HealthResponse, Market, PublicProfile, RelatedTag, SearchResults, Series,
+    SportsMarketTypesResponse, SportsMetadata, Tag, Team,
+};
+use crate::Result;
+
+/// The public request methods of the Gamma API.
+///
+/// [`Client`] implements every method by calling the API. There are no default implementations,
+/// so a test double states what each method returns, and a method added to the trait is never
+/// silently left unimplemented.
+#[async_trait]
+pub trait GammaApi: Send + Sync {
+    /// Performs a health check on the API.
+    async fn status(&self) -> Result<HealthResponse>;
+
+    /// Lists teams with optional filters.
+    async fn teams(&self, request: &TeamsRequest) -> Result<Vec<Team>>;
+
+    /// Gets sports metadata.
+    async fn sports(&self) -> Result<Vec<SportsMetadata>>;
+
+    /// Gets valid sports market types.
+    async fn sports_market_types(&self) -> Result<SportsMarketTypesResponse>;
+
+    /// Lists sports games with their live scores and state, with optional filters.
+    async fn sports_games(&self, request: &SportsGamesRequest) -> Result<Vec<Game>>;
+
+    /// Lists tags with optional filters.
+    async fn tags(&self, request: &TagsRequest) -> Result<Vec<Tag>>;
+
+    /// Gets a tag by ID.
+    async fn tag_by_id(&self, request: &TagByIdRequest) -> Result<Tag>;
+
+    /// Gets a tag by slug.
+    async fn tag_by_slug(&self, request: &TagBySlugRequest) -> Result<Tag>;
+
+    /// Gets related tag relationships by tag ID.
+    async fn related_tags_by_id(&self, request: &RelatedTagsByIdRequest)
+    -> Result<Vec<RelatedTag>>;
+
+    /// Gets related tag relationships by tag slug.
+    async fn related_tags_by_slug(
+        &self,
+        request: &RelatedTagsBySlugRequest,
+    ) -> Result<Vec<RelatedTag>>;
+
+    /// Gets tags related to a tag by ID.
+    async fn tags_related_to_tag_by_id(&self, request: &RelatedTagsByIdRequest)
+    -> Result<Vec<Tag>>;
+
+    /// Gets tags related to a tag by slug.
+    async fn tags_related_to_tag_by_slug(
+        &self,
+        request: &RelatedTagsBySlugRequest,
+    ) -> Result<Vec<Tag>>;
+
+    /// Lists events with optional filters.
+    async fn events(&self, request: &EventsRequest) -> Result<Vec<Event>>;
+
+    /// Gets an event by ID.
+    async fn event_by_id(&self, request: &EventByIdRequest) -> Result<Event>;
+
+    /// Gets an event by slug.
+    async fn event_by_slug(&self, request: &EventBySlugRequest) -> Result<Event>;
+
+    /// Gets tags for an event by ID.
+    async fn event_tags(&self, request: &EventTagsRequest) -> Result<Vec<Tag>>;
+
+    /// Lists markets with optional filters.
+    async fn markets(&self, request: &MarketsRequest) -> Result<Vec<Market>>;
+
+    /// Gets a market by ID.
+    async fn market_by_id(&self, request: &MarketByIdRequest) -> Result<Market>;
+
+    /// Gets a market by slug.
+    async fn market_by_slug(&self, request: &MarketBySlugRequest) -> Result<Market>;
+
+    /// Gets tags for a market by ID.
+    async fn market_tags(&self, request: &MarketTagsRequest) -> Result<Vec<Tag>>;
+
+    /// Lists series with optional filters.
+    async fn series(&self, request: &SeriesListRequest) -> Result<Vec<Series>>;
+
+    /// Gets a series by ID.
+    async fn series_by_id(&self, request: &SeriesByIdRequest) -> Result<Series>;
+
+    /// Lists comments with optional filters.
+    async fn comments(&self, request: &CommentsRequest) -> Result<Vec<Comment>>;
+
+    /// Gets comments by comment ID.
+    async fn comments_by_id(&self, request: &CommentsByIdRequest) -> Result<Vec<Comment>>;
+
+    /// Gets comments by user address.
+    async fn comments_by_user_address(
+        &self,
+        request: &CommentsByUserAddressRequest,
+    ) -> Result<Vec<Comment>>;
+
+    /// Gets a public profile by wallet address.
+    async fn public_profile(&self, request: &PublicProfileRequest) -> Result<PublicProfile>;
+
+    /// Searches markets, events, and profiles.
+    async fn search(&self, request: &SearchRequest) -> Result<SearchResults>;
+}
+
+#[async_trait]
+impl GammaApi for Client {
+    async fn status(&self) -> Result<HealthResponse> {
+        Client::status(self).await
+    }
+
+    async fn teams(&self, request: &TeamsRequest) -> Result<Vec<Team>> {
+        Client::teams(self, request).await
+    }
+
+    async fn sports(&self) -> Result<Vec<SportsMetadata>> {
+        Client::sports(self).await
+    }
+
+    async fn sports_market_types(&self) -> Result<SportsMarketTypesResponse> {
+        Client::sports_market_types(self).await
+    }
+
+    async fn sports_games(&self, request: &SportsGamesRequest) -> Result<Vec<Game>> {
+        Client::sports_games(self, request).await
+    }
+
+    async fn tags(&self, request: &TagsRequest) -> Result<Vec<Tag>> {
+        Client::tags(self, request).await
+    }
+
+    async fn tag_by_id(&self, request: &TagByIdRequest) -> Result<Tag> {
+        Client::tag_by_id(self, request).await
+    }
+
+    async fn tag_by_slug(&self, request: &TagBySlugRequest) -> Result<Tag> {
+        Client::tag_by_slug(self, request).await
+    }
+
+    async fn related_tags_by_id(
+        &self,
+        request: &RelatedTagsByIdRequest,
+    ) -> Result<Vec<RelatedTag>> {
+        Client::related_tags_by_id(self, request).await
+    }
+
+    async fn related_tags_by_slug(
+        &self,
+        request: &RelatedTagsBySlugRequest,
+    ) -> Result<Vec<RelatedTag>> {
+        Client::related_tags_by_slug(self, request).await
+    }
+
+    async fn tags_related_to_tag_by_id(
+        &self,
+        request: &RelatedTagsByIdRequest,
+    ) -> Result<Vec<Tag>> {
+        Client::tags_related_to_tag_by_id(self, request).await
+    }
+
+    async fn tags_related_to_tag_by_slug(
+        &self,
+        request: &RelatedTagsBySlugRequest,
+    ) -> Result<Vec<Tag>> {
+        Client::tags_related_to_tag_by_slug(self, request).await
+    }
+
+    async fn events(&self, request: &EventsRequest) -> Result<Vec<Event>> {
+        Client::events(self, request).await
+    }
+
+    async fn event_by_id(&self, request: &EventByIdRequest) -> Result<Event> {
+        Client::event_by_id(self, request).await
+    }
+
+    async fn event_by_slug(&self, request: &EventBySlugRequest) -> Result<Event> {
+        Client::event_by_slug(self, request).await
+    }
+
+    async fn event_tags(&self, request: &EventTagsRequest) -> Result<Vec<Tag>> {
+        Client::event_tags(self, request).await
+    }
+
+    async fn markets(&self, request: &MarketsRequest) -> Result<Vec<Market>> {
+        Client::markets(self, request).await
+    }
+
+    async fn market_by_id(&self, request: &MarketByIdRequest) -> Result<Market> {
+        Client::market_by_id(self, request).await
+    }
+
+    async fn market_by_slug(&self, request: &MarketBySlugRequest) -> Result<Market> {
+        Client::market_by_slug(self, request).await
+    }
+
+    async fn market_tags(&self, request: &MarketTagsRequest) -> Result<Vec<Tag>> {
+        Client::market_tags(self, request).await
+    }
+
+    async fn series(&self, request: &SeriesListRequest) -> Result<Vec<Series>> {
+        Client::series(self, request).await
+    }
+
+    async fn series_by_id(&self, request: &SeriesByIdRequest) -> Result<Series> {
+        Client::series_by_id(self, request).await
+    }
+
+    async fn comments(&self, request: &CommentsRequest) -> Result<Vec<Comment>> {
+        Client::comments(self, request).await
+    }
+
+    async fn comments_by_id(&self, request: &CommentsByIdRequest) -> Result<Vec<Comment>> {
+        Client::comments_by_id(self, request).await
+    }
+
+    async fn comments_by_user_address(
+        &self,
+        request: &CommentsByUserAddressRequest,
+    ) -> Result<Vec<Comment>> {
+        Client::comments_by_user_address(self, request).await
+    }
+
+    async fn public_profile(&self, request: &PublicProfileRequest) -> Result<PublicProfile> {
+        Client::public_profile(self, request).await
+    }
+
+    async fn search(&self, request: &SearchRequest) -> Result<SearchResults> {
+        Client::search(self, request).await
+    }
+}
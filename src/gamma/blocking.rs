@@ -0,0 +1,352 @@
+//! Blocking client for the Polymarket Gamma API.
+//!
+//! This module mirrors [`crate::gamma::Client`] with synchronous methods. Each
+//! [`Client`] owns a single-threaded Tokio runtime that drives the underlying async client, so
+//! callers do not need to manage a runtime themselves.
+//!
+//! The blocking client must not be constructed from within an async runtime, since blocking on
+//! a future inside a runtime would stall its executor. Use the async client there instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polymarket_client_sdk::gamma::blocking::Client;
+//! use polymarket_client_sdk::gamma::types::request::EventsRequest;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new("https://gamma-api.polymarket.com")?;
+//!
+//! let request = EventsRequest::builder().active(true).limit(10).build();
+//! let events = client.events(&request)?;
+//! for event in events {
+//!     println!("{}: {:?}", event.id, event.title);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::runtime::{Builder, Handle, Runtime};
+use url::Url;
+
+use super::types::request::{
+    CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest, EventByIdRequest,
+    EventBySlugRequest, EventTagsRequest, EventsRequest, MarketByIdRequest, MarketBySlugRequest,
+    MarketTagsRequest, MarketsRequest, PublicProfileRequest, RelatedTagsByIdRequest,
+    RelatedTagsBySlugRequest, SearchRequest, SeriesByIdRequest, SeriesListRequest,
+    SportsGamesRequest, TagByIdRequest, TagBySlugRequest, TagTaxonomyRequest, TagsRequest,
+    TeamsRequest,
+};
+use super::types::response::{
+    Comment, Event, Game, GammaPage, HealthResponse, Market, PublicProfile, RelatedTag,
+    SearchResults, Series, SportsMarketTypesResponse, SportsMetadata, Tag, TagTaxonomy,
+    TaxonomyProgress, Team,
+};
+use super::{Client as AsyncClient, Config};
+use crate::Result;
+use crate::error::{Error, Kind};
+use crate::meta::RequestOutcome;
+
+/// Blocking HTTP client for the Polymarket Gamma API.
+///
+/// Every method blocks the current thread until the request completes. Cloning is cheap and
+/// clones share the same runtime and connection pool.
+#[derive(Clone, Debug)]
+pub struct Client {
+    inner: AsyncClient,
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    /// Creates a new blocking Gamma API client with a custom host URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if called from within an async runtime, if the URL is invalid, or if the
+    /// HTTP client or runtime cannot be created.
+    pub fn new(host: &str) -> Result<Client> {
+        Self::with_config(host, Config::default())
+    }
+
+    /// Creates a new blocking Gamma API client with a custom host URL and [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if called from within an async runtime, if the URL or configuration is
+    /// invalid, or if the HTTP client or runtime cannot be created.
+    pub fn with_config(host: &str, config: Config) -> Result<Client> {
+        if Handle::try_current().is_ok() {
+            return Err(Error::validation(
+                "Cannot create a blocking client from within an async runtime; \
+                 use the async `gamma::Client` instead",
+            ));
+        }
+
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| Error::with_source(Kind::Internal, e))?;
+        // The underlying `reqwest` client is created outside of any runtime context, and only
+        // driven by the runtime when a request is made.
+        let inner = AsyncClient::with_config(host, config)?;
+
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the base URL of the API.
+    #[must_use]
+    pub fn host(&self) -> &Url {
+        self.inner.host()
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Performs a health check on the API.
+    ///
+    /// Returns "OK" when the API is healthy.
+    pub fn status(&self) -> Result<HealthResponse> {
+        self.block_on(self.inner.status())
+    }
+
+    /// Lists teams with optional filters.
+    pub fn teams(&self, request: &TeamsRequest) -> Result<Vec<Team>> {
+        self.block_on(self.inner.teams(request))
+    }
+
+    /// Gets sports metadata.
+    pub fn sports(&self) -> Result<Vec<SportsMetadata>> {
+        self.block_on(self.inner.sports())
+    }
+
+    /// Gets valid sports market types.
+    pub fn sports_market_types(&self) -> Result<SportsMarketTypesResponse> {
+        self.block_on(self.inner.sports_market_types())
+    }
+
+    /// Lists sports games with their live scores and state, with optional filters.
+    pub fn sports_games(&self, request: &SportsGamesRequest) -> Result<Vec<Game>> {
+        self.block_on(self.inner.sports_games(request))
+    }
+
+    /// Lists tags with optional filters.
+    pub fn tags(&self, request: &TagsRequest) -> Result<Vec<Tag>> {
+        self.block_on(self.inner.tags(request))
+    }
+
+    /// Gets a tag by ID.
+    pub fn tag_by_id(&self, request: &TagByIdRequest) -> Result<Tag> {
+        self.block_on(self.inner.tag_by_id(request))
+    }
+
+    /// Gets a tag by slug.
+    pub fn tag_by_slug(&self, request: &TagBySlugRequest) -> Result<Tag> {
+        self.block_on(self.inner.tag_by_slug(request))
+    }
+
+    /// Gets related tag relationships by tag ID.
+    pub fn related_tags_by_id(&self, request: &RelatedTagsByIdRequest) -> Result<Vec<RelatedTag>> {
+        self.block_on(self.inner.related_tags_by_id(request))
+    }
+
+    /// Gets related tag relationships by tag slug.
+    pub fn related_tags_by_slug(
+        &self,
+        request: &RelatedTagsBySlugRequest,
+    ) -> Result<Vec<RelatedTag>> {
+        self.block_on(self.inner.related_tags_by_slug(request))
+    }
+
+    /// Gets tags related to a tag by ID.
+    pub fn tags_related_to_tag_by_id(&self, request: &RelatedTagsByIdRequest) -> Result<Vec<Tag>> {
+        self.block_on(self.inner.tags_related_to_tag_by_id(request))
+    }
+
+    /// Gets tags related to a tag by slug.
+    pub fn tags_related_to_tag_by_slug(
+        &self,
+        request: &RelatedTagsBySlugRequest,
+    ) -> Result<Vec<Tag>> {
+        self.block_on(self.inner.tags_related_to_tag_by_slug(request))
+    }
+
+    /// Loads every tag and the relationships between them into a [`TagTaxonomy`].
+    ///
+    /// See [`AsyncClient::load_tag_taxonomy`].
+    pub fn load_tag_taxonomy(
+        &self,
+        request: &TagTaxonomyRequest,
+        on_progress: Option<&mut (dyn FnMut(TaxonomyProgress) + Send)>,
+    ) -> Result<TagTaxonomy> {
+        self.block_on(self.inner.load_tag_taxonomy(request, on_progress))
+    }
+
+    /// Lists events with optional filters.
+    pub fn events(&self, request: &EventsRequest) -> Result<Vec<Event>> {
+        self.block_on(self.inner.events(request))
+    }
+
+    /// Lists events with optional filters, along with the response metadata.
+    pub fn events_with_meta(&self, request: &EventsRequest) -> Result<RequestOutcome<Vec<Event>>> {
+        self.block_on(self.inner.events_with_meta(request))
+    }
+
+    /// Lists events with optional filters, skipping the events that fail to deserialize.
+    ///
+    /// See [`AsyncClient::events_lenient`].
+    pub fn events_lenient(&self, request: &EventsRequest) -> Result<(Vec<Event>, usize)> {
+        self.block_on(self.inner.events_lenient(request))
+    }
+
+    /// Lists one page of events, along with the total number of matching events.
+    pub fn events_page(&self, request: &EventsRequest) -> Result<GammaPage<Event>> {
+        self.block_on(self.inner.events_page(request))
+    }
+
+    /// Lists the events under the tag with the given `slug`.
+    ///
+    /// See [`AsyncClient::events_by_tag_slug`].
+    pub fn events_by_tag_slug(
+        &self,
+        slug: &str,
+        request: &EventsRequest,
+        expand_markets: bool,
+    ) -> Result<Vec<Event>> {
+        self.block_on(self.inner.events_by_tag_slug(slug, request, expand_markets))
+    }
+
+    /// Lists every event of the series with the given `series_id`, ordered by start date.
+    ///
+    /// See [`AsyncClient::series_events`].
+    pub fn series_events(&self, series_id: &str, request: &EventsRequest) -> Result<Vec<Event>> {
+        self.block_on(self.inner.series_events(series_id, request))
+    }
+
+    /// Gets an event by ID.
+    pub fn event_by_id(&self, request: &EventByIdRequest) -> Result<Event> {
+        self.block_on(self.inner.event_by_id(request))
+    }
+
+    /// Lists events with optional filters, localized for `accept_language`.
+    pub fn events_localized(
+        &self,
+        request: &EventsRequest,
+        accept_language: &str,
+    ) -> Result<Vec<Event>> {
+        self.block_on(self.inner.events_localized(request, accept_language))
+    }
+
+    /// Gets several events by ID, running up to `concurrency` requests at a time.
+    ///
+    /// See [`AsyncClient::events_by_ids`].
+    pub fn events_by_ids<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        concurrency: usize,
+    ) -> Result<Vec<Result<Event>>> {
+        self.block_on(self.inner.events_by_ids(ids, concurrency))
+    }
+
+    /// Gets an event by slug.
+    pub fn event_by_slug(&self, request: &EventBySlugRequest) -> Result<Event> {
+        self.block_on(self.inner.event_by_slug(request))
+    }
+
+    /// Gets tags for an event by ID.
+    pub fn event_tags(&self, request: &EventTagsRequest) -> Result<Vec<Tag>> {
+        self.block_on(self.inner.event_tags(request))
+    }
+
+    /// Lists markets with optional filters.
+    pub fn markets(&self, request: &MarketsRequest) -> Result<Vec<Market>> {
+        self.block_on(self.inner.markets(request))
+    }
+
+    /// Lists markets with optional filters, along with the response metadata.
+    pub fn markets_with_meta(
+        &self,
+        request: &MarketsRequest,
+    ) -> Result<RequestOutcome<Vec<Market>>> {
+        self.block_on(self.inner.markets_with_meta(request))
+    }
+
+    /// Lists markets with optional filters, skipping the markets that fail to deserialize.
+    ///
+    /// See [`AsyncClient::markets_lenient`].
+    pub fn markets_lenient(&self, request: &MarketsRequest) -> Result<(Vec<Market>, usize)> {
+        self.block_on(self.inner.markets_lenient(request))
+    }
+
+    /// Lists one page of markets, along with the total number of matching markets.
+    pub fn markets_page(&self, request: &MarketsRequest) -> Result<GammaPage<Market>> {
+        self.block_on(self.inner.markets_page(request))
+    }
+
+    /// Gets a market by ID.
+    pub fn market_by_id(&self, request: &MarketByIdRequest) -> Result<Market> {
+        self.block_on(self.inner.market_by_id(request))
+    }
+
+    /// Gets a market by slug.
+    pub fn market_by_slug(&self, request: &MarketBySlugRequest) -> Result<Market> {
+        self.block_on(self.inner.market_by_slug(request))
+    }
+
+    /// Gets tags for a market by ID.
+    pub fn market_tags(&self, request: &MarketTagsRequest) -> Result<Vec<Tag>> {
+        self.block_on(self.inner.market_tags(request))
+    }
+
+    /// Lists series with optional filters.
+    pub fn series(&self, request: &SeriesListRequest) -> Result<Vec<Series>> {
+        self.block_on(self.inner.series(request))
+    }
+
+    /// Gets a series by ID.
+    pub fn series_by_id(&self, request: &SeriesByIdRequest) -> Result<Series> {
+        self.block_on(self.inner.series_by_id(request))
+    }
+
+    /// Lists comments with optional filters.
+    pub fn comments(&self, request: &CommentsRequest) -> Result<Vec<Comment>> {
+        self.block_on(self.inner.comments(request))
+    }
+
+    /// Gets comments by comment ID.
+    pub fn comments_by_id(&self, request: &CommentsByIdRequest) -> Result<Vec<Comment>> {
+        self.block_on(self.inner.comments_by_id(request))
+    }
+
+    /// Gets comments by user address.
+    pub fn comments_by_user_address(
+        &self,
+        request: &CommentsByUserAddressRequest,
+    ) -> Result<Vec<Comment>> {
+        self.block_on(self.inner.comments_by_user_address(request))
+    }
+
+    /// Gets a public profile by wallet address.
+    pub fn public_profile(&self, request: &PublicProfileRequest) -> Result<PublicProfile> {
+        self.block_on(self.inner.public_profile(request))
+    }
+
+    /// Searches markets, events, and profiles.
+    pub fn search(&self, request: &SearchRequest) -> Result<SearchResults> {
+        self.block_on(self.inner.search(request))
+    }
+
+    /// Searches markets, events, and profiles, localized for `accept_language`.
+    pub fn search_localized(
+        &self,
+        request: &SearchRequest,
+        accept_language: &str,
+    ) -> Result<SearchResults> {
+        self.block_on(self.inner.search_localized(request, accept_language))
+    }
+}
@@ -25,27 +25,46 @@
 //! # }
 //! ```
 
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+use bon::Builder;
+use futures::{StreamExt as _, TryStreamExt as _, stream};
 use reqwest::{
-    Client as ReqwestClient, Method,
-    header::{HeaderMap, HeaderValue},
+    Client as ReqwestClient, Method, Request,
+    header::{ACCEPT_LANGUAGE, HeaderMap, HeaderValue},
 };
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use serde_json::Value;
 use url::Url;
 
+use super::pagination::{PageErrorPolicy, paginate};
+use super::slug::slugify;
 use super::types::request::{
     CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest, EventByIdRequest,
     EventBySlugRequest, EventTagsRequest, EventsRequest, MarketByIdRequest, MarketBySlugRequest,
     MarketTagsRequest, MarketsRequest, PublicProfileRequest, RelatedTagsByIdRequest,
-    RelatedTagsBySlugRequest, SearchRequest, SeriesByIdRequest, SeriesListRequest, TagByIdRequest,
-    TagBySlugRequest, TagsRequest, TeamsRequest,
+    RelatedTagsBySlugRequest, SearchRequest, SeriesByIdRequest, SeriesListRequest,
+    SportsGamesRequest, TagByIdRequest, TagBySlugRequest, TagTaxonomyRequest, TagsRequest,
+    TeamsRequest,
 };
 use super::types::response::{
-    Comment, Event, HealthResponse, Market, PublicProfile, RelatedTag, SearchResults, Series,
-    SportsMarketTypesResponse, SportsMetadata, Tag, Team,
+    Comment, Event, Game, GammaPage, HealthResponse, Market, PublicProfile, RelatedTag,
+    SearchResults, Series, SportsMarketTypesResponse, SportsMetadata, Tag, TagTaxonomy,
+    TaxonomyProgress, Team,
 };
+use crate::clob::types::MarketState;
 use crate::error::Error;
-use crate::{Result, ToQueryParams as _};
+use crate::meta::RequestOutcome;
+use crate::serde_helpers::{deserialize_lenient_array, deserialize_with_warnings};
+use crate::{RedirectPolicy, Result, ToQueryParams as _};
+
+/// The default Gamma API host.
+const DEFAULT_HOST: &str = "https://gamma-api.polymarket.com";
+
+/// Events requested per page by [`Client::series_events`] when the request sets no `limit`.
+const SERIES_EVENTS_PAGE_SIZE: i32 = 100;
 
 /// HTTP client for the Polymarket Gamma API.
 ///
@@ -66,20 +85,93 @@ use crate::{Result, ToQueryParams as _};
 ///
 /// // Or with a custom endpoint
 /// let client = Client::new("https://custom-api.example.com").unwrap();
+///
+/// // Or with options, see `Client::builder`
+/// let client = Client::builder()
+///     .accept_language("es")
+///     .build_client("https://gamma-api.polymarket.com")
+///     .unwrap();
 /// ```
 #[derive(Clone, Debug)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "`client` is the inner HTTP client, matching the other API clients"
+)]
 pub struct Client {
     host: Url,
     client: ReqwestClient,
+    /// Query parameters appended to every request, see [`Config`]
+    default_query: Vec<(String, String)>,
+    /// Whether the slugs of `*_by_slug` requests are normalized, see [`Config`]
+    normalize_slugs: bool,
+    /// Whether malformed elements of listings are skipped, see [`Config`]
+    lenient_arrays: bool,
 }
 
 impl Default for Client {
     fn default() -> Self {
-        Client::new("https://gamma-api.polymarket.com")
-            .expect("Client with default endpoint should succeed")
+        Client::new(DEFAULT_HOST).expect("Client with default endpoint should succeed")
+    }
+}
+
+/// Configuration for [`Client`]
+#[derive(Clone, Debug, Builder)]
+pub struct Config {
+    /// Value of the `Accept-Language` header sent with every request, e.g. `"es"` or
+    /// `"pt-BR, pt;q=0.9"`. Defaults to `None`, which leaves the header unset and returns the
+    /// default (English) content.
+    #[builder(into)]
+    accept_language: Option<String>,
+    /// Value of the `User-Agent` header sent with every request. Defaults to
+    /// [`DEFAULT_USER_AGENT`](crate::DEFAULT_USER_AGENT), which carries the crate version.
+    #[builder(into)]
+    user_agent: Option<String>,
+    /// Query parameters appended to every request, e.g. `[("source", "my-app")]`. A parameter is
+    /// skipped on calls that already set the same key, so per-call values always win. Defaults
+    /// to none.
+    #[builder(default)]
+    default_query: Vec<(String, String)>,
+    /// How redirect responses are handled. Defaults to [`RedirectPolicy::None`], which surfaces
+    /// a redirect as an error rather than silently following a moved endpoint.
+    #[builder(default)]
+    redirect: RedirectPolicy,
+    /// Whether the slug of `*_by_slug` requests, e.g. [`Client::event_by_slug`], is passed
+    /// through [`slugify`] before it is sent, so that user input such as `"NBA Finals"` finds
+    /// `nba-finals`. Defaults to `false`, which sends slugs as given.
+    #[builder(default)]
+    normalize_slugs: bool,
+    /// Whether listings, e.g. [`Client::markets`], skip the elements that fail to deserialize
+    /// instead of failing the whole response, so that one malformed market does not lose the
+    /// rest of a bulk pull. Skipped elements are logged with the `tracing` feature; use
+    /// [`Client::markets_lenient`] or [`Client::events_lenient`] to count them. Defaults to
+    /// `false`, which fails on the first malformed element.
+    #[builder(default)]
+    lenient_arrays: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl<S: config_builder::IsComplete> ConfigBuilder<S> {
+    /// Creates a client for `host` with the [`Config`] built so far, like
+    /// [`Client::with_config`], see [`Client::builder`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid, the `accept_language` or `user_agent` is not a
+    /// valid header value, or the HTTP client cannot be created.
+    pub fn build_client(self, host: &str) -> Result<Client> {
+        Client::with_config(host, self.build())
     }
 }
 
+#[expect(
+    clippy::same_name_method,
+    reason = "`GammaApi` forwards to the inherent methods of the same name"
+)]
 impl Client {
     /// Creates a new Gamma API client with a custom host URL.
     ///
@@ -91,17 +183,67 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
+        Self::with_config(host, Config::default())
+    }
+
+    /// Returns a [`Config`] builder that creates the client once done, with
+    /// [`build_client`](ConfigBuilder::build_client).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_client_sdk::RedirectPolicy;
+    /// use polymarket_client_sdk::gamma::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .accept_language("es")
+    ///     .user_agent("my-bot/1.0")
+    ///     .default_query(vec![("source".to_owned(), "my-bot".to_owned())])
+    ///     .redirect(RedirectPolicy::Limited(3))
+    ///     .build_client("https://gamma-api.polymarket.com")?;
+    /// # Ok::<(), polymarket_client_sdk::error::Error>(())
+    /// ```
+    pub fn builder() -> ConfigBuilder {
+        Config::builder()
+    }
+
+    /// Creates a new Gamma API client with a custom host URL and [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid, the `accept_language` or `user_agent` is not a
+    /// valid header value, or the HTTP client cannot be created.
+    pub fn with_config(host: &str, config: Config) -> Result<Client> {
+        let Config {
+            accept_language,
+            user_agent,
+            default_query,
+            redirect,
+            normalize_slugs,
+            lenient_arrays,
+        } = config;
         let mut headers = HeaderMap::new();
 
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
+        headers.insert("User-Agent", crate::user_agent(user_agent)?);
         headers.insert("Accept", HeaderValue::from_static("*/*"));
         headers.insert("Connection", HeaderValue::from_static("keep-alive"));
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+        if let Some(language) = accept_language {
+            let value = HeaderValue::from_str(&language)
+                .map_err(|e| Error::invalid_input_with_source(language, e))?;
+            headers.insert(ACCEPT_LANGUAGE, value);
+        }
+        let client = ReqwestClient::builder()
+            .default_headers(headers)
+            .redirect(redirect.to_reqwest())
+            .build()?;
 
         Ok(Self {
-            host: Url::parse(host)?,
+            host: crate::parse_host(host)?,
             client,
+            default_query,
+            normalize_slugs,
+            lenient_arrays,
         })
     }
 
@@ -111,27 +253,147 @@ impl Client {
         &self.host
     }
 
+    /// Returns a client for `host` that shares the HTTP connection pool and [`Config`] of this
+    /// one, e.g. to fail over to a mirror. The host is validated like in [`Client::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid.
+    pub fn with_host(&self, host: &str) -> Result<Client> {
+        Ok(Self {
+            host: crate::parse_host(host)?,
+            client: self.client.clone(),
+            default_query: self.default_query.clone(),
+            normalize_slugs: self.normalize_slugs,
+            lenient_arrays: self.lenient_arrays,
+        })
+    }
+
+    /// Returns `slug` as it is sent in a `*_by_slug` path, normalized if `normalize_slugs` is set.
+    ///
+    /// Fails if that leaves nothing to send, which would otherwise request the listing path,
+    /// e.g. `tags/slug/`.
+    fn slug<'slug>(&self, slug: &'slug str) -> Result<Cow<'slug, str>> {
+        let normalized = if self.normalize_slugs {
+            Cow::Owned(slugify(slug))
+        } else {
+            Cow::Borrowed(slug)
+        };
+        if normalized.is_empty() {
+            return Err(Error::validation(format!(
+                "slug {slug:?} is empty once normalized"
+            )));
+        }
+
+        Ok(normalized)
+    }
+
+    /// Like `get`, but for listings, whose malformed elements are skipped if `lenient_arrays`
+    /// is set.
+    async fn get_list<Req: Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        req: &Req,
+    ) -> Result<Vec<T>> {
+        self.get_list_with_meta(path, req)
+            .await
+            .map(RequestOutcome::into_value)
+    }
+
+    async fn get_list_with_meta<Req: Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        req: &Req,
+    ) -> Result<RequestOutcome<Vec<T>>> {
+        let outcome: RequestOutcome<Value> = self.get_with_meta(path, req).await?;
+        self.deserialize_list(outcome)
+    }
+
+    /// Deserializes the listing in `outcome`, skipping malformed elements if `lenient_arrays`
+    /// is set.
+    fn deserialize_list<T: DeserializeOwned>(
+        &self,
+        outcome: RequestOutcome<Value>,
+    ) -> Result<RequestOutcome<Vec<T>>> {
+        let RequestOutcome {
+            value,
+            rate_limit,
+            total_count,
+        } = outcome;
+        let value = deserialize_items(value, self.lenient_arrays)?;
+
+        Ok(RequestOutcome {
+            value,
+            rate_limit,
+            total_count,
+        })
+    }
+
     async fn get<Req: Serialize, Res: DeserializeOwned + Serialize>(
         &self,
         path: &str,
         req: &Req,
     ) -> Result<Res> {
+        self.get_with_meta(path, req)
+            .await
+            .map(RequestOutcome::into_value)
+    }
+
+    /// Like `get`, but overrides the configured `Accept-Language` for this request only.
+    async fn get_localized<Req: Serialize, Res: DeserializeOwned + Serialize>(
+        &self,
+        path: &str,
+        req: &Req,
+        accept_language: &str,
+    ) -> Result<Res> {
+        let query = req.query_params(None);
+        let value = HeaderValue::from_str(accept_language)
+            .map_err(|e| Error::invalid_input_with_source(accept_language, e))?;
+        let mut request = self.build_get(&format!("{path}{query}"))?;
+        request.headers_mut().insert(ACCEPT_LANGUAGE, value);
+        crate::request(&self.client, request, None).await
+    }
+
+    async fn get_with_meta<Req: Serialize, Res: DeserializeOwned + Serialize>(
+        &self,
+        path: &str,
+        req: &Req,
+    ) -> Result<RequestOutcome<Res>> {
         let query = req.query_params(None);
-        let request = self
+        let request = self.build_get(&format!("{path}{query}"))?;
+        crate::request_with_meta(&self.client, request, None).await
+    }
+
+    /// Builds a `GET` request for `path_and_query`, appending the configured default query
+    /// parameters whose keys the call does not set itself.
+    fn build_get(&self, path_and_query: &str) -> Result<Request> {
+        let mut request = self
             .client
-            .request(Method::GET, format!("{}{path}{query}", self.host))
+            .request(Method::GET, format!("{}{path_and_query}", self.host))
             .build()?;
-        crate::request(&self.client, request, None).await
+
+        if !self.default_query.is_empty() {
+            let url = request.url_mut();
+            let existing: HashSet<String> =
+                url.query_pairs().map(|(key, _)| key.into_owned()).collect();
+            let missing: Vec<_> = self
+                .default_query
+                .iter()
+                .filter(|(key, _)| !existing.contains(key))
+                .collect();
+            if !missing.is_empty() {
+                url.query_pairs_mut().extend_pairs(missing);
+            }
+        }
+
+        Ok(request)
     }
 
     /// Performs a health check on the API.
     ///
     /// Returns "OK" when the API is healthy.
     pub async fn status(&self) -> Result<HealthResponse> {
-        let request = self
-            .client
-            .request(Method::GET, format!("{}status", self.host))
-            .build()?;
+        let request = self.build_get("status")?;
 
         let response = self.client.execute(request).await?;
         let status_code = response.status();
@@ -151,12 +413,12 @@ impl Client {
 
     /// Lists teams with optional filters.
     pub async fn teams(&self, request: &TeamsRequest) -> Result<Vec<Team>> {
-        self.get("teams", request).await
+        self.get_list("teams", request).await
     }
 
     /// Gets sports metadata.
     pub async fn sports(&self) -> Result<Vec<SportsMetadata>> {
-        self.get("sports", &()).await
+        self.get_list("sports", &()).await
     }
 
     /// Gets valid sports market types.
@@ -164,9 +426,14 @@ impl Client {
         self.get("sports/market-types", &()).await
     }
 
+    /// Lists sports games with their live scores and state, with optional filters.
+    pub async fn sports_games(&self, request: &SportsGamesRequest) -> Result<Vec<Game>> {
+        self.get_list("sports/games", request).await
+    }
+
     /// Lists tags with optional filters.
     pub async fn tags(&self, request: &TagsRequest) -> Result<Vec<Tag>> {
-        self.get("tags", request).await
+        self.get_list("tags", request).await
     }
 
     /// Gets a tag by ID.
@@ -176,7 +443,7 @@ impl Client {
 
     /// Gets a tag by slug.
     pub async fn tag_by_slug(&self, request: &TagBySlugRequest) -> Result<Tag> {
-        self.get(&format!("tags/slug/{}", request.slug), request)
+        self.get(&format!("tags/slug/{}", self.slug(&request.slug)?), request)
             .await
     }
 
@@ -185,7 +452,7 @@ impl Client {
         &self,
         request: &RelatedTagsByIdRequest,
     ) -> Result<Vec<RelatedTag>> {
-        self.get(&format!("tags/{}/related-tags", request.id), request)
+        self.get_list(&format!("tags/{}/related-tags", request.id), request)
             .await
     }
 
@@ -194,7 +461,8 @@ impl Client {
         &self,
         request: &RelatedTagsBySlugRequest,
     ) -> Result<Vec<RelatedTag>> {
-        self.get(&format!("tags/slug/{}/related-tags", request.slug), request)
+        let slug = self.slug(&request.slug)?;
+        self.get_list(&format!("tags/slug/{slug}/related-tags"), request)
             .await
     }
 
@@ -203,7 +471,7 @@ impl Client {
         &self,
         request: &RelatedTagsByIdRequest,
     ) -> Result<Vec<Tag>> {
-        self.get(&format!("tags/{}/related-tags/tags", request.id), request)
+        self.get_list(&format!("tags/{}/related-tags/tags", request.id), request)
             .await
     }
 
@@ -212,16 +480,214 @@ impl Client {
         &self,
         request: &RelatedTagsBySlugRequest,
     ) -> Result<Vec<Tag>> {
-        self.get(
-            &format!("tags/slug/{}/related-tags/tags", request.slug),
-            request,
-        )
-        .await
+        let slug = self.slug(&request.slug)?;
+        self.get_list(&format!("tags/slug/{slug}/related-tags/tags"), request)
+            .await
+    }
+
+    /// Loads every tag and the relationships between them into a [`TagTaxonomy`].
+    ///
+    /// Tags are paged through `/tags` until a short page or `max_tags` tags, then the related
+    /// tags of each one are fetched, up to `concurrency` requests at a time. This is one request
+    /// per tag, so it is meant to run once at startup rather than per lookup. `on_progress` is
+    /// called after every page of tags and every tag's relationships.
+    ///
+    /// Returns the first error of any request, or a validation error if `request` is invalid.
+    pub async fn load_tag_taxonomy(
+        &self,
+        request: &TagTaxonomyRequest,
+        mut on_progress: Option<&mut (dyn FnMut(TaxonomyProgress) + Send)>,
+    ) -> Result<TagTaxonomy> {
+        request.validate()?;
+
+        let mut tags: Vec<Tag> = Vec::new();
+        while tags.len() < request.max_tags {
+            let remaining = i32::try_from(request.max_tags - tags.len()).unwrap_or(i32::MAX);
+            let limit = request.page_size.min(remaining);
+            let page_request = TagsRequest::builder()
+                .limit(limit)
+                .offset(i32::try_from(tags.len()).unwrap_or(i32::MAX))
+                .build();
+            let page = self.tags(&page_request).await?;
+            let is_last = page.len() < usize::try_from(limit).unwrap_or_default();
+
+            tags.extend(page);
+            if let Some(on_progress) = on_progress.as_mut() {
+                on_progress(TaxonomyProgress::Tags { loaded: tags.len() });
+            }
+            if is_last {
+                break;
+            }
+        }
+        tags.truncate(request.max_tags);
+
+        let total = tags.len();
+        let mut pending = stream::iter(&tags)
+            .map(|tag| async move {
+                let related_request = RelatedTagsByIdRequest::builder()
+                    .id(tag.id.as_str())
+                    .maybe_status(request.status)
+                    .build();
+                self.related_tags_by_id(&related_request).await
+            })
+            .buffer_unordered(request.concurrency);
+
+        let mut relationships = Vec::new();
+        let mut loaded = 0;
+        while let Some(related) = pending.next().await {
+            relationships.extend(related?);
+            loaded += 1;
+            if let Some(on_progress) = on_progress.as_mut() {
+                on_progress(TaxonomyProgress::Relationships { loaded, total });
+            }
+        }
+        drop(pending);
+
+        Ok(TagTaxonomy::new(tags, relationships))
     }
 
     /// Lists events with optional filters.
+    ///
+    /// Returns a validation error without making a request if a date range filter has its
+    /// minimum after its maximum.
     pub async fn events(&self, request: &EventsRequest) -> Result<Vec<Event>> {
-        self.get("events", request).await
+        self.events_with_meta(request)
+            .await
+            .map(RequestOutcome::into_value)
+    }
+
+    /// Lists events with optional filters, along with the response metadata.
+    ///
+    /// See [`Client::events`].
+    pub async fn events_with_meta(
+        &self,
+        request: &EventsRequest,
+    ) -> Result<RequestOutcome<Vec<Event>>> {
+        request.validate()?;
+        self.get_list_with_meta("events", request).await
+    }
+
+    /// Lists events with optional filters, skipping the events that fail to deserialize
+    /// regardless of the `lenient_arrays` of [`Config`]. Returns the events and the number of
+    /// events skipped.
+    ///
+    /// See [`Client::events`].
+    pub async fn events_lenient(&self, request: &EventsRequest) -> Result<(Vec<Event>, usize)> {
+        request.validate()?;
+        let outcome: RequestOutcome<Value> = self.get_with_meta("events", request).await?;
+        deserialize_lenient_array(outcome.value)
+    }
+
+    /// Lists one page of events, along with the total number of matching events.
+    ///
+    /// The total is taken from the response body when it is paginated, and otherwise from the
+    /// `X-Total-Count` header. See [`Client::events`].
+    pub async fn events_page(&self, request: &EventsRequest) -> Result<GammaPage<Event>> {
+        request.validate()?;
+        let outcome = self.get_with_meta("events", request).await?;
+        into_page(outcome, request.offset, request.limit, self.lenient_arrays)
+    }
+
+    /// Lists the events under the tag with the given `slug`.
+    ///
+    /// The slug is first resolved to a tag ID with [`Client::tag_by_slug`], then [`Client::events`]
+    /// is queried with `request` filtered by that ID. Any `tag_id` or `tag_slug` already set on
+    /// `request` is replaced. With `expand_markets`, the markets embedded in the events are then
+    /// replaced with the full markets from a single [`Client::markets`] request, so that fields
+    /// the events listing leaves out are filled in. Markets that the second request does not
+    /// return are kept as embedded.
+    ///
+    /// Returns a [`Status`](crate::error::Status) error with `404 Not Found` if no tag has the
+    /// given slug, or the first error of any other request.
+    pub async fn events_by_tag_slug(
+        &self,
+        slug: &str,
+        request: &EventsRequest,
+        expand_markets: bool,
+    ) -> Result<Vec<Event>> {
+        request.validate()?;
+
+        let tag = self
+            .tag_by_slug(&TagBySlugRequest::builder().slug(slug).build())
+            .await?;
+
+        let mut request = request.clone();
+        request.tag_id = Some(tag.id);
+        request.tag_slug = None;
+        let mut events = self.events(&request).await?;
+
+        if expand_markets {
+            let ids: Vec<String> = events
+                .iter()
+                .flat_map(|event| event.markets.iter().flatten())
+                .map(|market| market.id.clone())
+                .collect();
+            if ids.is_empty() {
+                return Ok(events);
+            }
+
+            let markets_request = MarketsRequest::builder()
+                .limit(i32::try_from(ids.len()).unwrap_or(i32::MAX))
+                .id(ids)
+                .build();
+            let mut expanded: HashMap<String, Market> = self
+                .markets(&markets_request)
+                .await?
+                .into_iter()
+                .map(|market| (market.id.clone(), market))
+                .collect();
+
+            for market in events
+                .iter_mut()
+                .flat_map(|event| event.markets.iter_mut().flatten())
+            {
+                if let Some(full) = expanded.remove(&market.id) {
+                    *market = full;
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Lists every event of the series with the given `series_id`, ordered by start date.
+    ///
+    /// This is meant for recurring events, e.g. a weekly market, where each occurrence is an
+    /// event of the same series. The events are queried with `request` filtered by the series
+    /// and paged through until the last page, `request.limit` events at a time (`100` if unset);
+    /// the `series_id` and `offset` of `request` are replaced. Events that list their series
+    /// but not this one are dropped, and events without a start date come last.
+    ///
+    /// Returns the first error of any page, or a validation error if a date range filter has
+    /// its minimum after its maximum.
+    pub async fn series_events(
+        &self,
+        series_id: &str,
+        request: &EventsRequest,
+    ) -> Result<Vec<Event>> {
+        request.validate()?;
+
+        let mut request = request.clone();
+        request.series_id = Some(series_id.to_owned());
+        let limit = request.limit.unwrap_or(SERIES_EVENTS_PAGE_SIZE);
+
+        let mut events: Vec<Event> = paginate(limit, PageErrorPolicy::Fail, |offset, limit| {
+            let mut page_request = request.clone();
+            page_request.offset = Some(offset);
+            page_request.limit = Some(limit);
+            async move { self.events_page(&page_request).await }
+        })
+        .try_filter(|event| {
+            let in_series = event.series.as_ref().is_none_or(|series| {
+                series.is_empty() || series.iter().any(|series| series.id == series_id)
+            });
+            futures::future::ready(in_series)
+        })
+        .try_collect()
+        .await?;
+        events.sort_by_key(|event| (event.start_date.is_none(), event.start_date));
+
+        Ok(events)
     }
 
     /// Gets an event by ID.
@@ -229,20 +695,114 @@ impl Client {
         self.get(&format!("events/{}", request.id), request).await
     }
 
+    /// Lists events with optional filters, localized for `accept_language` regardless of the
+    /// configured default.
+    ///
+    /// See [`Client::events`].
+    pub async fn events_localized(
+        &self,
+        request: &EventsRequest,
+        accept_language: &str,
+    ) -> Result<Vec<Event>> {
+        request.validate()?;
+        let events = self
+            .get_localized("events", request, accept_language)
+            .await?;
+        deserialize_items(events, self.lenient_arrays)
+    }
+
+    /// Gets several events by ID, running up to `concurrency` requests at a time.
+    ///
+    /// The returned vector has one entry per ID, in the same order as `ids`, so a failed lookup
+    /// (e.g. a `404` for an unknown ID) does not affect the others. Each request counts against
+    /// the API rate limit, so choose `concurrency` with that limit in mind.
+    ///
+    /// Returns a validation error if `concurrency` is zero.
+    pub async fn events_by_ids<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        concurrency: usize,
+    ) -> Result<Vec<Result<Event>>> {
+        if concurrency == 0 {
+            return Err(Error::validation("concurrency must be greater than zero"));
+        }
+
+        let results = stream::iter(ids)
+            .map(|id| async move {
+                let request = EventByIdRequest::builder().id(id.as_ref()).build();
+                self.event_by_id(&request).await
+            })
+            .buffered(concurrency)
+            .collect()
+            .await;
+
+        Ok(results)
+    }
+
     /// Gets an event by slug.
     pub async fn event_by_slug(&self, request: &EventBySlugRequest) -> Result<Event> {
-        self.get(&format!("events/slug/{}", request.slug), request)
-            .await
+        self.get(
+            &format!("events/slug/{}", self.slug(&request.slug)?),
+            request,
+        )
+        .await
     }
 
     /// Gets tags for an event by ID.
     pub async fn event_tags(&self, request: &EventTagsRequest) -> Result<Vec<Tag>> {
-        self.get(&format!("events/{}/tags", request.id), request)
+        self.get_list(&format!("events/{}/tags", request.id), request)
             .await
     }
 
     /// Lists markets with optional filters.
+    ///
+    /// Returns a validation error without making a request if a date range filter has its
+    /// minimum after its maximum.
     pub async fn markets(&self, request: &MarketsRequest) -> Result<Vec<Market>> {
+        self.markets_with_meta(request)
+            .await
+            .map(RequestOutcome::into_value)
+    }
+
+    /// Lists markets with optional filters, along with the response metadata.
+    ///
+    /// See [`Client::markets`].
+    pub async fn markets_with_meta(
+        &self,
+        request: &MarketsRequest,
+    ) -> Result<RequestOutcome<Vec<Market>>> {
+        let outcome = self.get_markets(request).await?;
+        let mut outcome = self.deserialize_list(outcome)?;
+        enforce_status(request, &mut outcome.value);
+        Ok(outcome)
+    }
+
+    /// Lists markets with optional filters, skipping the markets that fail to deserialize
+    /// regardless of the `lenient_arrays` of [`Config`]. Returns the markets and the number of
+    /// markets skipped; markets dropped by `enforce_status` are not counted.
+    ///
+    /// See [`Client::markets`].
+    pub async fn markets_lenient(&self, request: &MarketsRequest) -> Result<(Vec<Market>, usize)> {
+        let outcome = self.get_markets(request).await?;
+        let (mut markets, skipped) = deserialize_lenient_array(outcome.value)?;
+        enforce_status(request, &mut markets);
+        Ok((markets, skipped))
+    }
+
+    /// Lists one page of markets, along with the total number of matching markets.
+    ///
+    /// The total is taken from the response body when it is paginated, and otherwise from the
+    /// `X-Total-Count` header. See [`Client::markets`].
+    pub async fn markets_page(&self, request: &MarketsRequest) -> Result<GammaPage<Market>> {
+        let outcome = self.get_markets(request).await?;
+        let mut page = into_page(outcome, request.offset, request.limit, self.lenient_arrays)?;
+        enforce_status(request, &mut page.items);
+        Ok(page)
+    }
+
+    async fn get_markets(&self, request: &MarketsRequest) -> Result<RequestOutcome<Value>> {
+        request.validate()?;
+
         // Build base query string using the standard ToQueryParams trait
         let base_query = request.query_params(None);
 
@@ -257,11 +817,8 @@ impl Client {
             (false, false) => format!("{base_query}&{clob_params}"),
         };
 
-        let req = self
-            .client
-            .request(Method::GET, format!("{}markets{query}", self.host))
-            .build()?;
-        crate::request(&self.client, req, None).await
+        let req = self.build_get(&format!("markets{query}"))?;
+        crate::request_with_meta(&self.client, req, None).await
     }
 
     /// Gets a market by ID.
@@ -271,19 +828,22 @@ impl Client {
 
     /// Gets a market by slug.
     pub async fn market_by_slug(&self, request: &MarketBySlugRequest) -> Result<Market> {
-        self.get(&format!("markets/slug/{}", request.slug), request)
-            .await
+        self.get(
+            &format!("markets/slug/{}", self.slug(&request.slug)?),
+            request,
+        )
+        .await
     }
 
     /// Gets tags for a market by ID.
     pub async fn market_tags(&self, request: &MarketTagsRequest) -> Result<Vec<Tag>> {
-        self.get(&format!("markets/{}/tags", request.id), request)
+        self.get_list(&format!("markets/{}/tags", request.id), request)
             .await
     }
 
     /// Lists series with optional filters.
     pub async fn series(&self, request: &SeriesListRequest) -> Result<Vec<Series>> {
-        self.get("series", request).await
+        self.get_list("series", request).await
     }
 
     /// Gets a series by ID.
@@ -293,12 +853,13 @@ impl Client {
 
     /// Lists comments with optional filters.
     pub async fn comments(&self, request: &CommentsRequest) -> Result<Vec<Comment>> {
-        self.get("comments", request).await
+        self.get_list("comments", request).await
     }
 
     /// Gets comments by comment ID.
     pub async fn comments_by_id(&self, request: &CommentsByIdRequest) -> Result<Vec<Comment>> {
-        self.get(&format!("comments/{}", request.id), request).await
+        self.get_list(&format!("comments/{}", request.id), request)
+            .await
     }
 
     /// Gets comments by user address.
@@ -306,7 +867,7 @@ impl Client {
         &self,
         request: &CommentsByUserAddressRequest,
     ) -> Result<Vec<Comment>> {
-        self.get(
+        self.get_list(
             &format!("comments/user_address/{}", request.user_address),
             request,
         )
@@ -322,4 +883,74 @@ impl Client {
     pub async fn search(&self, request: &SearchRequest) -> Result<SearchResults> {
         self.get("public-search", request).await
     }
+
+    /// Searches markets, events, and profiles, localized for `accept_language` regardless of the
+    /// configured default.
+    pub async fn search_localized(
+        &self,
+        request: &SearchRequest,
+        accept_language: &str,
+    ) -> Result<SearchResults> {
+        self.get_localized("public-search", request, accept_language)
+            .await
+    }
+}
+
+/// Drops the markets whose locally computed [`MarketState`] contradicts the `closed` filter of
+/// `request`, or that are closed or resolved despite an `active(true)` filter, if it sets
+/// `enforce_status`.
+fn enforce_status(request: &MarketsRequest, markets: &mut Vec<Market>) {
+    if !request.enforce_status {
+        return;
+    }
+
+    let is_closed =
+        |market: &Market| matches!(market.state(), MarketState::Resolved | MarketState::Closed);
+    if let Some(closed) = request.closed {
+        markets.retain(|market| is_closed(market) == closed);
+    } else if request.active == Some(true) {
+        markets.retain(|market| !is_closed(market));
+    }
+}
+
+/// Deserializes the items of a listing, skipping the malformed ones if `lenient` is set.
+fn deserialize_items<T: DeserializeOwned>(items: Value, lenient: bool) -> Result<Vec<T>> {
+    if lenient {
+        deserialize_lenient_array(items).map(|(items, _)| items)
+    } else {
+        deserialize_with_warnings(items)
+    }
+}
+
+/// Splits a listing response into a [`GammaPage`]. The body is either a bare array or an object
+/// with `data` and `pagination.totalResults`; a total in the body wins over `X-Total-Count`.
+/// Malformed items are skipped if `lenient` is set.
+fn into_page<T: DeserializeOwned>(
+    outcome: RequestOutcome<Value>,
+    offset: Option<i32>,
+    limit: Option<i32>,
+    lenient: bool,
+) -> Result<GammaPage<T>> {
+    let (items, body_total) = match outcome.value {
+        Value::Object(mut object) if object.contains_key("data") => {
+            let total = object
+                .get("pagination")
+                .and_then(|pagination| pagination.get("totalResults"))
+                .and_then(Value::as_u64);
+            (object.remove("data").unwrap_or_default(), total)
+        }
+        value => (value, None),
+    };
+    let fetched = items.as_array().map(Vec::len);
+    let items: Vec<T> = deserialize_items(items, lenient)?;
+    let fetched = fetched.unwrap_or(items.len());
+
+    let offset = offset.and_then(|o| u32::try_from(o).ok()).unwrap_or(0);
+    let limit = limit
+        .and_then(|l| u32::try_from(l).ok())
+        .unwrap_or_else(|| u32::try_from(fetched).unwrap_or(u32::MAX));
+
+    let mut page = GammaPage::new(items, body_total.or(outcome.total_count), offset, limit);
+    page.fetched = fetched;
+    Ok(page)
 }
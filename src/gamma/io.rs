@@ -0,0 +1,107 @@
+//! Streaming export of Gamma API results to writers.
+
+use futures::io::{AsyncWrite, AsyncWriteExt as _};
+use futures::{Stream, StreamExt as _, pin_mut};
+use serde::Serialize;
+
+use crate::Result;
+use crate::error::{Error, Kind};
+
+/// Writes every item of `stream` to `writer` as newline-delimited JSON, returning the number of
+/// lines written.
+///
+/// Items are serialized and written one at a time, so the dataset is never held in memory. The
+/// writer is flushed once the stream is exhausted. The first error, either yielded by `stream`
+/// or raised while writing, stops the export and is returned; lines written before it remain in
+/// `writer`.
+///
+/// `writer` is a [`futures::io::AsyncWrite`]. Tokio writers can be adapted with
+/// `tokio_util::compat`.
+///
+/// # Example
+///
+/// ```
+/// use futures::stream;
+/// use polymarket_client_sdk::gamma::export_ndjson;
+///
+/// # async fn example() -> polymarket_client_sdk::Result<()> {
+/// let mut buffer = Vec::new();
+/// let items = stream::iter([Ok(1), Ok(2), Ok(3)]);
+///
+/// let written = export_ndjson(items, &mut buffer).await?;
+///
+/// assert_eq!(written, 3);
+/// assert_eq!(buffer, b"1\n2\n3\n");
+/// # Ok(())
+/// # }
+/// ```
+pub async fn export_ndjson<T, S, W>(stream: S, writer: W) -> Result<usize>
+where
+    T: Serialize,
+    S: Stream<Item = Result<T>>,
+    W: AsyncWrite,
+{
+    pin_mut!(stream);
+    pin_mut!(writer);
+
+    let mut written = 0;
+    let mut line = Vec::new();
+    while let Some(item) = stream.next().await {
+        line.clear();
+        serde_json::to_writer(&mut line, &item?)?;
+        line.push(b'\n');
+
+        writer.write_all(&line).await.map_err(io_error)?;
+        written += 1;
+    }
+    writer.flush().await.map_err(io_error)?;
+
+    Ok(written)
+}
+
+fn io_error(e: std::io::Error) -> Error {
+    Error::with_source(Kind::Internal, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, stream};
+    use serde_json::Value;
+
+    use super::*;
+
+    #[test]
+    fn export_ndjson_should_write_one_line_per_item() {
+        let items = vec![
+            serde_json::json!({ "id": "1", "title": "First" }),
+            serde_json::json!({ "id": "2", "title": "Line\nbreak" }),
+        ];
+        let mut buffer = Vec::new();
+
+        let written = block_on(export_ndjson(
+            stream::iter(items.clone()).map(Ok),
+            &mut buffer,
+        ))
+        .unwrap();
+
+        let output = String::from_utf8(buffer).unwrap();
+        let lines: Vec<Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(written, 2);
+        assert_eq!(lines, items);
+    }
+
+    #[test]
+    fn export_ndjson_should_stop_at_first_error() {
+        let items = stream::iter([Ok(1), Err(Error::validation("page failed")), Ok(3)]);
+        let mut buffer = Vec::new();
+
+        let err = block_on(export_ndjson(items, &mut buffer)).unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        assert_eq!(buffer, b"1\n");
+    }
+}
@@ -17,6 +17,7 @@
 //! | `/teams` | List sports teams |
 //! | `/sports` | Get sports metadata |
 //! | `/sports/market-types` | Get valid sports market types |
+//! | `/sports/games` | List sports games with live scores |
 //! | `/tags` | List tags |
 //! | `/tags/{id}` | Get tag by ID |
 //! | `/tags/slug/{slug}` | Get tag by slug |
@@ -61,11 +62,34 @@
 //! # }
 //! ```
 //!
+//! # Blocking API
+//!
+//! With the `blocking` feature enabled, [`blocking::Client`] exposes the same endpoints as
+//! synchronous methods for use outside of an async runtime.
+//!
+//! # Mocking
+//!
+//! [`GammaApi`] captures the request methods of [`Client`], so code can be written generically
+//! over `impl GammaApi` and tested against a hand-written mock. See the `gamma_mock` example.
+//!
 //! # API Base URL
 //!
 //! The default API endpoint is `https://gamma-api.polymarket.com`.
 
+pub mod api;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
+pub mod io;
+pub mod pagination;
+pub mod slug;
 pub mod types;
 
-pub use client::Client;
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "`GammaApi` names the API it abstracts over"
+)]
+pub use api::GammaApi;
+pub use client::{Client, Config, ConfigBuilder};
+pub use io::export_ndjson;
+pub use pagination::{Page, PageErrorPolicy, paginate};
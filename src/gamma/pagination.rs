@@ -0,0 +1,310 @@
+//! Offset pagination over Gamma API listings.
+
+use std::future::Future;
+
+use async_stream::stream;
+use futures::Stream;
+
+use crate::Result;
+use crate::error::Error;
+use crate::gamma::types::response::GammaPage;
+
+/// Number of pages in a row that [`PageErrorPolicy::SkipAndContinue`] skips before giving up.
+pub const MAX_CONSECUTIVE_SKIPS: u32 = 10;
+
+/// How [`paginate`] handles a page that fails to load.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PageErrorPolicy {
+    /// End the stream with the error.
+    #[default]
+    Fail,
+    /// Skip the failing page and continue at the next offset. The items of the skipped page are
+    /// lost. The stream still ends with the error once [`MAX_CONSECUTIVE_SKIPS`] pages in a row
+    /// have failed, so that an unreachable API does not stall it forever.
+    SkipAndContinue,
+    /// Retry the failing page up to this many times before ending the stream with the error.
+    /// Retries are sent immediately, without a delay.
+    RetryN(u32),
+}
+
+/// A page fetched by the `call` of [`paginate`].
+pub trait Page {
+    type Item;
+
+    /// Number of items the server returned on this page, including any that were not kept.
+    fn fetched(&self) -> usize;
+
+    /// Returns the items kept from this page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+impl<T> Page for Vec<T> {
+    type Item = T;
+
+    fn fetched(&self) -> usize {
+        self.len()
+    }
+
+    fn into_items(self) -> Vec<T> {
+        self
+    }
+}
+
+impl<T> Page for GammaPage<T> {
+    type Item = T;
+
+    fn fetched(&self) -> usize {
+        self.fetched
+    }
+
+    fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+/// Returns a stream of every item of an offset-paginated listing.
+///
+/// `call` fetches the page of at most `limit` items starting at `offset`, e.g. by setting both on
+/// an [`EventsRequest`](crate::gamma::types::request::EventsRequest). Pages are fetched one at a
+/// time as the stream is polled, starting at offset `0`, until the server returns fewer than
+/// `limit` items for a page, or the next offset would overflow. A page that fails to load is
+/// handled according to `on_page_error`.
+///
+/// A page is either the plain items, or a [`GammaPage`] such as
+/// [`Client::events_page`](crate::gamma::Client::events_page) returns. Prefer the latter when
+/// items may be dropped on the client, e.g. by `lenient_arrays` or `enforce_status`: only a
+/// [`GammaPage`] tells a page that lost items apart from the last page.
+///
+/// The stream yields a validation error if `limit` is not positive.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures::StreamExt as _;
+/// use polymarket_client_sdk::gamma::types::request::EventsRequest;
+/// use polymarket_client_sdk::gamma::{Client, PageErrorPolicy, paginate};
+///
+/// # async fn example() -> polymarket_client_sdk::Result<()> {
+/// let client = Client::default();
+/// let events = paginate(100, PageErrorPolicy::RetryN(3), |offset, limit| {
+///     let request = EventsRequest::builder().offset(offset).limit(limit).build();
+///     let client = &client;
+///     async move { client.events(&request).await }
+/// });
+/// futures::pin_mut!(events);
+///
+/// while let Some(event) = events.next().await {
+///     println!("{}", event?.id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn paginate<P, Call, Fut>(
+    limit: i32,
+    on_page_error: PageErrorPolicy,
+    mut call: Call,
+) -> impl Stream<Item = Result<P::Item>>
+where
+    P: Page,
+    Call: FnMut(i32, i32) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    stream! {
+        if limit <= 0 {
+            yield Err(Error::validation("limit must be greater than zero"));
+            return;
+        }
+
+        let mut offset = 0_i32;
+        let mut skipped = 0;
+
+        loop {
+            let mut retries = 0;
+            let page = loop {
+                match call(offset, limit).await {
+                    Ok(page) => break Some(page),
+                    Err(e) => match on_page_error {
+                        PageErrorPolicy::RetryN(max) if retries < max => retries += 1,
+                        PageErrorPolicy::SkipAndContinue if skipped < MAX_CONSECUTIVE_SKIPS => {
+                            #[cfg(feature = "tracing")]
+                            tracing::warn!(offset, error = %e, "Skipping page that failed to load");
+                            #[cfg(not(feature = "tracing"))]
+                            let _ = &e;
+                            skipped += 1;
+                            break None;
+                        }
+                        _ => {
+                            yield Err(e);
+                            return;
+                        }
+                    },
+                }
+            };
+
+            if let Some(page) = page {
+                skipped = 0;
+                let is_last = page.fetched() < usize::try_from(limit).unwrap_or(usize::MAX);
+                for item in page.into_items() {
+                    yield Ok(item);
+                }
+                if is_last {
+                    break;
+                }
+            }
+
+            match offset.checked_add(limit) {
+                Some(next) => offset = next,
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use futures::executor::block_on;
+    use futures::{StreamExt as _, future};
+
+    use super::*;
+
+    /// Collects a listing of three pages of two items whose middle page fails `failures` times.
+    fn collect_with_failing_middle(
+        policy: PageErrorPolicy,
+        failures: u32,
+    ) -> (Vec<Result<i32>>, u32) {
+        let remaining = Cell::new(failures);
+        let calls = Cell::new(0);
+
+        let items = block_on(
+            paginate(2, policy, |offset, _| {
+                calls.set(calls.get() + 1);
+                let page = match offset {
+                    2 if remaining.get() > 0 => {
+                        remaining.set(remaining.get() - 1);
+                        Err(Error::validation("transient"))
+                    }
+                    4 => Ok(vec![offset]),
+                    _ => Ok(vec![offset, offset + 1]),
+                };
+                future::ready(page)
+            })
+            .collect::<Vec<_>>(),
+        );
+
+        (items, calls.get())
+    }
+
+    #[test]
+    fn fail_should_end_at_failing_page() {
+        let (items, calls) = collect_with_failing_middle(PageErrorPolicy::Fail, 1);
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(*items[0].as_ref().unwrap(), 0);
+        assert_eq!(*items[1].as_ref().unwrap(), 1);
+        assert!(items[2].is_err(), "the failing page should end the stream");
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn skip_and_continue_should_complete_without_failing_page() {
+        let (items, calls) = collect_with_failing_middle(PageErrorPolicy::SkipAndContinue, 1);
+
+        let items: Vec<_> = items.into_iter().map(Result::unwrap).collect();
+        assert_eq!(items, [0, 1, 4]);
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn skip_and_continue_should_give_up_after_consecutive_failures() {
+        let (items, calls) = block_on(async {
+            let calls = Cell::new(0);
+            let items = paginate::<Vec<i32>, _, _>(2, PageErrorPolicy::SkipAndContinue, |_, _| {
+                calls.set(calls.get() + 1);
+                future::ready(Err(Error::validation("down")))
+            })
+            .collect::<Vec<_>>()
+            .await;
+            (items, calls.get())
+        });
+
+        assert_eq!(items.len(), 1);
+        assert!(items[0].is_err(), "the stream should end with the error");
+        assert_eq!(calls, MAX_CONSECUTIVE_SKIPS + 1);
+    }
+
+    #[test]
+    fn retry_should_complete_with_every_item() {
+        let (items, calls) = collect_with_failing_middle(PageErrorPolicy::RetryN(2), 2);
+
+        let items: Vec<_> = items.into_iter().map(Result::unwrap).collect();
+        assert_eq!(items, [0, 1, 2, 3, 4]);
+        assert_eq!(calls, 5);
+    }
+
+    #[test]
+    fn retry_should_fail_once_retries_are_exhausted() {
+        let (items, calls) = collect_with_failing_middle(PageErrorPolicy::RetryN(1), 2);
+
+        assert_eq!(items.len(), 3);
+        assert!(items[2].is_err(), "exhausted retries should end the stream");
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn short_page_should_continue_when_server_returned_full_page() {
+        let items = block_on(
+            paginate(2, PageErrorPolicy::Fail, |offset: i32, limit: i32| {
+                let offset_u32 = u32::try_from(offset).unwrap();
+                let limit_u32 = u32::try_from(limit).unwrap();
+                let page = match offset {
+                    // One of the two items the server returned was dropped on the client
+                    0 => {
+                        let mut page = GammaPage::new(vec![0], None, offset_u32, limit_u32);
+                        page.fetched = 2;
+                        page
+                    }
+                    _ => GammaPage::new(vec![offset], None, offset_u32, limit_u32),
+                };
+                future::ready(Ok(page))
+            })
+            .collect::<Vec<_>>(),
+        );
+
+        let items: Vec<_> = items.into_iter().map(Result::unwrap).collect();
+        assert_eq!(items, [0, 2]);
+    }
+
+    #[test]
+    fn offset_overflow_should_end_stream() {
+        let calls = Cell::new(0);
+        let limit = i32::MAX / 2 + 1;
+        let items = block_on(
+            paginate(limit, PageErrorPolicy::Fail, |offset, limit| {
+                calls.set(calls.get() + 1);
+                // Every page reports being full, so only the overflow can end the stream
+                let mut page = GammaPage::new(vec![offset], None, 0, 0);
+                page.fetched = usize::try_from(limit).unwrap();
+                future::ready(Ok(page))
+            })
+            .collect::<Vec<_>>(),
+        );
+
+        let items: Vec<_> = items.into_iter().map(Result::unwrap).collect();
+        assert_eq!(items, [0, limit]);
+        assert_eq!(calls.get(), 2, "the third offset would overflow");
+    }
+
+    #[test]
+    fn non_positive_limit_should_fail() {
+        let items = block_on(
+            paginate::<Vec<i32>, _, _>(0, PageErrorPolicy::Fail, |_, _| future::ready(Ok(vec![])))
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(items.len(), 1);
+        assert!(items[0].is_err(), "a zero limit should be rejected");
+    }
+}
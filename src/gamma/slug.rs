@@ -0,0 +1,149 @@
+//! Slugs as used in the URLs of Gamma events, markets, and tags.
+//!
+//! A slug is made of lowercase ASCII letters and digits, in words separated by single hyphens,
+//! e.g. `nba-finals`. [`slugify`] turns free-form input such as `"NBA Finals"` into that form, so
+//! that it can be passed to the `*_by_slug` methods of [`Client`](super::Client), which also do it
+//! themselves when the `normalize_slugs` of [`Config`](super::Config) is set.
+
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`is_valid_slug` reads as the check it is"
+)]
+
+/// Converts `s` to a slug: letters are lowercased, accented Latin letters are replaced by their
+/// ASCII counterparts (e.g. `é` by `e`, `ß` by `ss`), and every run of other characters becomes a
+/// single hyphen, without leading or trailing hyphens.
+///
+/// The result is empty if `s` has no letters or digits that can be written in ASCII.
+#[must_use]
+pub fn slugify(s: &str) -> String {
+    let mut slug = String::with_capacity(s.len());
+    let mut separated = false;
+
+    for c in s.chars().flat_map(char::to_lowercase) {
+        let mut ascii = [0; 4];
+        let letters = if c.is_ascii_alphanumeric() {
+            &*c.encode_utf8(&mut ascii)
+        } else if is_combining_mark(c) {
+            // An accent written as its own character belongs to the letter before it
+            continue;
+        } else if let Some(letters) = transliterate(c) {
+            letters
+        } else {
+            separated = true;
+            continue;
+        };
+
+        if separated && !slug.is_empty() {
+            slug.push('-');
+        }
+        separated = false;
+        slug.push_str(letters);
+    }
+
+    slug
+}
+
+/// Returns `true` for the combining diacritical marks, e.g. the accent of a decomposed `é`.
+fn is_combining_mark(c: char) -> bool {
+    matches!(c, '\u{300}'..='\u{36f}')
+}
+
+/// Returns the ASCII spelling of a lowercase accented Latin letter, if it has one.
+fn transliterate(c: char) -> Option<&'static str> {
+    let letters = match c {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ð' | 'ď' | 'đ' => "d",
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'þ' => "th",
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+
+    Some(letters)
+}
+
+/// Returns `true` if `s` is a non-empty slug, i.e. [`slugify`] leaves it unchanged.
+#[must_use]
+pub fn is_valid_slug(s: &str) -> bool {
+    !s.is_empty()
+        && s.split('-').all(|word| {
+            !word.is_empty()
+                && word
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_should_lowercase_and_hyphenate() {
+        assert_eq!(slugify("NBA Finals"), "nba-finals");
+        assert_eq!(slugify("  Will BTC hit $100k?  "), "will-btc-hit-100k");
+        assert_eq!(slugify("fed--rate_cut / 2025"), "fed-rate-cut-2025");
+        assert_eq!(
+            slugify("Élection présidentielle"),
+            "election-presidentielle"
+        );
+        assert_eq!(slugify("Straße nach Łódź"), "strasse-nach-lodz");
+        assert_eq!(slugify("Cafe\u{301} Zürich"), "cafe-zurich");
+        assert_eq!(slugify("Tokyo 東京 2025"), "tokyo-2025");
+        assert_eq!(slugify("nba-finals"), "nba-finals");
+        assert_eq!(slugify("?!"), "");
+    }
+
+    #[test]
+    fn valid_slugs_should_pass() {
+        for slug in ["nba-finals", "2025", "will-btc-hit-100k", "a"] {
+            assert!(is_valid_slug(slug), "{slug:?} should be valid");
+        }
+    }
+
+    #[test]
+    fn malformed_slugs_should_fail() {
+        for slug in [
+            "",
+            "NBA Finals",
+            "nba finals",
+            "-nba",
+            "nba-",
+            "nba--finals",
+            "nbä",
+        ] {
+            assert!(!is_valid_slug(slug), "{slug:?} should be invalid");
+        }
+    }
+
+    #[test]
+    fn slugified_input_should_be_valid() {
+        for input in [
+            "NBA Finals",
+            "  Will BTC hit $100k?  ",
+            "fed--rate_cut / 2025",
+            "Élection présidentielle",
+        ] {
+            assert!(is_valid_slug(&slugify(input)), "{input:?} should slugify");
+        }
+    }
+}
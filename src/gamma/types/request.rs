@@ -4,13 +4,31 @@
 )]
 
 use bon::Builder;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::Serialize;
 use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as, skip_serializing_none};
 
+use crate::Result;
+use crate::error::Error;
 use crate::gamma::types::{ParentEntityType, RelatedTagsStatus};
 use crate::types::Decimal;
 
+/// Ensures that `min <= max` when both bounds of a date range filter are set.
+fn validate_date_range(
+    name: &str,
+    min: Option<DateTime<Utc>>,
+    max: Option<DateTime<Utc>>,
+) -> Result<()> {
+    match (min, max) {
+        (Some(min), Some(max)) if min > max => Err(Error::validation(format!(
+            "{name}_min ({}) must not be after {name}_max ({})",
+            min.to_rfc3339(),
+            max.to_rfc3339()
+        ))),
+        _ => Ok(()),
+    }
+}
+
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Default, Serialize)]
@@ -34,6 +52,26 @@ pub struct TeamsRequest {
     pub abbreviation: Vec<String>,
 }
 
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, Builder, Default, Serialize)]
+#[non_exhaustive]
+pub struct SportsGamesRequest {
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[builder(default)]
+    pub league: Vec<String>,
+    /// Only games scheduled on this day, e.g. `2025-01-15`
+    pub date: Option<NaiveDate>,
+    /// Only games in this state, e.g. `"InProgress"` or `"Final"`, see [`Game::status`]
+    ///
+    /// [`Game::status`]: crate::gamma::types::response::Game::status
+    #[builder(into)]
+    pub status: Option<String>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Default, Serialize)]
 #[non_exhaustive]
@@ -44,6 +82,7 @@ pub struct TagsRequest {
     pub ascending: Option<bool>,
     pub include_template: Option<bool>,
     pub is_carousel: Option<bool>,
+    pub force_show: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -88,6 +127,45 @@ pub struct RelatedTagsBySlugRequest {
     pub status: Option<RelatedTagsStatus>,
 }
 
+/// Limits for loading the tag taxonomy with [`Client::load_tag_taxonomy`].
+///
+/// [`Client::load_tag_taxonomy`]: crate::gamma::Client::load_tag_taxonomy
+#[derive(Debug, Clone, Builder)]
+#[non_exhaustive]
+pub struct TagTaxonomyRequest {
+    /// Tags requested per page of `/tags`
+    #[builder(default = 100)]
+    pub page_size: i32,
+    /// Most tags to load; tags past this bound are not fetched
+    #[builder(default = 5_000)]
+    pub max_tags: usize,
+    /// Most relationship requests in flight at a time
+    #[builder(default = 8)]
+    pub concurrency: usize,
+    /// Only load relationships in this state
+    pub status: Option<RelatedTagsStatus>,
+}
+
+impl Default for TagTaxonomyRequest {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl TagTaxonomyRequest {
+    /// Checks that `page_size` and `concurrency` are positive. This is called by
+    /// [`crate::gamma::Client::load_tag_taxonomy`] before sending.
+    pub fn validate(&self) -> Result<()> {
+        if self.page_size <= 0 {
+            return Err(Error::validation("page_size must be greater than zero"));
+        }
+        if self.concurrency == 0 {
+            return Err(Error::validation("concurrency must be greater than zero"));
+        }
+        Ok(())
+    }
+}
+
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Default, Serialize)]
@@ -115,6 +193,8 @@ pub struct EventsRequest {
     #[builder(default)]
     pub slug: Vec<String>,
     pub tag_slug: Option<String>,
+    #[builder(into)]
+    pub series_id: Option<String>,
     pub related_tags: Option<bool>,
     pub active: Option<bool>,
     pub archived: Option<bool>,
@@ -134,6 +214,15 @@ pub struct EventsRequest {
     pub end_date_max: Option<DateTime<Utc>>,
 }
 
+impl EventsRequest {
+    /// Checks that the `start_date` and `end_date` range filters have `min <= max` when both
+    /// bounds are set. This is called by [`crate::gamma::Client::events`] before sending.
+    pub fn validate(&self) -> Result<()> {
+        validate_date_range("start_date", self.start_date_min, self.start_date_max)?;
+        validate_date_range("end_date", self.end_date_min, self.end_date_max)
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
 #[non_exhaustive]
@@ -216,10 +305,28 @@ pub struct MarketsRequest {
     #[builder(default)]
     pub question_ids: Vec<String>,
     pub include_tag: Option<bool>,
+    pub active: Option<bool>,
     pub closed: Option<bool>,
+    /// Whether to re-check the `closed` filter on the client after fetching. The server's
+    /// flags can lag behind a market's resolution, so e.g. `closed(false)` may still return
+    /// markets that have already been resolved. When set, markets whose locally computed
+    /// [`Market::state`](crate::gamma::types::response::Market::state) contradicts `closed` are
+    /// dropped from the results, as are closed or resolved markets under `active(true)` when
+    /// `closed` is not set. This is a belt-and-suspenders filter on top of the server's,
+    /// and a page may come back shorter than `limit` because of it. Not sent to the API.
+    #[serde(skip_serializing)]
+    #[builder(default)]
+    pub enforce_status: bool,
 }
 
 impl MarketsRequest {
+    /// Checks that the `start_date` and `end_date` range filters have `min <= max` when both
+    /// bounds are set. This is called by [`crate::gamma::Client::markets`] before sending.
+    pub fn validate(&self) -> Result<()> {
+        validate_date_range("start_date", self.start_date_min, self.start_date_max)?;
+        validate_date_range("end_date", self.end_date_min, self.end_date_max)
+    }
+
     /// Returns the repeated query parameters for `clob_token_ids`.
     ///
     /// The Gamma API expects `clob_token_ids` as repeated query parameters
@@ -3,14 +3,26 @@
     reason = "Response suffix is intentional for clarity"
 )]
 
-use chrono::{DateTime, Utc};
+use std::borrow::Borrow;
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
-use serde_with::serde_as;
+use serde_with::{DefaultOnNull, NoneAsEmptyString, serde_as};
+use url::Url;
 
-use crate::serde_helpers::StringFromAny;
+use crate::Result;
+use crate::clob::types::MarketState;
+use crate::error::Error;
+use crate::gamma::slug::slugify;
+use crate::serde_helpers::{BoolFromAny, StringFromAny, TimestampFromAny};
 use crate::types::Decimal;
 
 /// Image optimization metadata.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -20,6 +32,7 @@ pub struct ImageOptimization {
     pub image_url_optimized: Option<String>,
     pub image_size_kb_source: Option<f64>,
     pub image_size_kb_optimized: Option<f64>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub image_optimized_complete: Option<bool>,
     pub image_optimized_last_updated: Option<String>,
     #[serde(rename = "relID")]
@@ -29,17 +42,253 @@ pub struct ImageOptimization {
 }
 
 /// Pagination information.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct Pagination {
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub has_more: Option<bool>,
     pub total_results: Option<i32>,
 }
 
+/// Defines an enum over the known values of a string field, with an `Unknown` fallback that keeps
+/// any value the API adds later, so that a new value never fails deserialization. Known values
+/// are matched case-insensitively.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[non_exhaustive]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(from = "String", into = "String")]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+            /// A value not known to this version of the crate, as sent by the API.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// Returns the value as sent by the API.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)+
+                    Self::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                $(if value.eq_ignore_ascii_case($value) {
+                    return Self::$variant;
+                })+
+                Self::Unknown(value)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                match value {
+                    $name::Unknown(value) => value,
+                    known => known.as_str().to_owned(),
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// Status of the UMA oracle resolution of a market.
+    pub enum UmaResolutionStatus {
+        /// An outcome has been proposed and is in its challenge period.
+        Proposed => "proposed",
+        /// The proposed outcome has been disputed.
+        Disputed => "disputed",
+        /// The outcome has been settled.
+        Resolved => "resolved",
+    }
+}
+
+string_enum! {
+    /// State of a sports [`Game`].
+    pub enum GameStatus {
+        Scheduled => "Scheduled",
+        InProgress => "InProgress",
+        Final => "Final",
+    }
+}
+
+string_enum! {
+    /// How often the events of a [`Series`] recur.
+    pub enum Recurrence {
+        Hourly => "hourly",
+        Daily => "daily",
+        Weekly => "weekly",
+        Monthly => "monthly",
+    }
+}
+
+string_enum! {
+    /// Kind of a [`Market`].
+    pub enum MarketType {
+        /// A market on one or more discrete outcomes.
+        Normal => "normal",
+        /// A market on a numeric range.
+        Scalar => "scalar",
+    }
+}
+
+string_enum! {
+    /// Kind of the entity a [`Comment`] was posted on.
+    pub enum CommentParentType {
+        Event => "Event",
+        Series => "Series",
+        Market => "market",
+    }
+}
+
+string_enum! {
+    /// Kind of a [`Reaction`] to a comment.
+    pub enum ReactionType {
+        Heart => "HEART",
+        ThumbsUp => "THUMBS_UP",
+    }
+}
+
+string_enum! {
+    /// Top-level category of an event or market, for grouping them in a stable way, e.g. into
+    /// the sections of a UI. Derived from its tags with [`TopLevelCategory::from_tags`].
+    pub enum TopLevelCategory {
+        Politics => "politics",
+        Sports => "sports",
+        Crypto => "crypto",
+        Economics => "economy",
+        PopCulture => "pop-culture",
+        Business => "business",
+        Science => "science",
+        Tech => "tech",
+        World => "world",
+    }
+}
+
+/// Tag slugs that belong to a top-level category other than its own slug.
+const CATEGORY_ALIASES: &[(&str, TopLevelCategory)] = &[
+    ("elections", TopLevelCategory::Politics),
+    ("us-politics", TopLevelCategory::Politics),
+    ("us-election", TopLevelCategory::Politics),
+    ("nba", TopLevelCategory::Sports),
+    ("nfl", TopLevelCategory::Sports),
+    ("mlb", TopLevelCategory::Sports),
+    ("nhl", TopLevelCategory::Sports),
+    ("soccer", TopLevelCategory::Sports),
+    ("tennis", TopLevelCategory::Sports),
+    ("bitcoin", TopLevelCategory::Crypto),
+    ("ethereum", TopLevelCategory::Crypto),
+    ("crypto-prices", TopLevelCategory::Crypto),
+    ("economics", TopLevelCategory::Economics),
+    ("fed", TopLevelCategory::Economics),
+    ("fed-rates", TopLevelCategory::Economics),
+    ("inflation", TopLevelCategory::Economics),
+    ("movies", TopLevelCategory::PopCulture),
+    ("music", TopLevelCategory::PopCulture),
+    ("celebrities", TopLevelCategory::PopCulture),
+    ("stocks", TopLevelCategory::Business),
+    ("space", TopLevelCategory::Science),
+    ("ai", TopLevelCategory::Tech),
+    ("geopolitics", TopLevelCategory::World),
+];
+
+impl TopLevelCategory {
+    /// Returns the category of the first of `tags` that belongs to one, by its slug or, without
+    /// a slug, its slugified label. Besides the slug of each category, e.g. `politics`, common
+    /// sub-tags such as `elections` or `nba` count towards it.
+    ///
+    /// Returns `Unknown` with the slug of the first tag if none belongs to a category, or with an
+    /// empty string if there are no tags.
+    #[must_use]
+    pub fn from_tags(tags: &[Tag]) -> Self {
+        let slugs: Vec<String> = tags
+            .iter()
+            .filter_map(|tag| match tag.slug.as_deref().map(str::trim) {
+                Some(slug) if !slug.is_empty() => Some(slug.to_ascii_lowercase()),
+                _ => tag.label.as_deref().map(slugify),
+            })
+            .filter(|slug| !slug.is_empty())
+            .collect();
+
+        for slug in &slugs {
+            if let Some((_, category)) = CATEGORY_ALIASES.iter().find(|(alias, _)| alias == slug) {
+                return category.clone();
+            }
+            let category = Self::from(slug.clone());
+            if !matches!(category, Self::Unknown(_)) {
+                return category;
+            }
+        }
+
+        Self::Unknown(slugs.into_iter().next().unwrap_or_default())
+    }
+}
+
 /// Health check response.
 pub type HealthResponse = String;
 
+/// One page of a listing, along with the total number of matching items when the API reports it,
+/// e.g. to render "showing 20 of 340".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GammaPage<T> {
+    /// Items on this page.
+    pub items: Vec<T>,
+    /// Total number of items across all pages, if the API reported it.
+    pub total: Option<u64>,
+    /// Offset of the first item on this page.
+    pub offset: u32,
+    /// Requested page size, or the number of items returned if the request set no limit.
+    pub limit: u32,
+    /// Number of items the server returned on this page, which is more than `items` holds when
+    /// malformed items were skipped or items were dropped on the client, e.g. by
+    /// `enforce_status`.
+    #[serde(default)]
+    pub fetched: usize,
+}
+
+impl<T> GammaPage<T> {
+    #[must_use]
+    pub fn new(items: Vec<T>, total: Option<u64>, offset: u32, limit: u32) -> Self {
+        Self {
+            fetched: items.len(),
+            items,
+            total,
+            offset,
+            limit,
+        }
+    }
+
+    /// Returns `true` if items remain after this page. Without a total, a full page is assumed
+    /// to have more. Both are judged by the items the server returned, not those kept.
+    #[must_use]
+    pub fn has_more(&self) -> bool {
+        let end = u64::from(self.offset) + self.fetched as u64;
+        match self.total {
+            Some(total) => end < total,
+            None => self.limit > 0 && self.fetched >= self.limit as usize,
+        }
+    }
+}
+
 /// A sports team.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -58,6 +307,51 @@ pub struct Team {
     pub provider_id: Option<i32>,
 }
 
+/// Live state of a sports game, as tracked for the markets on it.
+///
+/// Games that have not started yet have no scores, period or elapsed time.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Game {
+    #[serde_as(as = "StringFromAny")]
+    pub id: String,
+    pub league: Option<String>,
+    pub home_team: Option<Team>,
+    pub away_team: Option<Team>,
+    pub home_score: Option<i32>,
+    pub away_score: Option<i32>,
+    /// Current period of play, e.g. `"Q3"` or `"2H"`
+    pub period: Option<String>,
+    /// Time elapsed in the current period, e.g. `"07:42"`
+    pub elapsed: Option<String>,
+    pub status: Option<GameStatus>,
+    #[serde_as(as = "Option<BoolFromAny>")]
+    pub live: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
+    pub ended: Option<bool>,
+    pub start_time: Option<DateTime<Utc>>,
+    #[serde_as(as = "Option<StringFromAny>")]
+    #[serde(default)]
+    pub event_id: Option<String>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Game {
+    /// Returns the home and away scores, or `None` if the game has not started.
+    #[must_use]
+    pub fn score(&self) -> Option<(i32, i32)> {
+        self.home_score.zip(self.away_score)
+    }
+
+    /// Returns `true` if the game is being played right now.
+    #[must_use]
+    pub fn is_live(&self) -> bool {
+        self.live == Some(true) && self.ended != Some(true)
+    }
+}
+
 /// Sports metadata information.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -74,14 +368,53 @@ pub struct SportsMetadata {
 }
 
 /// Sports market types response.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct SportsMarketTypesResponse {
+    #[serde(default)]
+    #[serde_as(deserialize_as = "DefaultOnNull")]
     pub market_types: Vec<String>,
 }
 
+impl SportsMarketTypesResponse {
+    /// Returns the market type matching `key`, ignoring ASCII case.
+    #[must_use]
+    pub fn find(&self, key: &str) -> Option<&str> {
+        self.market_types
+            .iter()
+            .map(String::as_str)
+            .find(|market_type| market_type.eq_ignore_ascii_case(key))
+    }
+
+    /// Returns `true` if `key` is a valid market type, ignoring ASCII case.
+    #[must_use]
+    pub fn contains(&self, key: &str) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Returns the market types specific to `sport`, ignoring ASCII case.
+    ///
+    /// Sport-specific market types are keyed as `{sport}_{type}` (e.g. `tennis_match_totals`),
+    /// while generic ones such as `moneyline` are shared across sports and not returned here.
+    pub fn by_sport<'resp>(
+        &'resp self,
+        sport: &'resp str,
+    ) -> impl Iterator<Item = &'resp str> + 'resp {
+        self.market_types
+            .iter()
+            .map(String::as_str)
+            .filter(move |market_type| {
+                market_type
+                    .split_once('_')
+                    .is_some_and(|(prefix, _)| prefix.eq_ignore_ascii_case(sport))
+            })
+    }
+}
+
 /// A tag for categorizing content.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -89,17 +422,109 @@ pub struct Tag {
     pub id: String,
     pub label: Option<String>,
     pub slug: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub force_show: Option<bool>,
     pub published_at: Option<String>,
     pub created_by: Option<i32>,
     pub updated_by: Option<i32>,
+    #[serde_as(deserialize_as = "NoneAsEmptyString")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(deserialize_as = "NoneAsEmptyString")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub force_hide: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub is_carousel: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub requires_translation: Option<bool>,
 }
 
+impl fmt::Display for Tag {
+    /// Formats the tag as `#{id} {slug}`, e.g. `#100 politics`, falling back to its label without
+    /// a slug and to `#{id}` alone without either.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_labeled(f, &self.id, self.slug.as_deref().or(self.label.as_deref()))
+    }
+}
+
+impl Tag {
+    /// Returns `true` if `other` is the same tag, i.e. has the same `id`.
+    ///
+    /// Unlike `==`, which compares every field, this still holds between two snapshots of a tag
+    /// fetched before and after an update.
+    #[must_use]
+    pub fn same_as(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+
+    /// Returns how long ago at `now` this tag was last changed, i.e. since `updated_at`, or
+    /// since `created_at` if it was never updated. Returns `None` if neither is known.
+    #[must_use]
+    pub fn age(&self, now: DateTime<Utc>) -> Option<TimeDelta> {
+        self.updated_at
+            .or(self.created_at)
+            .map(|changed| now - changed)
+    }
+
+    /// Returns `true` if this tag was last changed more than `max_age` before `now`, e.g. to
+    /// decide whether a cached copy should be refetched.
+    ///
+    /// A tag without timestamps has an unknown age and is never stale. See [`Tag::age`].
+    #[must_use]
+    pub fn is_stale(&self, max_age: TimeDelta, now: DateTime<Utc>) -> bool {
+        self.age(now).is_some_and(|age| age > max_age)
+    }
+}
+
+/// A [`Tag`] compared and hashed by its `id` only.
+///
+/// [`Tag`] compares all of its fields, so two snapshots of the same tag taken at different times
+/// are not equal. Wrap tags in `TagById` to deduplicate them or use them as map keys. Since it
+/// borrows as `str`, a `HashSet<TagById>` can be queried with a plain id.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct TagById(pub Tag);
+
+impl PartialEq for TagById {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.id == other.0.id
+    }
+}
+
+impl Eq for TagById {}
+
+impl Hash for TagById {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.id.hash(state);
+    }
+}
+
+impl PartialOrd for TagById {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TagById {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.id.cmp(&other.0.id)
+    }
+}
+
+impl Borrow<str> for TagById {
+    fn borrow(&self) -> &str {
+        &self.0.id
+    }
+}
+
+impl From<Tag> for TagById {
+    fn from(tag: Tag) -> Self {
+        Self(tag)
+    }
+}
+
 /// A relationship between tags.
 #[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -117,6 +542,131 @@ pub struct RelatedTag {
     pub rank: Option<i32>,
 }
 
+/// The tags and their relationships, loaded at once by [`Client::load_tag_taxonomy`].
+///
+/// Relationships are directed from a tag to its related tags, which are its children here. Roots
+/// are the tags that no other tag lists as related. Relationships to tags that are not part of
+/// the taxonomy are dropped.
+///
+/// [`Client::load_tag_taxonomy`]: crate::gamma::Client::load_tag_taxonomy
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct TagTaxonomy {
+    tags: Vec<Tag>,
+    by_id: HashMap<String, usize>,
+    by_slug: HashMap<String, usize>,
+    children: HashMap<usize, Vec<usize>>,
+    roots: Vec<usize>,
+}
+
+impl TagTaxonomy {
+    /// Builds a taxonomy from `tags` and the `relationships` between them. Children are ordered
+    /// by their relationship's rank, and everything else keeps the order of `tags`.
+    #[must_use]
+    pub fn new<R: IntoIterator<Item = RelatedTag>>(tags: Vec<Tag>, relationships: R) -> Self {
+        let by_id: HashMap<String, usize> = tags
+            .iter()
+            .enumerate()
+            .map(|(index, tag)| (tag.id.clone(), index))
+            .collect();
+        let by_slug = tags
+            .iter()
+            .enumerate()
+            .filter_map(|(index, tag)| Some((tag.slug.clone()?, index)))
+            .collect();
+
+        let mut edges: Vec<(usize, Option<i32>, usize)> = relationships
+            .into_iter()
+            .filter_map(|relationship| {
+                let parent = *by_id.get(relationship.tag_id.as_deref()?)?;
+                let child = *by_id.get(relationship.related_tag_id.as_deref()?)?;
+                (parent != child).then_some((parent, relationship.rank, child))
+            })
+            .collect();
+        // Ranked relationships first, lowest rank first
+        edges.sort_by_key(|&(parent, rank, child)| (parent, rank.is_none(), rank, child));
+
+        let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut is_child = vec![false; tags.len()];
+        for (parent, _, child) in edges {
+            let siblings = children.entry(parent).or_default();
+            if !siblings.contains(&child) {
+                siblings.push(child);
+            }
+            is_child[child] = true;
+        }
+        let roots = (0..tags.len()).filter(|&index| !is_child[index]).collect();
+
+        Self {
+            tags,
+            by_id,
+            by_slug,
+            children,
+            roots,
+        }
+    }
+
+    /// Returns the tag with the given `id`.
+    #[must_use]
+    pub fn by_id(&self, id: &str) -> Option<&Tag> {
+        self.by_id.get(id).map(|&index| &self.tags[index])
+    }
+
+    /// Returns the tag with the given `slug`.
+    #[must_use]
+    pub fn by_slug(&self, slug: &str) -> Option<&Tag> {
+        self.by_slug.get(slug).map(|&index| &self.tags[index])
+    }
+
+    /// Returns the related tags of the tag `id`, by rank.
+    #[must_use]
+    pub fn children(&self, id: &str) -> Vec<&Tag> {
+        self.by_id
+            .get(id)
+            .and_then(|index| self.children.get(index))
+            .into_iter()
+            .flatten()
+            .map(|&index| &self.tags[index])
+            .collect()
+    }
+
+    /// Returns the tags that are not related to from any other tag.
+    #[must_use]
+    pub fn roots(&self) -> Vec<&Tag> {
+        self.roots.iter().map(|&index| &self.tags[index]).collect()
+    }
+
+    /// Returns every tag of the taxonomy.
+    #[must_use]
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    /// Returns the number of tags.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    /// Returns `true` if the taxonomy has no tags.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+}
+
+/// Progress of [`Client::load_tag_taxonomy`], reported after each request.
+///
+/// [`Client::load_tag_taxonomy`]: crate::gamma::Client::load_tag_taxonomy
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaxonomyProgress {
+    /// A page of tags was loaded, bringing the total to `loaded`.
+    Tags { loaded: usize },
+    /// The relationships of `loaded` out of `total` tags were loaded.
+    Relationships { loaded: usize, total: usize },
+}
+
 /// A category for organizing content.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -148,6 +698,7 @@ pub struct EventCreator {
 }
 
 /// A chat/live stream associated with an event.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -156,12 +707,14 @@ pub struct Chat {
     pub channel_id: Option<String>,
     pub channel_name: Option<String>,
     pub channel_image: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub live: Option<bool>,
     pub start_time: Option<DateTime<Utc>>,
     pub end_time: Option<DateTime<Utc>>,
 }
 
 /// A template for creating events/markets.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -173,14 +726,17 @@ pub struct Template {
     pub market_title: Option<String>,
     pub description: Option<String>,
     pub resolution_source: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub neg_risk: Option<bool>,
     pub sort_by: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub show_market_images: Option<bool>,
     pub series_slug: Option<String>,
     pub outcomes: Option<String>,
 }
 
 /// A collection of events.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -197,12 +753,19 @@ pub struct Collection {
     pub icon: Option<String>,
     pub header_image: Option<String>,
     pub layout: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub active: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub closed: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub archived: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub new: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub featured: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub restricted: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub is_template: Option<bool>,
     pub template_variables: Option<String>,
     pub published_at: Option<String>,
@@ -210,6 +773,7 @@ pub struct Collection {
     pub updated_by: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub comments_enabled: Option<bool>,
     pub image_optimized: Option<ImageOptimization>,
     pub icon_optimized: Option<ImageOptimization>,
@@ -234,11 +798,17 @@ pub struct Event {
     pub end_date: Option<DateTime<Utc>>,
     pub image: Option<String>,
     pub icon: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub active: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub closed: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub archived: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub new: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub featured: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub restricted: Option<bool>,
     pub liquidity: Option<Decimal>,
     pub volume: Option<Decimal>,
@@ -246,6 +816,7 @@ pub struct Event {
     pub sort_by: Option<String>,
     pub category: Option<String>,
     pub subcategory: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub is_template: Option<bool>,
     pub template_variables: Option<String>,
     #[serde(alias = "published_at")]
@@ -254,6 +825,7 @@ pub struct Event {
     pub updated_by: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub comments_enabled: Option<bool>,
     pub competitive: Option<Decimal>,
     pub volume_24hr: Option<Decimal>,
@@ -268,9 +840,11 @@ pub struct Event {
     pub sportsradar_match_id: Option<String>,
     #[serde_as(as = "Option<StringFromAny>")]
     pub turn_provider_id: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub enable_order_book: Option<bool>,
     pub liquidity_amm: Option<Decimal>,
     pub liquidity_clob: Option<Decimal>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub neg_risk: Option<bool>,
     #[serde(rename = "negRiskMarketID")]
     pub neg_risk_market_id: Option<String>,
@@ -285,12 +859,18 @@ pub struct Event {
     pub categories: Option<Vec<Category>>,
     pub collections: Option<Vec<Collection>>,
     pub tags: Option<Vec<Tag>>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub cyom: Option<bool>,
     pub closed_time: Option<DateTime<Utc>>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub show_all_outcomes: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub show_market_images: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub automatically_resolved: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub enable_neg_risk: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub automatically_active: Option<bool>,
     pub event_date: Option<String>,
     pub start_time: Option<DateTime<Utc>>,
@@ -299,7 +879,9 @@ pub struct Event {
     pub score: Option<String>,
     pub elapsed: Option<String>,
     pub period: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub live: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub ended: Option<bool>,
     pub finished_timestamp: Option<DateTime<Utc>>,
     pub gmp_chart_mode: Option<String>,
@@ -307,28 +889,190 @@ pub struct Event {
     pub tweet_count: Option<i32>,
     pub chats: Option<Vec<Chat>>,
     pub featured_order: Option<i32>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub estimate_value: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub cant_estimate: Option<bool>,
     pub estimated_value: Option<String>,
     pub templates: Option<Vec<Template>>,
     pub spreads_main_line: Option<Decimal>,
     pub totals_main_line: Option<Decimal>,
     pub carousel_map: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub pending_deployment: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub deploying: Option<bool>,
     pub deploying_timestamp: Option<DateTime<Utc>>,
     pub scheduled_deployment_timestamp: Option<DateTime<Utc>>,
     pub game_status: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub requires_translation: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub neg_risk_augmented: Option<bool>,
     pub game_id: Option<i64>,
     pub election_type: Option<String>,
     pub country_name: Option<String>,
     pub color: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub cumulative_markets: Option<bool>,
 }
 
+impl fmt::Display for Event {
+    /// Formats the event as `#{id} {slug}`, falling back to its title without a slug and to
+    /// `#{id}` alone without either.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_labeled(f, &self.id, self.slug.as_deref().or(self.title.as_deref()))
+    }
+}
+
+impl Event {
+    /// Returns the URL of the image of this event, or `None` if it is blank or invalid. A
+    /// relative URL is resolved against `base`, e.g. the CDN serving the images, and is
+    /// `None` without one. Only `http` and `https` URLs are returned.
+    #[must_use]
+    pub fn image_url(&self, base: Option<&Url>) -> Option<Url> {
+        image_url(self.image.as_deref(), base)
+    }
+
+    /// Returns the URL of the icon of this event, like [`Event::image_url`].
+    #[must_use]
+    pub fn icon_url(&self, base: Option<&Url>) -> Option<Url> {
+        image_url(self.icon.as_deref(), base)
+    }
+
+    /// Returns the first series this event belongs to, if any. Gamma returns `null` rather than
+    /// an empty list for events outside of a series.
+    #[must_use]
+    pub fn first_series(&self) -> Option<&Series> {
+        self.series.as_deref()?.first()
+    }
+
+    /// Returns the first template used to create this event, if any.
+    #[must_use]
+    pub fn first_template(&self) -> Option<&Template> {
+        self.templates.as_deref()?.first()
+    }
+
+    /// Returns `true` if this event is tagged with the tag `id`. A missing tag list is treated
+    /// as empty.
+    #[must_use]
+    pub fn has_tag(&self, id: &str) -> bool {
+        self.tags.iter().flatten().any(|tag| tag.id == id)
+    }
+
+    /// Returns the slugs of the tags on this event, skipping tags without a slug.
+    pub fn tag_slugs(&self) -> impl Iterator<Item = &str> {
+        self.tags
+            .iter()
+            .flatten()
+            .filter_map(|tag| tag.slug.as_deref())
+    }
+
+    /// Returns `true` if Gamma features this event, e.g. to highlight it. A missing flag is
+    /// `false`.
+    #[must_use]
+    pub fn is_featured(&self) -> bool {
+        self.featured.unwrap_or(false)
+    }
+
+    /// Returns `true` if Gamma flags this event as geo-restricted. A missing flag is `false`.
+    #[must_use]
+    pub fn is_restricted(&self) -> bool {
+        self.restricted.unwrap_or(false)
+    }
+
+    /// Returns the top-level category of this event, derived from its tags with
+    /// [`TopLevelCategory::from_tags`]. Unlike the free-text `category` field, which Gamma
+    /// often leaves empty, this buckets events the same way whatever their sub-tags.
+    #[must_use]
+    pub fn category(&self) -> TopLevelCategory {
+        TopLevelCategory::from_tags(self.tags.as_deref().unwrap_or_default())
+    }
+
+    /// Returns the number of comments on this event, or `None` if Gamma did not report it.
+    /// Negative counts are treated as missing.
+    #[must_use]
+    pub fn comment_count(&self) -> Option<u32> {
+        u32::try_from(self.comment_count?).ok()
+    }
+
+    /// Returns `true` if this event traded at least `min_volume_24hr` over the last 24 hours.
+    /// Gamma has no trending flag on events, so the threshold decides, e.g. to pick the events
+    /// for a homepage. An event without a 24-hour volume is not trending.
+    #[must_use]
+    pub fn is_trending(&self, min_volume_24hr: Decimal) -> bool {
+        self.volume_24hr
+            .is_some_and(|volume| volume >= min_volume_24hr)
+    }
+
+    /// Returns the leading outcome of a multi-outcome event and its `Yes` price, i.e. the child
+    /// market whose `Yes` outcome has the highest implied probability. An outcome is named by
+    /// the `groupItemTitle` of its market, or its question if the title is missing.
+    ///
+    /// Only the markets embedded in the event are inspected, so fetch events with their markets
+    /// populated. Markets without a name or a valid `Yes` price are skipped. Returns `None` if no
+    /// market is left, or if several markets tie for the highest price.
+    #[must_use]
+    pub fn leading_outcome(&self) -> Option<(String, Decimal)> {
+        let mut leader: Option<(&str, Decimal)> = None;
+        let mut tied = false;
+
+        for market in self.markets.iter().flatten() {
+            let Some(name) = market
+                .group_item_title
+                .as_deref()
+                .or(market.question.as_deref())
+            else {
+                continue;
+            };
+            let Some(price) = market
+                .outcome_prices()
+                .ok()
+                .and_then(|prices| prices.get("Yes").copied())
+            else {
+                continue;
+            };
+
+            match leader {
+                Some((_, best)) if price < best => {}
+                Some((_, best)) if price == best => tied = true,
+                _ => {
+                    leader = Some((name, price));
+                    tied = false;
+                }
+            }
+        }
+
+        if tied {
+            return None;
+        }
+        leader.map(|(name, price)| (name.to_owned(), price))
+    }
+
+    /// Compares this event, the older fetch, against `other`, the newer one.
+    #[must_use]
+    pub fn diff(&self, other: &Event) -> EventDiff {
+        let tag_ids = |event: &Event| sorted_ids(event.tags.iter().flatten().map(|t| &t.id));
+        let market_ids = |event: &Event| sorted_ids(event.markets.iter().flatten().map(|m| &m.id));
+
+        EventDiff {
+            title: change(&self.title, &other.title),
+            volume: change(&self.volume, &other.volume),
+            volume_24hr: change(&self.volume_24hr, &other.volume_24hr),
+            liquidity: change(&self.liquidity, &other.liquidity),
+            open_interest: change(&self.open_interest, &other.open_interest),
+            active: change(&self.active, &other.active),
+            closed: change(&self.closed, &other.closed),
+            archived: change(&self.archived, &other.archived),
+            end_date: change(&self.end_date, &other.end_date),
+            tag_ids: change(&tag_ids(self), &tag_ids(other)),
+            market_ids: change(&market_ids(self), &market_ids(other)),
+        }
+    }
+}
+
 /// A prediction market.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -358,11 +1102,13 @@ pub struct Market {
     pub outcomes: Option<String>,
     pub outcome_prices: Option<String>,
     pub volume: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub active: Option<bool>,
-    pub market_type: Option<String>,
+    pub market_type: Option<MarketType>,
     pub format_type: Option<String>,
     pub lower_bound_date: Option<String>,
     pub upper_bound_date: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub closed: Option<bool>,
     pub market_maker_address: Option<String>,
     pub created_by: Option<i32>,
@@ -370,12 +1116,17 @@ pub struct Market {
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
     pub closed_time: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub wide_format: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub new: Option<bool>,
     pub mailchimp_tag: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub featured: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub archived: Option<bool>,
     pub resolved_by: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub restricted: Option<bool>,
     pub market_group: Option<i32>,
     pub group_item_title: Option<String>,
@@ -383,18 +1134,22 @@ pub struct Market {
     #[serde(rename = "questionID")]
     pub question_id: Option<String>,
     pub uma_end_date: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub enable_order_book: Option<bool>,
     pub order_price_min_tick_size: Option<Decimal>,
     pub order_min_size: Option<Decimal>,
-    pub uma_resolution_status: Option<String>,
+    pub uma_resolution_status: Option<UmaResolutionStatus>,
     pub curation_order: Option<i32>,
     pub volume_num: Option<Decimal>,
     pub liquidity_num: Option<Decimal>,
     pub end_date_iso: Option<String>,
     pub start_date_iso: Option<String>,
     pub uma_end_date_iso: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub has_reviewed_dates: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub ready_for_cron: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub comments_enabled: Option<bool>,
     pub volume_24hr: Option<Decimal>,
     pub volume_1wk: Option<Decimal>,
@@ -411,6 +1166,7 @@ pub struct Market {
     pub team_b_id: Option<String>,
     pub uma_bond: Option<String>,
     pub uma_reward: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub fpmm_live: Option<bool>,
     pub volume_24hr_amm: Option<Decimal>,
     pub volume_1wk_amm: Option<Decimal>,
@@ -427,7 +1183,9 @@ pub struct Market {
     pub maker_base_fee: Option<i32>,
     pub taker_base_fee: Option<i32>,
     pub custom_liveness: Option<i32>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub accepting_orders: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub notifications_enabled: Option<bool>,
     pub score: Option<i32>,
     pub image_optimized: Option<ImageOptimization>,
@@ -436,7 +1194,9 @@ pub struct Market {
     pub categories: Option<Vec<Category>>,
     pub tags: Option<Vec<Tag>>,
     pub creator: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub ready: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub funded: Option<bool>,
     pub past_slugs: Option<String>,
     pub ready_timestamp: Option<DateTime<Utc>>,
@@ -446,6 +1206,7 @@ pub struct Market {
     pub rewards_min_size: Option<Decimal>,
     pub rewards_max_spread: Option<Decimal>,
     pub spread: Option<Decimal>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub automatically_resolved: Option<bool>,
     pub one_day_price_change: Option<Decimal>,
     pub one_hour_price_change: Option<Decimal>,
@@ -455,38 +1216,55 @@ pub struct Market {
     pub last_trade_price: Option<Decimal>,
     pub best_bid: Option<Decimal>,
     pub best_ask: Option<Decimal>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub automatically_active: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub clear_book_on_start: Option<bool>,
     pub chart_color: Option<String>,
     pub series_color: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub show_gmp_series: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub show_gmp_outcome: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub manual_activation: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub neg_risk_other: Option<bool>,
     pub game_id: Option<String>,
     pub group_item_range: Option<String>,
     pub sports_market_type: Option<String>,
     pub line: Option<Decimal>,
     pub uma_resolution_statuses: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub pending_deployment: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub deploying: Option<bool>,
     pub deploying_timestamp: Option<DateTime<Utc>>,
     pub scheduled_deployment_timestamp: Option<DateTime<Utc>>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub rfq_enabled: Option<bool>,
     pub event_start_time: Option<DateTime<Utc>>,
     #[serde(alias = "submitted_by")]
     pub submitted_by: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub requires_translation: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub pager_duty_notification_enabled: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub approved: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub cyom: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub fees_enabled: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub holding_rewards_enabled: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub neg_risk: Option<bool>,
     #[serde(rename = "negRiskRequestID")]
     pub neg_risk_request_id: Option<String>,
     #[serde(rename = "negRiskMarketID")]
     pub neg_risk_market_id: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub sent_discord: Option<bool>,
     pub twitter_card_last_refreshed: Option<String>,
     pub twitter_card_location: Option<String>,
@@ -494,6 +1272,372 @@ pub struct Market {
     pub clob_rewards: Option<Vec<ClobReward>>,
 }
 
+impl fmt::Display for Market {
+    /// Formats the market as `#{id} {slug}`, falling back to its question without a slug and to
+    /// `#{id}` alone without either.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_labeled(
+            f,
+            &self.id,
+            self.slug.as_deref().or(self.question.as_deref()),
+        )
+    }
+}
+
+impl Market {
+    /// Returns `true` if Gamma features this market, e.g. to highlight it. A missing flag is
+    /// `false`.
+    #[must_use]
+    pub fn is_featured(&self) -> bool {
+        self.featured.unwrap_or(false)
+    }
+
+    /// Returns `true` if Gamma flags this market as geo-restricted. A missing flag is `false`.
+    #[must_use]
+    pub fn is_restricted(&self) -> bool {
+        self.restricted.unwrap_or(false)
+    }
+
+    /// Returns the top-level category of this market, derived from its tags with
+    /// [`TopLevelCategory::from_tags`].
+    #[must_use]
+    pub fn category(&self) -> TopLevelCategory {
+        TopLevelCategory::from_tags(self.tags.as_deref().unwrap_or_default())
+    }
+
+    /// Returns the URL of the image of this market, or `None` if it is blank or invalid. A
+    /// relative URL is resolved against `base`, e.g. the CDN serving the images, and is
+    /// `None` without one. Only `http` and `https` URLs are returned.
+    #[must_use]
+    pub fn image_url(&self, base: Option<&Url>) -> Option<Url> {
+        image_url(self.image.as_deref(), base)
+    }
+
+    /// Returns the URL of the icon of this market, like [`Market::image_url`].
+    #[must_use]
+    pub fn icon_url(&self, base: Option<&Url>) -> Option<Url> {
+        image_url(self.icon.as_deref(), base)
+    }
+
+    /// Returns `true` if `other` is the same market, i.e. has the same condition ID.
+    ///
+    /// Unlike `==`, which compares every field, this still holds between two snapshots of a
+    /// market taken before and after its prices moved. Markets without a condition ID are
+    /// compared by `id` instead.
+    #[must_use]
+    pub fn same_as(&self, other: &Self) -> bool {
+        match (&self.condition_id, &other.condition_id) {
+            (Some(condition_id), Some(other_condition_id)) => condition_id == other_condition_id,
+            _ => self.id == other.id,
+        }
+    }
+
+    /// Returns the first event this market belongs to, if any.
+    #[must_use]
+    pub fn first_event(&self) -> Option<&Event> {
+        self.events.as_deref()?.first()
+    }
+
+    /// Returns `true` if this market is tagged with the tag `id`. A missing tag list is treated
+    /// as empty.
+    #[must_use]
+    pub fn has_tag(&self, id: &str) -> bool {
+        self.tags.iter().flatten().any(|tag| tag.id == id)
+    }
+
+    /// Returns the slugs of the tags on this market, skipping tags without a slug.
+    pub fn tag_slugs(&self) -> impl Iterator<Item = &str> {
+        self.tags
+            .iter()
+            .flatten()
+            .filter_map(|tag| tag.slug.as_deref())
+    }
+
+    /// Returns the winning outcome of this market once it has resolved.
+    ///
+    /// A market counts as resolved when its UMA resolution status is `resolved`, or when it is
+    /// closed and its outcome prices settled at exactly `1` for one outcome and `0` for the rest.
+    /// Returns `None` for unresolved markets and for 50/50 resolutions, where no single outcome
+    /// won; use [`Market::is_split_resolution`] to tell the latter apart.
+    #[must_use]
+    pub fn resolution(&self) -> Option<Resolution> {
+        if !self.is_settled() {
+            return None;
+        }
+
+        let prices = self.parsed_outcome_prices()?;
+        if !prices
+            .iter()
+            .all(|price| price.is_zero() || *price == Decimal::ONE)
+        {
+            return None;
+        }
+        let mut winners = prices
+            .iter()
+            .enumerate()
+            .filter(|(_, price)| **price == Decimal::ONE);
+        let (winner, _) = winners.next()?;
+        if winners.next().is_some() {
+            return None;
+        }
+
+        let winning_outcome = parse_json_array(self.outcomes.as_deref())?
+            .into_iter()
+            .nth(winner)?;
+        let winning_token_id = parse_json_array(self.clob_token_ids.as_deref())
+            .and_then(|ids| ids.into_iter().nth(winner));
+
+        Some(Resolution {
+            winning_outcome,
+            winning_token_id,
+            resolved_at: self.closed_time.as_deref().and_then(parse_gamma_datetime),
+        })
+    }
+
+    /// Returns `true` if this market resolved 50/50, i.e. it settled with every outcome priced
+    /// equally (`0.5`/`0.5` for a binary market) instead of a single winner.
+    #[must_use]
+    pub fn is_split_resolution(&self) -> bool {
+        if !self.is_settled() {
+            return false;
+        }
+
+        self.parsed_outcome_prices().is_some_and(|prices| {
+            let count = Decimal::from(prices.len());
+            prices.len() > 1 && prices.iter().all(|price| *price * count == Decimal::ONE)
+        })
+    }
+
+    /// Returns the current price of each outcome, keyed by outcome name, from the positionally
+    /// aligned `outcomes` and `outcomePrices` lists. A market without either list has an empty
+    /// map.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the lists are malformed, i.e. they are not JSON lists, a
+    /// price is not a decimal, or the lists have different lengths.
+    pub fn outcome_prices(&self) -> Result<HashMap<String, Decimal>> {
+        let (Some(outcomes), Some(prices)) = (&self.outcomes, &self.outcome_prices) else {
+            return Ok(HashMap::new());
+        };
+
+        let malformed = |reason: String| {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(market = %self.id, %reason, "Malformed outcome prices");
+            Error::validation(format!("market {}: {reason}", self.id))
+        };
+
+        let outcomes = parse_json_array(Some(outcomes))
+            .ok_or_else(|| malformed(format!("`outcomes` is not a JSON list: {outcomes}")))?;
+        let prices = parse_json_array(Some(prices))
+            .ok_or_else(|| malformed(format!("`outcomePrices` is not a JSON list: {prices}")))?;
+        if outcomes.len() != prices.len() {
+            return Err(malformed(format!(
+                "{} outcomes but {} prices",
+                outcomes.len(),
+                prices.len()
+            )));
+        }
+
+        outcomes
+            .into_iter()
+            .zip(prices)
+            .map(|(outcome, price)| {
+                let price = price.parse().map_err(|e| {
+                    malformed(format!("invalid price {price:?} for {outcome:?}: {e}"))
+                })?;
+                Ok((outcome, price))
+            })
+            .collect()
+    }
+
+    /// Returns the sum of the outcome prices, which is `1` for a fair market. Above `1` the
+    /// prices carry an overround, below it an underround. A market without outcome prices sums to
+    /// `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the outcome lists are malformed, see
+    /// [`Market::outcome_prices`].
+    pub fn raw_sum(&self) -> Result<Decimal> {
+        Ok(self.outcome_prices()?.values().sum())
+    }
+
+    /// Returns the implied probability of each outcome, keyed by outcome name: its price divided
+    /// by [`Market::raw_sum`], so that the probabilities total `1` even when the prices of a
+    /// multi-outcome market do not. A market whose prices are all zero, or that has none, has an
+    /// empty map.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the outcome lists are malformed, see
+    /// [`Market::outcome_prices`].
+    pub fn normalized_probabilities(&self) -> Result<HashMap<String, Decimal>> {
+        let prices = self.outcome_prices()?;
+        let sum: Decimal = prices.values().sum();
+        if sum.is_zero() {
+            return Ok(HashMap::new());
+        }
+
+        Ok(prices
+            .into_iter()
+            .map(|(outcome, price)| (outcome, price / sum))
+            .collect())
+    }
+
+    /// Whether the market has stopped trading with final prices: resolved by UMA, or closed.
+    fn is_settled(&self) -> bool {
+        self.state() == MarketState::Resolved || self.closed.unwrap_or(false)
+    }
+
+    fn parsed_outcome_prices(&self) -> Option<Vec<Decimal>> {
+        parse_json_array(self.outcome_prices.as_deref())?
+            .iter()
+            .map(|price| price.parse().ok())
+            .collect()
+    }
+
+    /// Returns the lifecycle state of this market. A market is considered resolved once its UMA
+    /// resolution status is `resolved`, and missing flags are treated as `false`. See
+    /// [`MarketState`] for how conflicting flags are resolved.
+    #[must_use]
+    pub fn state(&self) -> MarketState {
+        let resolved = self.uma_resolution_status == Some(UmaResolutionStatus::Resolved);
+
+        MarketState::from_flags(
+            resolved,
+            self.closed.unwrap_or(false) || self.archived.unwrap_or(false),
+            self.active.unwrap_or(false),
+            self.accepting_orders.unwrap_or(false),
+            self.accepting_orders_timestamp.is_some(),
+        )
+    }
+
+    /// Compares this market, the older fetch, against `other`, the newer one.
+    #[must_use]
+    pub fn diff(&self, other: &Market) -> MarketDiff {
+        let market_tag_ids =
+            |market: &Market| sorted_ids(market.tags.iter().flatten().map(|t| &t.id));
+
+        MarketDiff {
+            question: change(&self.question, &other.question),
+            outcome_prices: change(
+                &self.parsed_outcome_prices(),
+                &other.parsed_outcome_prices(),
+            ),
+            last_trade_price: change(&self.last_trade_price, &other.last_trade_price),
+            best_bid: change(&self.best_bid, &other.best_bid),
+            best_ask: change(&self.best_ask, &other.best_ask),
+            spread: change(&self.spread, &other.spread),
+            volume: change(&self.volume_value(), &other.volume_value()),
+            volume_24hr: change(&self.volume_24hr, &other.volume_24hr),
+            liquidity: change(&self.liquidity_value(), &other.liquidity_value()),
+            active: change(&self.active, &other.active),
+            closed: change(&self.closed, &other.closed),
+            archived: change(&self.archived, &other.archived),
+            accepting_orders: change(&self.accepting_orders, &other.accepting_orders),
+            end_date: change(&self.end_date, &other.end_date),
+            state: change(&self.state(), &other.state()),
+            tag_ids: change(&market_tag_ids(self), &market_tag_ids(other)),
+        }
+    }
+
+    /// Returns the total volume, preferring the numeric field over the string one.
+    fn volume_value(&self) -> Option<Decimal> {
+        self.volume_num
+            .or_else(|| self.volume.as_deref()?.parse().ok())
+    }
+
+    /// Returns the liquidity, preferring the numeric field over the string one.
+    fn liquidity_value(&self) -> Option<Decimal> {
+        self.liquidity_num
+            .or_else(|| self.liquidity.as_deref()?.parse().ok())
+    }
+}
+
+/// The outcome a resolved [`Market`] settled on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Resolution {
+    /// Label of the winning outcome, e.g. `"Yes"`
+    pub winning_outcome: String,
+    /// CLOB token ID of the winning outcome, if the market lists its token IDs
+    pub winning_token_id: Option<String>,
+    /// When the market closed, if known
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+/// What the CLOB needs to trade a Gamma [`Market`]: its condition ID and the token ID of each
+/// outcome, converted with `ClobMarketRef::try_from(&market)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ClobMarketRef {
+    /// Condition ID of the market, e.g. for [`Client::market`](crate::clob::Client::market)
+    pub condition_id: String,
+    /// CLOB token ID of each outcome, in the order of `outcomes`
+    pub token_ids: Vec<String>,
+    /// Label of each outcome, e.g. `"Yes"`, or empty if the market does not list them
+    pub outcomes: Vec<String>,
+}
+
+impl ClobMarketRef {
+    /// Returns the token ID of `outcome`, matched case-insensitively, or `None` if the market
+    /// has no such outcome.
+    #[must_use]
+    pub fn token_id(&self, outcome: &str) -> Option<&str> {
+        let outcome = outcome.trim();
+        self.outcomes
+            .iter()
+            .position(|label| label.eq_ignore_ascii_case(outcome))
+            .and_then(|index| self.token_ids.get(index))
+            .map(String::as_str)
+    }
+}
+
+impl TryFrom<&Market> for ClobMarketRef {
+    type Error = Error;
+
+    /// Extracts the CLOB identifiers of `market`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the market has no condition ID or no CLOB token IDs, as
+    /// for markets that are not tradable on the CLOB, or if its `outcomes` do not line up with
+    /// its token IDs.
+    fn try_from(market: &Market) -> Result<Self> {
+        let invalid = |reason: &str| Error::validation(format!("market {}: {reason}", market.id));
+
+        let condition_id = market
+            .condition_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .ok_or_else(|| invalid("no condition ID"))?
+            .to_owned();
+        let token_ids = parse_json_array(market.clob_token_ids.as_deref())
+            .filter(|ids| !ids.is_empty())
+            .ok_or_else(|| invalid("no CLOB token IDs, it is not tradable on the CLOB"))?;
+        let outcomes = match market.outcomes.as_deref() {
+            None => Vec::new(),
+            Some(outcomes) => parse_json_array(Some(outcomes))
+                .ok_or_else(|| invalid("`outcomes` is not a JSON list"))?,
+        };
+        if !outcomes.is_empty() && outcomes.len() != token_ids.len() {
+            return Err(invalid(&format!(
+                "{} outcomes but {} CLOB token IDs",
+                outcomes.len(),
+                token_ids.len()
+            )));
+        }
+
+        Ok(Self {
+            condition_id,
+            token_ids,
+            outcomes,
+        })
+    }
+}
+
 /// CLOB rewards configuration for a market.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -509,6 +1653,7 @@ pub struct ClobReward {
 }
 
 /// A series of related events.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -519,24 +1664,33 @@ pub struct Series {
     pub title: Option<String>,
     pub subtitle: Option<String>,
     pub series_type: Option<String>,
-    pub recurrence: Option<String>,
+    pub recurrence: Option<Recurrence>,
     pub description: Option<String>,
     pub image: Option<String>,
     pub icon: Option<String>,
     pub layout: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub active: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub closed: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub archived: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub new: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub featured: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub restricted: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub is_template: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub template_variables: Option<bool>,
     pub published_at: Option<String>,
     pub created_by: Option<String>,
     pub updated_by: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub comments_enabled: Option<bool>,
     pub competitive: Option<String>,
     pub volume_24hr: Option<Decimal>,
@@ -553,6 +1707,7 @@ pub struct Series {
     pub tags: Option<Vec<Tag>>,
     pub comment_count: Option<i32>,
     pub chats: Option<Vec<Chat>>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub requires_translation: Option<bool>,
 }
 
@@ -566,15 +1721,19 @@ pub struct CommentPosition {
 }
 
 /// A comment profile.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct CommentProfile {
     pub name: Option<String>,
     pub pseudonym: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub display_username_public: Option<bool>,
     pub bio: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub is_mod: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub is_creator: Option<bool>,
     pub proxy_wallet: Option<String>,
     pub base_address: Option<String>,
@@ -591,7 +1750,7 @@ pub struct Reaction {
     pub id: String,
     #[serde(rename = "commentID")]
     pub comment_id: Option<i32>,
-    pub reaction_type: Option<String>,
+    pub reaction_type: Option<ReactionType>,
     pub icon: Option<String>,
     pub user_address: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
@@ -599,20 +1758,26 @@ pub struct Reaction {
 }
 
 /// A comment on an event, series, or market.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct Comment {
     pub id: String,
     pub body: Option<String>,
-    pub parent_entity_type: Option<String>,
+    pub parent_entity_type: Option<CommentParentType>,
     #[serde(rename = "parentEntityID")]
     pub parent_entity_id: Option<i32>,
     #[serde(rename = "parentCommentID")]
     pub parent_comment_id: Option<String>,
     pub user_address: Option<String>,
     pub reply_address: Option<String>,
+    /// Sent as an RFC 3339 date, or a Unix timestamp by some endpoints
+    #[serde(default)]
+    #[serde_as(as = "Option<TimestampFromAny>")]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    #[serde_as(as = "Option<TimestampFromAny>")]
     pub updated_at: Option<DateTime<Utc>>,
     pub profile: Option<CommentProfile>,
     pub reactions: Option<Vec<Reaction>>,
@@ -620,17 +1785,110 @@ pub struct Comment {
     pub reaction_count: Option<i32>,
 }
 
+impl Comment {
+    /// Returns the number of reactions of this comment per `reaction_type`. Reactions without a
+    /// type are not counted.
+    #[must_use]
+    pub fn reaction_counts(&self) -> HashMap<ReactionType, usize> {
+        let mut counts = HashMap::new();
+        for reaction_type in self
+            .reactions
+            .iter()
+            .flatten()
+            .filter_map(|reaction| reaction.reaction_type.clone())
+        {
+            *counts.entry(reaction_type).or_default() += 1;
+        }
+        counts
+    }
+}
+
+/// A comment together with its nested replies, built by [`CommentThread::build`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct CommentThread {
+    pub comment: Comment,
+    pub replies: Vec<CommentThread>,
+}
+
+impl CommentThread {
+    /// Builds the threads of a flat list of `comments`, nesting each comment under the comment
+    /// named by its `parent_comment_id`.
+    ///
+    /// Replies whose parent is not part of `comments`, e.g. because it is on another page, are
+    /// attached at the root, as are comments caught in a cycle of parents. Roots and replies keep
+    /// the order of `comments`.
+    #[must_use]
+    pub fn build(comments: Vec<Comment>) -> Vec<Self> {
+        let by_id: HashMap<String, usize> = comments
+            .iter()
+            .enumerate()
+            .map(|(index, comment)| (comment.id.clone(), index))
+            .collect();
+        let parents: Vec<Option<usize>> = comments
+            .iter()
+            .enumerate()
+            .map(|(index, comment)| {
+                let parent = *by_id.get(comment.parent_comment_id.as_deref()?)?;
+                (parent != index).then_some(parent)
+            })
+            .collect();
+
+        let mut children = vec![Vec::new(); comments.len()];
+        for (index, parent) in parents.iter().enumerate() {
+            if let Some(parent) = parent {
+                children[*parent].push(index);
+            }
+        }
+
+        let mut slots: Vec<Option<Comment>> = comments.into_iter().map(Some).collect();
+        let mut threads: Vec<Self> = (0..slots.len())
+            .filter(|&index| parents[index].is_none())
+            .filter_map(|index| Self::take(index, &mut slots, &children))
+            .collect();
+        // Whatever is left is only reachable through a cycle of parents
+        for index in 0..slots.len() {
+            threads.extend(Self::take(index, &mut slots, &children));
+        }
+
+        threads
+    }
+
+    /// Returns the number of replies in this thread, at any depth.
+    #[must_use]
+    pub fn reply_count(&self) -> usize {
+        self.replies
+            .iter()
+            .map(|reply| 1 + reply.reply_count())
+            .sum()
+    }
+
+    fn take(index: usize, slots: &mut [Option<Comment>], children: &[Vec<usize>]) -> Option<Self> {
+        let comment = slots[index].take()?;
+        let replies = children[index]
+            .iter()
+            .filter_map(|&child| Self::take(child, slots, children))
+            .collect();
+
+        Some(Self { comment, replies })
+    }
+}
+
 /// A user associated with a public profile.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct PublicProfileUser {
     pub id: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub creator: Option<bool>,
     #[serde(rename = "mod")]
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub is_mod: Option<bool>,
 }
 
 /// Public profile response.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -638,12 +1896,14 @@ pub struct PublicProfile {
     pub created_at: Option<DateTime<Utc>>,
     pub proxy_wallet: Option<String>,
     pub profile_image: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub display_username_public: Option<bool>,
     pub bio: Option<String>,
     pub pseudonym: Option<String>,
     pub name: Option<String>,
     pub users: Option<Vec<PublicProfileUser>>,
     pub x_username: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub verified_badge: Option<bool>,
 }
 
@@ -659,6 +1919,7 @@ pub struct SearchTag {
 }
 
 /// A profile in search results.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -676,14 +1937,18 @@ pub struct Profile {
     pub utm_campaign: Option<String>,
     pub utm_content: Option<String>,
     pub utm_term: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub wallet_activated: Option<bool>,
     pub pseudonym: Option<String>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub display_username_public: Option<bool>,
     pub profile_image: Option<String>,
     pub bio: Option<String>,
     pub proxy_wallet: Option<String>,
     pub profile_image_optimized: Option<ImageOptimization>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub is_close_only: Option<bool>,
+    #[serde_as(as = "Option<BoolFromAny>")]
     pub is_cert_req: Option<bool>,
     pub cert_req_date: Option<DateTime<Utc>>,
 }
@@ -697,3 +1962,147 @@ pub struct SearchResults {
     pub profiles: Option<Vec<Profile>>,
     pub pagination: Option<Pagination>,
 }
+
+impl SearchResults {
+    /// Returns the matching events by descending volume. Events without a volume come last.
+    #[must_use]
+    pub fn rank_by_volume(&self) -> Vec<&Event> {
+        let mut events: Vec<&Event> = self.events.iter().flatten().collect();
+        events.sort_by_key(|event| Reverse(event.volume));
+        events
+    }
+
+    /// Returns up to `n` markets of the matching events with the highest volume, in descending
+    /// order. Markets without a volume come last.
+    #[must_use]
+    pub fn top_markets(&self, n: usize) -> Vec<&Market> {
+        let mut markets: Vec<&Market> = self
+            .events
+            .iter()
+            .flatten()
+            .flat_map(|event| event.markets.iter().flatten())
+            .collect();
+        markets.sort_by_key(|market| Reverse(market.volume_value()));
+        markets.truncate(n);
+        markets
+    }
+}
+
+/// A changed value as `(old, new)`, or `None` if the value did not change.
+pub type Change<T> = Option<(T, T)>;
+
+/// The fields that changed between two fetches of a [`Market`], see [`Market::diff`].
+///
+/// Numeric fields are compared by value, so `"0.50"` and `"0.5"` are equal. Tags are compared by
+/// id regardless of order.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarketDiff {
+    pub question: Change<Option<String>>,
+    pub outcome_prices: Change<Option<Vec<Decimal>>>,
+    pub last_trade_price: Change<Option<Decimal>>,
+    pub best_bid: Change<Option<Decimal>>,
+    pub best_ask: Change<Option<Decimal>>,
+    pub spread: Change<Option<Decimal>>,
+    pub volume: Change<Option<Decimal>>,
+    pub volume_24hr: Change<Option<Decimal>>,
+    pub liquidity: Change<Option<Decimal>>,
+    pub active: Change<Option<bool>>,
+    pub closed: Change<Option<bool>>,
+    pub archived: Change<Option<bool>>,
+    pub accepting_orders: Change<Option<bool>>,
+    pub end_date: Change<Option<DateTime<Utc>>>,
+    pub state: Change<MarketState>,
+    /// Sorted tag ids
+    pub tag_ids: Change<Vec<String>>,
+}
+
+impl MarketDiff {
+    /// Returns `true` if none of the compared fields changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// The fields that changed between two fetches of an [`Event`], see [`Event::diff`].
+///
+/// Tags and markets are compared by id regardless of order.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventDiff {
+    pub title: Change<Option<String>>,
+    pub volume: Change<Option<Decimal>>,
+    pub volume_24hr: Change<Option<Decimal>>,
+    pub liquidity: Change<Option<Decimal>>,
+    pub open_interest: Change<Option<Decimal>>,
+    pub active: Change<Option<bool>>,
+    pub closed: Change<Option<bool>>,
+    pub archived: Change<Option<bool>>,
+    pub end_date: Change<Option<DateTime<Utc>>>,
+    /// Sorted tag ids
+    pub tag_ids: Change<Vec<String>>,
+    /// Sorted ids of the markets in the event
+    pub market_ids: Change<Vec<String>>,
+}
+
+impl EventDiff {
+    /// Returns `true` if none of the compared fields changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+fn change<T: PartialEq + Clone>(old: &T, new: &T) -> Change<T> {
+    (old != new).then(|| (old.clone(), new.clone()))
+}
+
+fn sorted_ids<'id, I: Iterator<Item = &'id String>>(ids: I) -> Vec<String> {
+    let mut ids: Vec<String> = ids.cloned().collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+/// Writes `#{id} {label}`, or `#{id}` if the label is missing or blank.
+fn fmt_labeled(f: &mut fmt::Formatter<'_>, id: &str, label: Option<&str>) -> fmt::Result {
+    match label.map(str::trim).filter(|label| !label.is_empty()) {
+        Some(label) => write!(f, "#{id} {label}"),
+        None => write!(f, "#{id}"),
+    }
+}
+
+/// Parses an image URL as sent by Gamma, see [`Market::image_url`].
+fn image_url(value: Option<&str>, base: Option<&Url>) -> Option<Url> {
+    let value = value?.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    let url = match Url::parse(value) {
+        Ok(url) => url,
+        Err(url::ParseError::RelativeUrlWithoutBase) => base?.join(value).ok()?,
+        Err(_) => return None,
+    };
+    matches!(url.scheme(), "http" | "https").then_some(url)
+}
+
+/// Parses the JSON-encoded string arrays Gamma uses for fields such as `outcomes`, e.g.
+/// `"[\"Yes\", \"No\"]"`.
+fn parse_json_array(value: Option<&str>) -> Option<Vec<String>> {
+    let value = value?;
+    serde_json::from_str(value).ok().or_else(|| {
+        // Some responses encode the list twice, as a JSON string holding the JSON list
+        let inner: String = serde_json::from_str(value).ok()?;
+        serde_json::from_str(&inner).ok()
+    })
+}
+
+/// Parses a Gamma timestamp, which is either RFC 3339 or of the form `2024-11-06 12:00:00+00`.
+fn parse_gamma_datetime(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%#z"))
+        .ok()
+        .map(|datetime| datetime.with_timezone(&Utc))
+}
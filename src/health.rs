@@ -0,0 +1,99 @@
+//! Readiness check across the Gamma and CLOB APIs.
+//!
+//! [`healthcheck`] pings both APIs concurrently, with [`gamma::Client::status`] and
+//! [`clob::Client::ok`], and reports how each of them responded:
+//!
+//! ```no_run
+//! use polymarket_client_sdk::health::{HealthStatus, healthcheck};
+//! use polymarket_client_sdk::{clob, gamma};
+//!
+//! # async fn example() {
+//! let report = healthcheck(&gamma::Client::default(), &clob::Client::default()).await;
+//!
+//! if report.status() != HealthStatus::Up {
+//!     eprintln!("not ready: {report:?}");
+//! }
+//! # }
+//! ```
+
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`HealthReport` and `HealthStatus` name the check they describe"
+)]
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use crate::auth::state::State;
+use crate::error::Error;
+use crate::{Result, clob, gamma};
+
+/// Overall readiness of the APIs in a [`HealthReport`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HealthStatus {
+    /// Every API responded
+    Up,
+    /// Some APIs responded and others failed
+    Degraded,
+    /// No API responded
+    Down,
+}
+
+/// How one API responded to its ping.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct ServiceHealth {
+    /// Time until the ping succeeded or failed
+    pub latency: Duration,
+    /// Why the ping failed, or `None` if the API is reachable
+    pub error: Option<Error>,
+}
+
+impl ServiceHealth {
+    /// Returns `true` if the API responded successfully.
+    #[must_use]
+    pub fn is_reachable(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Result of [`healthcheck`], with the response of each API.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct HealthReport {
+    pub gamma: ServiceHealth,
+    pub clob: ServiceHealth,
+}
+
+impl HealthReport {
+    /// Returns whether both APIs, one of them, or neither is reachable.
+    #[must_use]
+    pub fn status(&self) -> HealthStatus {
+        match (self.gamma.is_reachable(), self.clob.is_reachable()) {
+            (true, true) => HealthStatus::Up,
+            (false, false) => HealthStatus::Down,
+            _ => HealthStatus::Degraded,
+        }
+    }
+}
+
+/// Pings the Gamma and CLOB APIs concurrently and reports the reachability and latency of each.
+///
+/// A ping fails on a transport error or a non-success response, e.g. a `503` during
+/// maintenance. The check itself never fails, so that it can back a readiness probe directly.
+pub async fn healthcheck<S: State>(gamma: &gamma::Client, clob: &clob::Client<S>) -> HealthReport {
+    let (gamma, clob) = futures::join!(probe(gamma.status()), probe(clob.ok()));
+
+    HealthReport { gamma, clob }
+}
+
+async fn probe<T, Fut: Future<Output = Result<T>>>(ping: Fut) -> ServiceHealth {
+    let started = Instant::now();
+    let error = ping.await.err();
+
+    ServiceHealth {
+        latency: started.elapsed(),
+        error,
+    }
+}
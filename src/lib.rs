@@ -9,6 +9,9 @@ pub mod data;
 pub mod error;
 #[cfg(feature = "gamma")]
 pub mod gamma;
+#[cfg(feature = "gamma")]
+pub mod health;
+pub mod meta;
 #[cfg(feature = "rtds")]
 pub mod rtds;
 pub(crate) mod serde_helpers;
@@ -16,17 +19,16 @@ pub mod types;
 #[cfg(any(feature = "ws", feature = "rtds"))]
 pub mod ws;
 
-use std::fmt::Write as _;
-
 use alloy::primitives::ChainId;
 use alloy::primitives::{B256, b256, keccak256};
 use phf::phf_map;
-use reqwest::header::HeaderMap;
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderValue, LOCATION};
 use reqwest::{Request, StatusCode};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 
-use crate::error::Error;
+use crate::error::{Error, Status};
+use crate::meta::{RateLimitInfo, RequestOutcome};
 use crate::types::{Address, address};
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -39,9 +41,36 @@ pub const AMOY: ChainId = 80002;
 
 pub const PRIVATE_KEY_VAR: &str = "POLYMARKET_PRIVATE_KEY";
 
+/// `User-Agent` sent by the API clients unless overridden, e.g. `rs-clob-client/0.3.3`
+pub const DEFAULT_USER_AGENT: &str = concat!("rs-clob-client/", env!("CARGO_PKG_VERSION"));
+
 /// Timestamp in seconds since [`std::time::UNIX_EPOCH`]
 pub(crate) type Timestamp = i64;
 
+/// How HTTP clients handle redirect responses.
+///
+/// The Polymarket APIs do not redirect on purpose, so following a redirect usually means the
+/// endpoint has moved. With the default of [`RedirectPolicy::None`], a `3xx` response surfaces as
+/// a [`Kind::Redirect`](error::Kind::Redirect) error instead.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RedirectPolicy {
+    /// Never follow redirects
+    #[default]
+    None,
+    /// Follow up to the given number of redirects
+    Limited(usize),
+}
+
+impl RedirectPolicy {
+    pub(crate) fn to_reqwest(self) -> reqwest::redirect::Policy {
+        match self {
+            RedirectPolicy::None => reqwest::redirect::Policy::none(),
+            RedirectPolicy::Limited(max) => reqwest::redirect::Policy::limited(max),
+        }
+    }
+}
+
 static CONFIG: phf::Map<ChainId, ContractConfig> = phf_map! {
     137_u64 => ContractConfig {
         exchange: address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"),
@@ -191,7 +220,8 @@ pub trait ToQueryParams: Serialize {
     ///
     /// Returns an empty string if no parameters are set, otherwise returns
     /// a string starting with `?` followed by URL-encoded key-value pairs.
-    /// Also uses an optional cursor as a parameter, if provided.
+    /// Also uses an optional cursor as a parameter, if provided. The cursor is percent-encoded,
+    /// as base64 cursors can hold `+`, `/` and `=`.
     fn query_params(&self, next_cursor: Option<&str>) -> String {
         let mut params = serde_urlencoded::to_string(self)
             .inspect_err(|e| {
@@ -207,7 +237,8 @@ pub trait ToQueryParams: Serialize {
             if !params.is_empty() {
                 params.push('&');
             }
-            let _ = write!(params, "next_cursor={cursor}");
+            params.push_str("next_cursor=");
+            params.extend(url::form_urlencoded::byte_serialize(cursor.as_bytes()));
         }
 
         if params.is_empty() {
@@ -220,6 +251,92 @@ pub trait ToQueryParams: Serialize {
 
 impl<T: Serialize> ToQueryParams for T {}
 
+/// Returns the `User-Agent` header for a client, `user_agent` if set and [`DEFAULT_USER_AGENT`]
+/// otherwise.
+pub(crate) fn user_agent(user_agent: Option<String>) -> Result<HeaderValue> {
+    match user_agent {
+        Some(value) => {
+            HeaderValue::from_str(&value).map_err(|e| Error::invalid_input_with_source(value, e))
+        }
+        None => Ok(HeaderValue::from_static(DEFAULT_USER_AGENT)),
+    }
+}
+
+/// Parses a user-supplied client host. The host must be an absolute `http`, `https`, `ws` or
+/// `wss` URL with a non-empty host name, and the error names the offending value otherwise.
+pub(crate) fn parse_host(value: &str) -> Result<url::Url> {
+    let url = url::Url::parse(value).map_err(|e| match e {
+        url::ParseError::RelativeUrlWithoutBase => Error::invalid_host(
+            value,
+            format!("missing scheme, expected e.g. `https://{value}`"),
+        ),
+        e => Error::invalid_host_with_source(value, e),
+    })?;
+
+    if !matches!(url.scheme(), "http" | "https" | "ws" | "wss") {
+        return Err(Error::invalid_host(
+            value,
+            format!(
+                "unsupported scheme `{}`, expected one of http, https, ws or wss",
+                url.scheme()
+            ),
+        ));
+    }
+
+    if url.host_str().is_none_or(str::is_empty) {
+        return Err(Error::invalid_host(value, "missing host name"));
+    }
+
+    Ok(url)
+}
+
+/// Body of a successful response, read by [`request`].
+pub(crate) trait FromResponse: Sized {
+    /// Reads the body of `response`, returning `None` if the API answered with `null`.
+    async fn from_response(response: reqwest::Response) -> Result<Option<Self>>;
+}
+
+impl<T: DeserializeOwned> FromResponse for T {
+    async fn from_response(response: reqwest::Response) -> Result<Option<Self>> {
+        let json_value = response.json::<serde_json::Value>().await?;
+        serde_helpers::deserialize_with_warnings(json_value)
+    }
+}
+
+/// Response of an endpoint whose body carries nothing, e.g. an empty body or a plain-text `OK`.
+/// The body is read but not parsed, so that it does not fail as invalid JSON.
+pub(crate) struct NoContent;
+
+impl FromResponse for NoContent {
+    async fn from_response(response: reqwest::Response) -> Result<Option<Self>> {
+        response.bytes().await?;
+        Ok(Some(Self))
+    }
+}
+
+async fn request<Response: FromResponse>(
+    client: &reqwest::Client,
+    request: Request,
+    headers: Option<HeaderMap>,
+) -> Result<Response> {
+    request_with_meta(client, request, headers)
+        .await
+        .map(RequestOutcome::into_value)
+}
+
+/// Returns `true` if `headers` declare a JSON body, i.e. `application/json` or a `+json` type.
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .is_some_and(|mime| {
+            let mime = mime.trim();
+            mime.eq_ignore_ascii_case("application/json")
+                || mime.to_ascii_lowercase().ends_with("+json")
+        })
+}
+
 #[cfg_attr(
     feature = "tracing",
     tracing::instrument(
@@ -228,15 +345,16 @@ impl<T: Serialize> ToQueryParams for T {}
         fields(
             method = %request.method(),
             path = request.url().path(),
-            status_code
+            status_code,
+            request_id
         )
     )
 )]
-async fn request<Response: DeserializeOwned>(
+async fn request_with_meta<Response: FromResponse>(
     client: &reqwest::Client,
     mut request: Request,
     headers: Option<HeaderMap>,
-) -> Result<Response> {
+) -> Result<RequestOutcome<Response>> {
     let method = request.method().clone();
     let path = request.url().path().to_owned();
 
@@ -244,14 +362,44 @@ async fn request<Response: DeserializeOwned>(
         *request.headers_mut() = h;
     }
 
+    #[cfg(feature = "tracing")]
+    record_request_id(&mut request);
+
     let response = client.execute(request).await?;
     let status_code = response.status();
 
     #[cfg(feature = "tracing")]
     tracing::Span::current().record("status_code", status_code.as_u16());
 
+    // A `304 Not Modified` answers a conditional request rather than pointing elsewhere
+    if status_code.is_redirection() && status_code != StatusCode::NOT_MODIFIED {
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            status = %status_code,
+            method = %method,
+            path = %path,
+            location = ?location,
+            "API request redirected"
+        );
+
+        return Err(Error::redirect(status_code, method, path, location));
+    }
+
     if !status_code.is_success() {
+        let retry_after = meta::retry_after(response.headers());
+        let json_body = is_json(response.headers());
         let message = response.text().await.unwrap_or_default();
+        let body = if json_body {
+            serde_json::from_str(&message).ok()
+        } else {
+            None
+        };
 
         #[cfg(feature = "tracing")]
         tracing::warn!(
@@ -262,14 +410,27 @@ async fn request<Response: DeserializeOwned>(
             "API request failed"
         );
 
-        return Err(Error::status(status_code, method, path, message));
+        return Err(Status {
+            status_code,
+            method,
+            path,
+            message,
+            retry_after,
+            body,
+        }
+        .into());
     }
 
-    let json_value = response.json::<serde_json::Value>().await?;
-    let response_data: Option<Response> = serde_helpers::deserialize_with_warnings(json_value)?;
+    let rate_limit = RateLimitInfo::from_headers(response.headers());
+    let total_count = meta::total_count(response.headers());
+    let response_data = Response::from_response(response).await?;
 
     if let Some(response) = response_data {
-        Ok(response)
+        Ok(RequestOutcome {
+            value: response,
+            rate_limit,
+            total_count,
+        })
     } else {
         #[cfg(feature = "tracing")]
         tracing::warn!(method = %method, path = %path, "API resource not found");
@@ -282,10 +443,75 @@ async fn request<Response: DeserializeOwned>(
     }
 }
 
+/// Sets an `X-Request-Id` header, unless the caller already provided one, and records it on the
+/// current span so that client traces can be matched with server logs.
+#[cfg(feature = "tracing")]
+fn record_request_id(request: &mut Request) {
+    let headers = request.headers_mut();
+    if !headers.contains_key(meta::REQUEST_ID_HEADER) {
+        let id = uuid::Uuid::new_v4().to_string();
+        if let Ok(value) = reqwest::header::HeaderValue::from_str(&id) {
+            headers.insert(meta::REQUEST_ID_HEADER, value);
+        }
+    }
+
+    if let Some(id) = headers
+        .get(meta::REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+    {
+        tracing::Span::current().record("request_id", id);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_host_should_succeed() {
+        for host in [
+            "https://gamma-api.polymarket.com",
+            "http://localhost:8080",
+            "wss://ws-subscriptions-clob.polymarket.com",
+            "ws://127.0.0.1:1234/",
+        ] {
+            parse_host(host).unwrap();
+        }
+    }
+
+    #[test]
+    fn parse_host_missing_scheme_should_fail() {
+        let err = parse_host("gamma-api.polymarket.com").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Validation: invalid host `gamma-api.polymarket.com`: missing scheme, expected e.g. \
+             `https://gamma-api.polymarket.com`"
+        );
+    }
+
+    #[test]
+    fn parse_host_wrong_scheme_should_fail() {
+        let err = parse_host("ftp://gamma-api.polymarket.com").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation: invalid host `ftp://gamma-api.polymarket.com`: unsupported scheme `ftp`, \
+             expected one of http, https, ws or wss"
+        );
+
+        // Without `//`, the host name is parsed as the scheme
+        let err = parse_host("localhost:8080").unwrap_err();
+        assert!(
+            err.to_string().contains("unsupported scheme `localhost`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn parse_host_missing_host_should_fail() {
+        parse_host("https://").unwrap_err();
+    }
+
     #[test]
     fn config_contains_80002() {
         let cfg = contract_config(AMOY, false).expect("missing config");
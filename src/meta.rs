@@ -0,0 +1,210 @@
+//! Response metadata returned alongside deserialized API payloads.
+//!
+//! Methods suffixed with `_with_meta` return a [`RequestOutcome`] that carries, next to the usual
+//! value, information read from the response headers such as [`RateLimitInfo`]. This lets
+//! schedulers pace themselves before the server starts answering with `429 Too Many Requests`.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+/// Header carrying the request quota for the current window.
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+/// Header carrying the number of requests left in the current window.
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+/// Header carrying when the current window resets.
+pub const RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+/// Header correlating a request with server-side logs. With the `tracing` feature, every request
+/// carries one, generated unless the caller set it, and the id is recorded on the request span.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+/// Header carrying the total number of items of a paginated listing.
+pub const TOTAL_COUNT_HEADER: &str = "x-total-count";
+
+/// A deserialized response value together with metadata from the response headers.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestOutcome<T> {
+    /// The deserialized response body
+    pub value: T,
+    /// Rate-limit information, if the server sent any rate-limit headers
+    pub rate_limit: Option<RateLimitInfo>,
+    /// Total number of items of a paginated listing (`X-Total-Count`), if the server sent it
+    pub total_count: Option<u64>,
+}
+
+impl<T> RequestOutcome<T> {
+    /// Builds an outcome from a value and the headers of the response it was read from.
+    #[must_use]
+    pub fn new(value: T, headers: &HeaderMap) -> Self {
+        Self {
+            value,
+            rate_limit: RateLimitInfo::from_headers(headers),
+            total_count: total_count(headers),
+        }
+    }
+
+    /// Discards the metadata, returning the response value.
+    #[must_use]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Maps the response value, keeping the metadata.
+    #[must_use]
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> RequestOutcome<U> {
+        RequestOutcome {
+            value: f(self.value),
+            rate_limit: self.rate_limit,
+            total_count: self.total_count,
+        }
+    }
+}
+
+/// Rate-limit state reported by the server through `X-RateLimit-*` headers.
+///
+/// Each field is `None` when the corresponding header is missing or not an unsigned integer.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RateLimitInfo {
+    /// Total requests allowed in the current window (`X-RateLimit-Limit`)
+    pub limit: Option<u64>,
+    /// Requests left in the current window (`X-RateLimit-Remaining`)
+    pub remaining: Option<u64>,
+    /// When the current window resets, as sent by the server (`X-RateLimit-Reset`)
+    pub reset: Option<u64>,
+}
+
+impl RateLimitInfo {
+    /// Parses the known rate-limit headers.
+    ///
+    /// Returns `None` if none of them could be parsed.
+    #[must_use]
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let parse = |name: &str| {
+            let value = headers.get(name)?.to_str().ok()?;
+            value.trim().parse::<u64>().ok()
+        };
+
+        let info = Self {
+            limit: parse(RATE_LIMIT_LIMIT_HEADER),
+            remaining: parse(RATE_LIMIT_REMAINING_HEADER),
+            reset: parse(RATE_LIMIT_RESET_HEADER),
+        };
+
+        (info != Self::default()).then_some(info)
+    }
+
+    /// Returns `true` if the server reported that no requests are left in the current window.
+    #[must_use]
+    pub const fn is_exhausted(&self) -> bool {
+        matches!(self.remaining, Some(0))
+    }
+}
+
+/// Parses the `X-Total-Count` header of a paginated listing.
+#[must_use]
+pub fn total_count(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get(TOTAL_COUNT_HEADER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Parses the `Retry-After` header, given either as delay seconds or as an HTTP-date.
+///
+/// A date in the past yields a zero delay. Returns `None` if the header is missing or malformed.
+#[must_use]
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after(value, Utc::now())
+}
+
+fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn from_headers_should_succeed() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", HeaderValue::from_static("100"));
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("0"));
+        headers.insert(
+            "X-RateLimit-Reset",
+            HeaderValue::from_static(" 1700000000 "),
+        );
+
+        let info = RateLimitInfo::from_headers(&headers).unwrap();
+
+        assert_eq!(info.limit, Some(100));
+        assert_eq!(info.remaining, Some(0));
+        assert_eq!(info.reset, Some(1_700_000_000));
+        assert!(
+            info.is_exhausted(),
+            "no remaining requests should be exhausted"
+        );
+    }
+
+    #[test]
+    fn total_count_should_parse_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(TOTAL_COUNT_HEADER, HeaderValue::from_static(" 340 "));
+
+        assert_eq!(total_count(&headers), Some(340));
+        assert_eq!(total_count(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn from_headers_without_rate_limit_should_be_none() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("soon"));
+
+        assert_eq!(RateLimitInfo::from_headers(&headers), None);
+        assert_eq!(RateLimitInfo::from_headers(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn retry_after_should_parse_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static(" 120 "));
+
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn retry_after_should_parse_http_date() {
+        let now = DateTime::parse_from_rfc3339("2015-10-21T07:27:30Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+}
@@ -8,12 +8,13 @@ use super::types::request::Subscription;
 use super::types::response::{ChainlinkPrice, Comment, CommentType, CryptoPrice, RtdsMessage};
 use crate::Result;
 use crate::auth::state::{Authenticated, State, Unauthenticated};
-use crate::auth::{Credentials, Normal};
+use crate::auth::{Credentials, CurrentCredentials, Normal};
 use crate::error::Error;
 use crate::types::Address;
 use crate::ws::ConnectionManager;
 use crate::ws::config::Config;
 use crate::ws::connection::ConnectionState;
+use crate::ws::stats::WsStats;
 
 /// RTDS (Real-Time Data Socket) client for streaming Polymarket data.
 ///
@@ -103,7 +104,7 @@ impl Client<Unauthenticated> {
             inner: Arc::new(ClientInner {
                 state: Authenticated {
                     address,
-                    credentials,
+                    credentials: CurrentCredentials::new(credentials),
                     kind: Normal,
                 },
                 config: inner.config,
@@ -187,6 +188,16 @@ impl<S: State> Client<S> {
         self.inner.connection.state()
     }
 
+    /// Get a snapshot of the traffic on the connection, e.g. to check that a stream is healthy.
+    ///
+    /// Messages are counted per `topic`.
+    #[must_use]
+    pub fn stats(&self) -> WsStats {
+        let mut stats = self.inner.connection.stats();
+        stats.subscriptions = self.inner.subscriptions.subscription_count();
+        stats
+    }
+
     /// Get the number of active subscriptions.
     ///
     /// # Returns
@@ -209,7 +220,7 @@ impl Client<Authenticated<Normal>> {
         comment_type: Option<CommentType>,
     ) -> Result<impl Stream<Item = Result<Comment>>> {
         let subscription = Subscription::comments(comment_type)
-            .with_clob_auth(self.inner.state.credentials.clone());
+            .with_clob_auth(Credentials::clone(&self.inner.state.credentials.get()));
         let stream = self.inner.subscriptions.subscribe(subscription)?;
 
         Ok(stream.filter_map(|msg_result| async move {
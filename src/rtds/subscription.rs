@@ -3,7 +3,7 @@
     reason = "Subscription types deliberately include the module name for clarity"
 )]
 
-use std::sync::{Arc, PoisonError, RwLock};
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
 use std::time::Instant;
 
 use async_stream::try_stream;
@@ -17,7 +17,7 @@ use super::types::response::{RtdsMessage, parse_messages};
 use crate::Result;
 use crate::auth::Credentials;
 use crate::ws::ConnectionManager;
-use crate::ws::connection::ConnectionState;
+use crate::ws::connection::{AbortOnDrop, ConnectionState};
 
 #[non_exhaustive]
 #[derive(Clone)]
@@ -67,6 +67,8 @@ pub struct SubscriptionManager {
     active_subs: DashMap<String, SubscriptionInfo>,
     subscribed_topics: DashSet<TopicType>,
     last_auth: RwLock<Option<Credentials>>,
+    /// Aborts the reconnection handler along with the manager
+    reconnection_handler: OnceLock<AbortOnDrop>,
 }
 
 impl SubscriptionManager {
@@ -78,15 +80,19 @@ impl SubscriptionManager {
             active_subs: DashMap::new(),
             subscribed_topics: DashSet::new(),
             last_auth: RwLock::new(None),
+            reconnection_handler: OnceLock::new(),
         }
     }
 
     /// Start the reconnection handler that re-subscribes on connection recovery.
+    ///
+    /// The handler only holds a weak reference to the manager, so it does not keep the
+    /// connection alive, and it is aborted once the manager is dropped.
     pub fn start_reconnection_handler(self: &Arc<Self>) {
-        let this = Arc::clone(self);
+        let this = Arc::downgrade(self);
+        let mut state_rx = self.connection.state_receiver();
 
-        tokio::spawn(async move {
-            let mut state_rx = this.connection.state_receiver();
+        let handle = tokio::spawn(async move {
             let mut was_connected = state_rx.borrow().is_connected();
 
             loop {
@@ -101,6 +107,9 @@ impl SubscriptionManager {
                 match state {
                     ConnectionState::Connected { .. } => {
                         if was_connected {
+                            let Some(this) = this.upgrade() else {
+                                break;
+                            };
                             // Reconnect to subscriptions
                             #[cfg(feature = "tracing")]
                             tracing::debug!("RTDS reconnected, re-establishing subscriptions");
@@ -118,6 +127,8 @@ impl SubscriptionManager {
                 }
             }
         });
+
+        _ = self.reconnection_handler.set(AbortOnDrop::new(&handle));
     }
 
     /// Re-send subscription requests for all tracked topics.
@@ -230,7 +241,7 @@ impl SubscriptionManager {
         Ok(try_stream! {
             loop {
                 match rx.recv().await {
-                    Ok(msg) => {
+                    Ok(Ok(msg)) => {
                         // Filter messages by topic and type
                         let matches_topic = msg.topic == target_topic;
                         let matches_type = target_type == "*" || msg.msg_type == target_type;
@@ -239,6 +250,8 @@ impl SubscriptionManager {
                             yield msg;
                         }
                     }
+                    // Undecodable frames are already logged by the connection
+                    Ok(Err(_)) => {}
                     Err(RecvError::Lagged(n)) => {
                         #[cfg(feature = "tracing")]
                         tracing::warn!("RTDS subscription lagged, missed {n} messages");
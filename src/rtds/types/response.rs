@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::types::Decimal;
+use crate::ws::MessageType;
 
 /// Top-level RTDS message wrapper.
 ///
@@ -20,6 +21,13 @@ pub struct RtdsMessage {
     pub payload: Value,
 }
 
+/// Messages are grouped by `topic`, as the `type` alone (e.g. `update`) is shared across topics.
+impl MessageType for RtdsMessage {
+    fn message_type(&self) -> &str {
+        &self.topic
+    }
+}
+
 impl RtdsMessage {
     /// Try to extract the payload as a crypto price update.
     #[must_use]
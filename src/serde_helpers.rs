@@ -3,6 +3,7 @@
 //! When the `tracing` feature is enabled, this module also logs warnings for any
 //! unknown fields encountered during deserialization, helping detect API changes.
 
+use chrono::{DateTime, Utc};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 
@@ -72,6 +73,213 @@ impl serde_with::SerializeAs<String> for StringFromAny {
     }
 }
 
+/// Deserializes a boolean from any of the encodings used across Polymarket APIs: `true` and
+/// `false`, the strings `"true"` and `"false"` (in any case), and the integers `0` and `1`, also
+/// as strings.
+///
+/// Use with `#[serde(deserialize_with = "deserialize_flexible_bool")]` for `bool` fields, or see
+/// [`BoolFromAny`] for `Option<bool>`.
+pub fn deserialize_flexible_bool<'de, D>(deserializer: D) -> std::result::Result<bool, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use std::fmt;
+
+    use serde::de::{self, Unexpected, Visitor};
+
+    struct FlexibleBoolVisitor;
+
+    impl Visitor<'_> for FlexibleBoolVisitor {
+        type Value = bool;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a boolean, \"true\", \"false\", 0 or 1")
+        }
+
+        fn visit_bool<E>(self, v: bool) -> std::result::Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(v)
+        }
+
+        fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match v.trim() {
+                s if s.eq_ignore_ascii_case("true") || s == "1" => Ok(true),
+                s if s.eq_ignore_ascii_case("false") || s == "0" => Ok(false),
+                _ => Err(E::invalid_value(Unexpected::Str(v), &self)),
+            }
+        }
+
+        fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match v {
+                0 => Ok(false),
+                1 => Ok(true),
+                _ => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+            }
+        }
+
+        fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match v {
+                0 => Ok(false),
+                1 => Ok(true),
+                _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+            }
+        }
+    }
+
+    deserializer.deserialize_any(FlexibleBoolVisitor)
+}
+
+/// A `serde_as` type that deserializes booleans with [`deserialize_flexible_bool`].
+///
+/// Use with `#[serde_as(as = "BoolFromAny")]` for `bool` fields
+/// or `#[serde_as(as = "Option<BoolFromAny>")]` for `Option<bool>`. Values are serialized as
+/// plain booleans.
+pub struct BoolFromAny;
+
+impl<'de> serde_with::DeserializeAs<'de, bool> for BoolFromAny {
+    fn deserialize_as<D>(deserializer: D) -> std::result::Result<bool, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_flexible_bool(deserializer)
+    }
+}
+
+impl serde_with::SerializeAs<bool> for BoolFromAny {
+    fn serialize_as<S>(source: &bool, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_bool(*source)
+    }
+}
+
+/// Unix timestamps below this are in seconds. `10^11` seconds is in the year 5138, while
+/// `10^11` milliseconds is in March 1973, so any timestamp after 1973 falls in one unit's range.
+const SECONDS_BELOW: u64 = 100_000_000_000;
+/// Unix timestamps below this, and at least [`SECONDS_BELOW`], are in milliseconds.
+const MILLIS_BELOW: u64 = SECONDS_BELOW * 1_000;
+/// Unix timestamps below this, and at least [`MILLIS_BELOW`], are in microseconds. Larger ones
+/// are in nanoseconds.
+const MICROS_BELOW: u64 = MILLIS_BELOW * 1_000;
+
+/// Converts a Unix timestamp whose unit is inferred from its magnitude, see
+/// [`deserialize_smart_timestamp`].
+fn timestamp_from_unix(value: i64) -> Option<DateTime<Utc>> {
+    let magnitude = value.unsigned_abs();
+    if magnitude < SECONDS_BELOW {
+        DateTime::from_timestamp(value, 0)
+    } else if magnitude < MILLIS_BELOW {
+        DateTime::from_timestamp_millis(value)
+    } else if magnitude < MICROS_BELOW {
+        DateTime::from_timestamp_micros(value)
+    } else {
+        Some(DateTime::from_timestamp_nanos(value))
+    }
+}
+
+/// Deserializes a timestamp sent as a Unix timestamp in seconds, milliseconds, microseconds or
+/// nanoseconds, either as an integer or a string of digits, or as an RFC 3339 string.
+///
+/// The unit of a Unix timestamp is not sent along, so it is inferred from its magnitude: below
+/// `10^11` it is in seconds, below `10^14` in milliseconds, below `10^17` in microseconds and
+/// in nanoseconds above. This assumes timestamps between March 1973 and the year 5138, where
+/// the ranges do not overlap; e.g. a timestamp in milliseconds from 1970 is read as seconds.
+///
+/// Use with `#[serde(deserialize_with = "deserialize_smart_timestamp")]` for `DateTime<Utc>`
+/// fields, or see [`TimestampFromAny`] for `Option<DateTime<Utc>>`.
+pub fn deserialize_smart_timestamp<'de, D>(
+    deserializer: D,
+) -> std::result::Result<DateTime<Utc>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use std::fmt;
+
+    use serde::de::{self, Unexpected, Visitor};
+
+    struct SmartTimestampVisitor;
+
+    impl Visitor<'_> for SmartTimestampVisitor {
+        type Value = DateTime<Utc>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a Unix timestamp or an RFC 3339 date")
+        }
+
+        fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            let trimmed = v.trim();
+            if let Ok(value) = trimmed.parse::<i64>() {
+                return self.visit_i64(value);
+            }
+
+            DateTime::parse_from_rfc3339(trimmed)
+                .map(|date| date.with_timezone(&Utc))
+                .map_err(|_e| E::invalid_value(Unexpected::Str(v), &self))
+        }
+
+        fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            timestamp_from_unix(v).ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+        }
+
+        fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            let value =
+                i64::try_from(v).map_err(|_e| E::invalid_value(Unexpected::Unsigned(v), &self))?;
+            self.visit_i64(value)
+        }
+    }
+
+    deserializer.deserialize_any(SmartTimestampVisitor)
+}
+
+/// A `serde_as` type that deserializes timestamps with [`deserialize_smart_timestamp`].
+///
+/// Use with `#[serde_as(as = "TimestampFromAny")]` for `DateTime<Utc>` fields
+/// or `#[serde_as(as = "Option<TimestampFromAny>")]` for `Option<DateTime<Utc>>`. Values are
+/// serialized as RFC 3339 strings.
+pub struct TimestampFromAny;
+
+impl<'de> serde_with::DeserializeAs<'de, DateTime<Utc>> for TimestampFromAny {
+    fn deserialize_as<D>(deserializer: D) -> std::result::Result<DateTime<Utc>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_smart_timestamp(deserializer)
+    }
+}
+
+impl serde_with::SerializeAs<DateTime<Utc>> for TimestampFromAny {
+    fn serialize_as<S>(
+        source: &DateTime<Utc>,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(source, serializer)
+    }
+}
+
 /// Deserialize JSON with unknown field warnings.
 ///
 /// This function deserializes JSON to a target type while detecting and logging
@@ -148,6 +356,47 @@ pub fn deserialize_with_warnings<T: DeserializeOwned>(value: Value) -> crate::Re
     Ok(serde_json::from_value(value)?)
 }
 
+/// Deserializes `value` as an array of `T` one element at a time, skipping the elements that
+/// fail to deserialize instead of failing the whole array. Returns the elements that were
+/// deserialized and the number that were skipped.
+///
+/// A `value` that is not an array fails as it would for `Vec<T>`.
+#[cfg(feature = "gamma")]
+pub fn deserialize_lenient_array<T: DeserializeOwned>(
+    value: Value,
+) -> crate::Result<(Vec<T>, usize)> {
+    let Value::Array(elements) = value else {
+        return deserialize_with_warnings(value).map(|items| (items, 0));
+    };
+
+    let mut items = Vec::with_capacity(elements.len());
+    let mut skipped = 0;
+    for (index, element) in elements.into_iter().enumerate() {
+        match deserialize_with_warnings(element) {
+            Ok(item) => items.push(item),
+            Err(e) => {
+                warn_skipped::<T>(index, &e);
+                skipped += 1;
+            }
+        }
+    }
+
+    Ok((items, skipped))
+}
+
+#[cfg(all(feature = "gamma", feature = "tracing"))]
+fn warn_skipped<T>(index: usize, error: &crate::error::Error) {
+    tracing::warn!(
+        type_name = %std::any::type_name::<T>(),
+        index,
+        error = %error,
+        "skipped malformed array element"
+    );
+}
+
+#[cfg(all(feature = "gamma", not(feature = "tracing")))]
+fn warn_skipped<T>(_index: usize, _error: &crate::error::Error) {}
+
 /// Look up a value in a JSON structure by dot-separated path.
 ///
 /// Handles paths from `serde_ignored` which use:
@@ -197,6 +446,25 @@ mod tests {
 
     use super::*;
 
+    #[cfg(feature = "gamma")]
+    #[test]
+    fn lenient_array_should_skip_malformed_elements() {
+        let value = serde_json::json!([1, "two", 3, null]);
+
+        let (items, skipped): (Vec<u8>, usize) = deserialize_lenient_array(value).unwrap();
+
+        assert_eq!(items, [1, 3]);
+        assert_eq!(skipped, 2);
+    }
+
+    #[cfg(feature = "gamma")]
+    #[test]
+    fn lenient_array_should_reject_non_arrays() {
+        let value = serde_json::json!({ "data": [1] });
+
+        deserialize_lenient_array::<u8>(value).unwrap_err();
+    }
+
     #[derive(Debug, Deserialize, PartialEq)]
     struct TestStruct {
         known_field: String,
@@ -248,6 +516,24 @@ mod tests {
         assert_eq!(result, vec![1, 2, 3]);
     }
 
+    #[cfg(feature = "arbitrary-precision")]
+    #[test]
+    fn deserialize_decimal_number_keeps_precision() {
+        use std::collections::HashMap;
+
+        use rust_decimal_macros::dec;
+
+        use crate::types::Decimal;
+
+        // 17 significant digits do not survive a round trip through `f64`
+        let json: Value =
+            serde_json::from_str(r#"{"price": 0.12345678901234567}"#).expect("parsing failed");
+
+        let result: HashMap<String, Decimal> =
+            deserialize_with_warnings(json).expect("deserialization failed");
+        assert_eq!(result["price"], dec!(0.12345678901234567));
+    }
+
     #[derive(Debug, Deserialize, PartialEq)]
     struct NestedStruct {
         outer: String,
@@ -414,6 +700,167 @@ mod tests {
         assert_eq!(result.id, "");
     }
 
+    // ========== deserialize_flexible_bool tests ==========
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct FlexibleBool {
+        #[serde(deserialize_with = "deserialize_flexible_bool")]
+        flag: bool,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, serde::Serialize)]
+    struct OptionalBoolFromAny {
+        #[serde(with = "serde_with::As::<Option<BoolFromAny>>")]
+        flag: Option<bool>,
+    }
+
+    fn flexible_bool(value: &serde_json::Value) -> Result<bool, serde_json::Error> {
+        serde_json::from_value::<FlexibleBool>(serde_json::json!({ "flag": value }))
+            .map(|parsed| parsed.flag)
+    }
+
+    #[test]
+    fn flexible_bool_accepts_booleans() {
+        assert!(flexible_bool(&serde_json::json!(true)).expect("true should parse"));
+        assert!(!flexible_bool(&serde_json::json!(false)).expect("false should parse"));
+    }
+
+    #[test]
+    fn flexible_bool_accepts_strings() {
+        for (value, expected) in [
+            ("true", true),
+            ("false", false),
+            ("TRUE", true),
+            ("False", false),
+            ("1", true),
+            ("0", false),
+        ] {
+            let parsed = flexible_bool(&serde_json::json!(value)).expect("string should parse");
+            assert_eq!(parsed, expected, "{value:?} should parse as {expected}");
+        }
+    }
+
+    #[test]
+    fn flexible_bool_accepts_integers() {
+        assert!(flexible_bool(&serde_json::json!(1)).expect("1 should parse"));
+        assert!(!flexible_bool(&serde_json::json!(0)).expect("0 should parse"));
+    }
+
+    #[test]
+    fn flexible_bool_rejects_other_values() {
+        for value in [
+            serde_json::json!(2),
+            serde_json::json!(-1),
+            serde_json::json!("yes"),
+            serde_json::json!(""),
+            serde_json::json!(null),
+            serde_json::json!(0.5),
+        ] {
+            assert!(
+                flexible_bool(&value).is_err(),
+                "{value} should not parse as a bool"
+            );
+        }
+    }
+
+    #[test]
+    fn bool_from_any_option() {
+        for (value, expected) in [
+            (serde_json::json!("true"), Some(true)),
+            (serde_json::json!(0), Some(false)),
+            (serde_json::json!(null), None),
+        ] {
+            let parsed: OptionalBoolFromAny =
+                serde_json::from_value(serde_json::json!({ "flag": value }))
+                    .expect("deserialization failed");
+            assert_eq!(parsed.flag, expected);
+        }
+    }
+
+    #[test]
+    fn bool_from_any_serializes_as_bool() {
+        let obj = OptionalBoolFromAny { flag: Some(true) };
+        let json = serde_json::to_value(&obj).expect("serialization failed");
+        assert_eq!(json, serde_json::json!({ "flag": true }));
+    }
+
+    // ========== deserialize_smart_timestamp tests ==========
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct SmartTimestamp {
+        #[serde(deserialize_with = "deserialize_smart_timestamp")]
+        at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, serde::Serialize)]
+    struct OptionalTimestampFromAny {
+        #[serde(default, with = "serde_with::As::<Option<TimestampFromAny>>")]
+        at: Option<DateTime<Utc>>,
+    }
+
+    fn smart_timestamp(value: &serde_json::Value) -> Result<DateTime<Utc>, serde_json::Error> {
+        serde_json::from_value::<SmartTimestamp>(serde_json::json!({ "at": value }))
+            .map(|parsed| parsed.at)
+    }
+
+    #[test]
+    fn smart_timestamp_normalizes_every_unit() {
+        let expected = DateTime::from_timestamp(1_703_980_800, 0).expect("valid timestamp");
+
+        for value in [
+            serde_json::json!(1_703_980_800_i64),
+            serde_json::json!(1_703_980_800_000_i64),
+            serde_json::json!(1_703_980_800_000_000_i64),
+            serde_json::json!(1_703_980_800_000_000_000_i64),
+            serde_json::json!("1703980800"),
+            serde_json::json!("1703980800000"),
+            serde_json::json!(" 1703980800000000 "),
+            serde_json::json!("2023-12-31T00:00:00Z"),
+            serde_json::json!("2023-12-31T01:00:00+01:00"),
+        ] {
+            let parsed = smart_timestamp(&value).expect("timestamp should parse");
+            assert_eq!(parsed, expected, "{value} should normalize to {expected}");
+        }
+    }
+
+    #[test]
+    fn smart_timestamp_keeps_sub_second_precision() {
+        let parsed = smart_timestamp(&serde_json::json!(1_703_980_800_123_i64))
+            .expect("millis should parse");
+
+        assert_eq!(parsed.timestamp_millis(), 1_703_980_800_123);
+    }
+
+    #[test]
+    fn smart_timestamp_rejects_other_values() {
+        for value in [
+            serde_json::json!("yesterday"),
+            serde_json::json!(""),
+            serde_json::json!(true),
+            serde_json::json!(1.5),
+            serde_json::json!(u64::MAX),
+        ] {
+            assert!(
+                smart_timestamp(&value).is_err(),
+                "{value} should not parse as a timestamp"
+            );
+        }
+    }
+
+    #[test]
+    fn timestamp_from_any_option() {
+        let parsed: OptionalTimestampFromAny =
+            serde_json::from_value(serde_json::json!({ "at": null }))
+                .expect("deserialization failed");
+        assert_eq!(parsed.at, None);
+
+        let parsed: OptionalTimestampFromAny =
+            serde_json::from_value(serde_json::json!({ "at": 1_703_980_800_000_i64 }))
+                .expect("deserialization failed");
+        let json = serde_json::to_value(&parsed).expect("serialization failed");
+        assert_eq!(json, serde_json::json!({ "at": "2023-12-31T00:00:00Z" }));
+    }
+
     // ========== lookup_value tests ==========
 
     #[cfg(feature = "tracing")]
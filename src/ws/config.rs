@@ -23,6 +23,10 @@ pub struct Config {
     pub heartbeat_timeout: Duration,
     /// Reconnection strategy configuration
     pub reconnect: ReconnectConfig,
+    /// Maximum time without any incoming frame, including PONG replies and server pings,
+    /// before the connection is considered dead and reconnected. This catches half-open TCP
+    /// connections that never report an error. Defaults to `None`, which disables the check.
+    pub max_idle: Option<Duration>,
 }
 
 impl Default for Config {
@@ -31,6 +35,7 @@ impl Default for Config {
             heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL_DURATION,
             heartbeat_timeout: DEFAULT_HEARTBEAT_TIMEOUT_DURATION,
             reconnect: ReconnectConfig::default(),
+            max_idle: None,
         }
     }
 }
@@ -5,6 +5,7 @@
 
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::sync::Arc;
 use std::time::Instant;
 
 use backoff::backoff::Backoff as _;
@@ -13,11 +14,13 @@ use serde::Serialize;
 use serde::de::DeserializeOwned;
 use tokio::net::TcpStream;
 use tokio::sync::{broadcast, mpsc, watch};
+use tokio::task::{AbortHandle, JoinHandle};
 use tokio::time::{interval, sleep, timeout};
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungstenite::Message};
 
 use super::config::Config;
-use super::traits::MessageParser;
+use super::stats::{StatsRecorder, WsStats};
+use super::traits::{MessageParser, MessageType};
 use crate::auth::Credentials;
 use crate::clob::ws::WsError;
 use crate::error::Kind;
@@ -29,6 +32,27 @@ type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
 /// Broadcast channel capacity for incoming messages.
 const BROADCAST_CAPACITY: usize = 1024;
 
+/// A single item broadcast to subscribers.
+///
+/// Frames that fail to decode are delivered as `Err` with the rendered parse error, so
+/// subscribers can surface them without the connection being torn down.
+pub type Frame<M> = std::result::Result<M, Arc<str>>;
+
+/// Aborts a background task when dropped, so the task cannot outlive its owner.
+pub(crate) struct AbortOnDrop(AbortHandle);
+
+impl AbortOnDrop {
+    pub(crate) fn new<T>(handle: &JoinHandle<T>) -> Self {
+        Self(handle.abort_handle())
+    }
+}
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
 /// Connection state tracking.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -65,6 +89,10 @@ impl ConnectionState {
 /// - Heartbeat monitoring via PING/PONG
 /// - Broadcasting messages to multiple subscribers
 ///
+/// The connection runs in a background task that lives as long as any clone of the manager.
+/// Once the last clone is dropped, the task sends a close frame, closes the socket and exits,
+/// which in turn ends every stream fed by [`ConnectionManager::subscribe`].
+///
 /// # Type Parameters
 ///
 /// - `M`: Message type that implements [`DeserializeOwned`] among other "helper" types
@@ -82,8 +110,11 @@ impl ConnectionState {
 ///
 /// // Subscribe to messages
 /// let mut rx = connection.subscribe();
-/// while let Ok(msg) = rx.recv().await {
-///     println!("Received: {:?}", msg);
+/// while let Ok(frame) = rx.recv().await {
+///     match frame {
+///         Ok(msg) => println!("Received: {:?}", msg),
+///         Err(e) => eprintln!("Undecodable frame: {e}"),
+///     }
 /// }
 /// ```
 #[derive(Clone)]
@@ -99,14 +130,19 @@ where
     /// Sender channel for outgoing messages
     sender_tx: mpsc::UnboundedSender<String>,
     /// Broadcast sender for incoming messages
-    broadcast_tx: broadcast::Sender<M>,
+    broadcast_tx: broadcast::Sender<Frame<M>>,
+    /// Traffic counters updated by the connection task
+    stats: Arc<StatsRecorder>,
+    /// Never sent on; dropping the last clone tells the connection task to shut down
+    _shutdown: Arc<watch::Sender<()>>,
     /// Phantom data for unused type parameters
     _phantom: PhantomData<P>,
 }
 
+// Only the connection task needs `MessageType`, to group received messages in its stats
 impl<M, P> ConnectionManager<M, P>
 where
-    M: DeserializeOwned + Debug + Clone + Send + 'static,
+    M: DeserializeOwned + MessageType + Debug + Clone + Send + 'static,
     P: MessageParser<M>,
 {
     /// Create a new connection manager and start the connection loop.
@@ -118,12 +154,15 @@ where
         let (sender_tx, sender_rx) = mpsc::unbounded_channel();
         let (broadcast_tx, _) = broadcast::channel(BROADCAST_CAPACITY);
         let (state_tx, state_rx) = watch::channel(ConnectionState::Disconnected);
+        let (shutdown_tx, shutdown_rx) = watch::channel(());
+        let stats = Arc::new(StatsRecorder::default());
 
         // Spawn connection task
         let connection_config = config;
         let connection_endpoint = endpoint;
         let broadcast_tx_clone = broadcast_tx.clone();
         let state_tx_clone = state_tx.clone();
+        let stats_clone = Arc::clone(&stats);
 
         tokio::spawn(async move {
             Self::connection_loop(
@@ -133,6 +172,8 @@ where
                 broadcast_tx_clone,
                 parser,
                 state_tx_clone,
+                shutdown_rx,
+                stats_clone,
             )
             .await;
         });
@@ -142,18 +183,28 @@ where
             state_rx,
             sender_tx,
             broadcast_tx,
+            stats,
+            _shutdown: Arc::new(shutdown_tx),
             _phantom: PhantomData,
         })
     }
 
     /// Main connection loop with automatic reconnection.
+    ///
+    /// Runs until reconnection gives up or every clone of the manager is dropped.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "the loop owns every channel end of the connection task"
+    )]
     async fn connection_loop(
         endpoint: String,
         config: Config,
         mut sender_rx: mpsc::UnboundedReceiver<String>,
-        broadcast_tx: broadcast::Sender<M>,
+        broadcast_tx: broadcast::Sender<Frame<M>>,
         parser: P,
         state_tx: watch::Sender<ConnectionState>,
+        mut shutdown_rx: watch::Receiver<()>,
+        stats: Arc<StatsRecorder>,
     ) {
         let mut attempt = 0_u32;
         let mut backoff: backoff::ExponentialBackoff = config.reconnect.clone().into();
@@ -164,10 +215,16 @@ where
             _ = state_tx.send(ConnectionState::Connecting);
 
             // Attempt connection
-            match connect_async(&endpoint).await {
+            let connected = tokio::select! {
+                connected = connect_async(&endpoint) => connected,
+                () = shutdown_requested(&mut shutdown_rx) => break,
+            };
+
+            match connected {
                 Ok((ws_stream, _)) => {
                     attempt = 0;
                     backoff.reset();
+                    stats.record_connection();
                     _ = state_tx.send(ConnectionState::Connected {
                         since: Instant::now(),
                     });
@@ -180,6 +237,8 @@ where
                         state_rx,
                         config.clone(),
                         &parser,
+                        &mut shutdown_rx,
+                        &stats,
                     )
                     .await
                     {
@@ -199,11 +258,14 @@ where
                 }
             }
 
+            if is_shut_down(&shutdown_rx) {
+                break;
+            }
+
             // Check if we should stop reconnecting
             if let Some(max) = config.reconnect.max_attempts
                 && attempt >= max
             {
-                _ = state_tx.send(ConnectionState::Disconnected);
                 break;
             }
 
@@ -211,34 +273,60 @@ where
             _ = state_tx.send(ConnectionState::Reconnecting { attempt });
 
             if let Some(duration) = backoff.next_backoff() {
-                sleep(duration).await;
+                tokio::select! {
+                    () = sleep(duration) => {}
+                    () = shutdown_requested(&mut shutdown_rx) => break,
+                }
             }
         }
+
+        _ = state_tx.send(ConnectionState::Disconnected);
     }
 
     /// Handle an active WebSocket connection.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "the connection borrows every channel end of the connection task"
+    )]
     async fn handle_connection(
         ws_stream: WsStream,
         sender_rx: &mut mpsc::UnboundedReceiver<String>,
-        broadcast_tx: &broadcast::Sender<M>,
+        broadcast_tx: &broadcast::Sender<Frame<M>>,
         state_rx: watch::Receiver<ConnectionState>,
         config: Config,
         parser: &P,
+        shutdown_rx: &mut watch::Receiver<()>,
+        stats: &StatsRecorder,
     ) -> Result<()> {
         let (mut write, mut read) = ws_stream.split();
 
+        // Watchdog that fires once no frame has arrived for `max_idle`
+        let max_idle = config.max_idle;
+        let idle = sleep(max_idle.unwrap_or_default());
+        tokio::pin!(idle);
+
         // Channel to notify heartbeat loop when PONG is received
         let (pong_tx, pong_rx) = watch::channel(Instant::now());
         let (ping_tx, mut ping_rx) = mpsc::unbounded_channel();
 
-        let heartbeat_handle = tokio::spawn(async move {
+        let _heartbeat = AbortOnDrop::new(&tokio::spawn(async move {
             Self::heartbeat_loop(ping_tx, state_rx, &config, pong_rx).await;
-        });
+        }));
 
         loop {
             tokio::select! {
                 // Handle incoming messages
                 Some(msg) = read.next() => {
+                    if let Some(max_idle) = max_idle {
+                        idle.as_mut().reset(tokio::time::Instant::now() + max_idle);
+                    }
+
+                    if let Ok(Message::Text(text)) = &msg {
+                        stats.record_frame(text.len());
+                    } else if let Ok(Message::Binary(bytes)) = &msg {
+                        stats.record_frame(bytes.len());
+                    }
+
                     match msg {
                         Ok(Message::Text(text)) if text == "PONG" => {
                             _ = pong_tx.send(Instant::now());
@@ -253,26 +341,27 @@ where
                                     for message in messages {
                                         #[cfg(feature = "tracing")]
                                         tracing::trace!(?message, "Parsed WebSocket message");
-                                        _ = broadcast_tx.send(message);
+                                        stats.record_message(message.message_type());
+                                        _ = broadcast_tx.send(Ok(message));
                                     }
                                 }
                                 Err(e) => {
                                     #[cfg(feature = "tracing")]
                                     tracing::warn!(%text, error = %e, "Failed to parse WebSocket message");
                                     #[cfg(not(feature = "tracing"))]
-                                    let _ = (&text, &e);
+                                    let _ = &text;
+                                    stats.record_parse_error();
+                                    _ = broadcast_tx.send(Err(Arc::from(e.to_string())));
                                 }
                             }
                         }
                         Ok(Message::Close(_)) => {
-                            heartbeat_handle.abort();
                             return Err(Error::with_source(
                                 Kind::WebSocket,
                                 WsError::ConnectionClosed,
                             ))
                         }
                         Err(e) => {
-                            heartbeat_handle.abort();
                             return Err(Error::with_source(
                                 Kind::WebSocket,
                                 WsError::Connection(e),
@@ -298,6 +387,19 @@ where
                     }
                 }
 
+                // Treat a connection without any frame for too long as dead
+                () = &mut idle, if max_idle.is_some() => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(?max_idle, "No WebSocket frame received, reconnecting");
+                    return Err(Error::with_source(Kind::WebSocket, WsError::Timeout));
+                }
+
+                // Close the socket once every manager is dropped
+                () = shutdown_requested(shutdown_rx) => {
+                    _ = write.send(Message::Close(None)).await;
+                    break;
+                }
+
                 // Check if connection is still active
                 else => {
                     break;
@@ -305,9 +407,6 @@ where
             }
         }
 
-        // Cleanup
-        heartbeat_handle.abort();
-
         Ok(())
     }
 
@@ -369,7 +468,13 @@ where
             }
         }
     }
+}
 
+impl<M, P> ConnectionManager<M, P>
+where
+    M: DeserializeOwned + Debug + Clone + Send + 'static,
+    P: MessageParser<M>,
+{
     /// Send a subscription request to the WebSocket server.
     pub fn send<R: Serialize>(&self, request: &R) -> Result<()> {
         let json = serde_json::to_string(request)?;
@@ -398,12 +503,22 @@ where
         *self.state_rx.borrow()
     }
 
+    /// Get a snapshot of the traffic on this connection.
+    ///
+    /// The `subscriptions` of the snapshot are left at `0`, as subscriptions are tracked by the
+    /// subscription managers on top of the connection.
+    #[must_use]
+    pub fn stats(&self) -> WsStats {
+        self.stats.snapshot()
+    }
+
     /// Subscribe to incoming messages.
     ///
     /// Each call returns a new independent receiver. Multiple subscribers can
-    /// receive messages concurrently without blocking each other.
+    /// receive messages concurrently without blocking each other. Frames that could
+    /// not be parsed are delivered as `Err` items.
     #[must_use]
-    pub fn subscribe(&self) -> broadcast::Receiver<M> {
+    pub fn subscribe(&self) -> broadcast::Receiver<Frame<M>> {
         self.broadcast_tx.subscribe()
     }
 
@@ -416,3 +531,13 @@ where
         self.state_tx.subscribe()
     }
 }
+
+/// Completes once every [`ConnectionManager`] holding the sender of `shutdown_rx` is dropped.
+async fn shutdown_requested(shutdown_rx: &mut watch::Receiver<()>) {
+    while shutdown_rx.changed().await.is_ok() {}
+}
+
+/// Returns `true` if every [`ConnectionManager`] holding the sender of `shutdown_rx` is dropped.
+fn is_shut_down(shutdown_rx: &watch::Receiver<()>) -> bool {
+    shutdown_rx.has_changed().is_err()
+}
@@ -7,6 +7,7 @@
 //!
 //! - [`ConnectionManager`]: Generic WebSocket connection handler with heartbeat and reconnection
 //! - [`MessageParser`]: Trait for parsing incoming WebSocket messages
+//! - [`stats::WsStats`]: Snapshot of the traffic on a connection
 //!
 //! # Example
 //!
@@ -21,6 +22,7 @@
 
 pub mod config;
 pub mod connection;
+pub mod stats;
 pub mod traits;
 
 pub use connection::ConnectionManager;
@@ -0,0 +1,125 @@
+//! Connection-level statistics for WebSocket channels.
+
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`WsStats` names the WebSocket traffic it describes"
+)]
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::Instant;
+
+/// Snapshot of the traffic on a WebSocket connection since it was first opened.
+///
+/// The counters cover every connection of a channel, so they keep growing across reconnects.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WsStats {
+    /// Number of messages received per message type, e.g. per `event_type` on the CLOB channels
+    pub messages_by_type: HashMap<String, u64>,
+    /// Number of text frames that could not be parsed into messages
+    pub parse_errors: u64,
+    /// Number of payload bytes received in text and binary frames, including heartbeat replies
+    pub bytes_received: u64,
+    /// Number of times the connection was re-established after the first connection
+    pub reconnects: u64,
+    /// Number of active subscriptions on the connection
+    pub subscriptions: usize,
+    /// When the last message was received, or `None` if none has been received yet
+    pub last_message_at: Option<Instant>,
+}
+
+impl WsStats {
+    /// Returns the total number of messages received across all message types.
+    #[must_use]
+    pub fn messages(&self) -> u64 {
+        self.messages_by_type.values().sum()
+    }
+}
+
+/// Counters behind [`WsStats`], updated by the connection task.
+#[derive(Debug, Default)]
+pub(crate) struct StatsRecorder {
+    bytes_received: AtomicU64,
+    connections: AtomicU64,
+    parse_errors: AtomicU64,
+    messages: Mutex<MessageCounts>,
+}
+
+#[derive(Debug, Default)]
+struct MessageCounts {
+    by_type: HashMap<String, u64>,
+    last_at: Option<Instant>,
+}
+
+impl StatsRecorder {
+    pub(crate) fn record_connection(&self) {
+        self.connections.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_frame(&self, bytes: usize) {
+        let bytes = u64::try_from(bytes).unwrap_or(u64::MAX);
+        self.bytes_received.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_parse_error(&self) {
+        self.parse_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_message(&self, message_type: &str) {
+        let mut messages = self.lock();
+        *messages.by_type.entry(message_type.to_owned()).or_default() += 1;
+        messages.last_at = Some(Instant::now());
+    }
+
+    /// Returns a snapshot of the counters, with `subscriptions` left at `0` for the caller to fill.
+    pub(crate) fn snapshot(&self) -> WsStats {
+        let messages = self.lock();
+        WsStats {
+            messages_by_type: messages.by_type.clone(),
+            parse_errors: self.parse_errors.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            reconnects: self.connections.load(Ordering::Relaxed).saturating_sub(1),
+            subscriptions: 0,
+            last_message_at: messages.last_at,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MessageCounts> {
+        // The counts are plain integers, so they are still consistent after a panic
+        self.messages.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_should_reflect_recorded_traffic() {
+        let recorder = StatsRecorder::default();
+        assert_eq!(recorder.snapshot(), WsStats::default());
+
+        recorder.record_connection();
+        recorder.record_frame(10);
+        recorder.record_message("book");
+        recorder.record_frame(5);
+        recorder.record_message("book");
+        recorder.record_message("price_change");
+        recorder.record_parse_error();
+        recorder.record_connection();
+
+        let stats = recorder.snapshot();
+        assert_eq!(stats.messages(), 3);
+        assert_eq!(stats.messages_by_type.get("book"), Some(&2));
+        assert_eq!(stats.messages_by_type.get("price_change"), Some(&1));
+        assert_eq!(stats.parse_errors, 1);
+        assert_eq!(stats.bytes_received, 15);
+        assert_eq!(stats.reconnects, 1);
+        assert!(
+            stats.last_message_at.is_some(),
+            "the last message time should be set"
+        );
+    }
+}
@@ -33,6 +33,12 @@ pub trait MessageParser<M: DeserializeOwned>: Send + Sync + 'static {
     fn parse(&self, bytes: &[u8]) -> crate::Result<Vec<M>>;
 }
 
+/// Message type used to group received messages in [`WsStats`](super::stats::WsStats).
+pub trait MessageType {
+    /// The type of this message, e.g. its `event_type` on the wire.
+    fn message_type(&self) -> &str;
+}
+
 pub trait WithCredentials: Serialize + Sized {
     fn as_authenticated(&self, credentials: &Credentials) -> Result<String, serde_json::Error> {
         let mut payload_json = serde_json::to_value(self)?;
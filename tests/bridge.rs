@@ -239,7 +239,12 @@ mod supported_assets {
 }
 
 mod client {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::RedirectPolicy;
     use polymarket_client_sdk::bridge::Client;
+    use polymarket_client_sdk::error::Kind;
+    use reqwest::StatusCode;
+    use serde_json::json;
 
     #[test]
     fn default_client_should_have_correct_host() {
@@ -259,4 +264,53 @@ mod client {
         let result = Client::new("not a valid url");
         result.unwrap_err();
     }
+
+    #[tokio::test]
+    async fn redirect_should_fail_by_default() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/supported-assets");
+            then.status(StatusCode::TEMPORARY_REDIRECT)
+                .header("location", "/v2/supported-assets");
+        });
+        let moved = server.mock(|when, then| {
+            when.method(GET).path("/v2/supported-assets");
+            then.status(StatusCode::OK)
+                .json_body(json!({"supportedAssets": []}));
+        });
+
+        let err = client.supported_assets().await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Redirect);
+        mock.assert();
+        moved.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn limited_redirect_policy_should_follow() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::with_redirect(&server.base_url(), RedirectPolicy::Limited(1))?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/supported-assets");
+            then.status(StatusCode::TEMPORARY_REDIRECT)
+                .header("location", "/v2/supported-assets");
+        });
+        let moved = server.mock(|when, then| {
+            when.method(GET).path("/v2/supported-assets");
+            then.status(StatusCode::OK)
+                .json_body(json!({"supportedAssets": []}));
+        });
+
+        let response = client.supported_assets().await?;
+
+        assert!(response.supported_assets.is_empty());
+        moved.assert();
+
+        Ok(())
+    }
 }
@@ -13,7 +13,7 @@ use chrono::{DateTime, Utc};
 use httpmock::MockServer;
 use polymarket_client_sdk::POLYGON;
 use polymarket_client_sdk::clob::types::SignatureType;
-use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::clob::{CircuitBreakerConfig, CircuitState, Client, Config};
 use polymarket_client_sdk::types::Decimal;
 use reqwest::StatusCode;
 use rust_decimal_macros::dec;
@@ -27,22 +27,27 @@ use crate::common::{
 
 mod unauthenticated {
 
+    use std::io::{BufRead as _, BufReader, Write as _};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
     use chrono::{TimeDelta, TimeZone as _};
     use futures_util::future;
     use futures_util::stream::StreamExt as _;
     use polymarket_client_sdk::clob::types::request::{
-        LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, PriceHistoryRequest,
-        PriceRequest, SpreadRequest,
+        LastTradePriceRequest, MarketsRequest, MidpointRequest, OrderBookSummaryRequest,
+        PriceHistoryRequest, PriceRequest, SamplingFilter, SpreadRequest,
     };
     use polymarket_client_sdk::clob::types::response::{
         FeeRateResponse, GeoblockResponse, LastTradePriceResponse, LastTradesPricesResponse,
         MarketResponse, MidpointResponse, MidpointsResponse, NegRiskResponse,
         OrderBookSummaryResponse, OrderSummary, Page, PriceHistoryResponse, PricePoint,
-        PriceResponse, PricesResponse, Rewards, SimplifiedMarketResponse, SpreadResponse,
-        SpreadsResponse, TickSizeResponse, Token,
+        PriceResponse, PricesResponse, PublicTrade, Rewards, SimplifiedMarketResponse,
+        SpreadResponse, SpreadsResponse, TickSizeResponse, Token,
     };
-    use polymarket_client_sdk::clob::types::{Interval, Side, TickSize, TimeRange};
-    use polymarket_client_sdk::error::Status;
+    use polymarket_client_sdk::clob::types::{Interval, MarketState, Side, TickSize, TimeRange};
+    use polymarket_client_sdk::error::{Kind, Status};
     use reqwest::Method;
 
     use super::*;
@@ -168,7 +173,7 @@ mod unauthenticated {
 
         assert_eq!(
             status_err.to_string(),
-            r#"error(404 Not Found) making GET call to /price with {"message":"Request did not match any route or mock"}"#
+            r#"GET /price: {"message":"Request did not match any route or mock"} (404 Not Found)"#
         );
         assert_eq!(status_err.status_code, StatusCode::NOT_FOUND);
         assert_eq!(status_err.method, Method::GET);
@@ -209,6 +214,292 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn concurrent_prices_should_coalesce_into_one_batch() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .coalesce_window(std::time::Duration::from_millis(50))
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let prices: serde_json::Map<_, _> = (0..10)
+            .map(|i| (i.to_string(), json!({ "BUY": format!("0.{i}5") })))
+            .collect();
+        let batch = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/prices");
+            then.status(StatusCode::OK)
+                .json_body(serde_json::Value::Object(prices));
+        });
+        let single = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/price");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "price": "0" }));
+        });
+
+        let requests: Vec<_> = (0..10)
+            .map(|i| {
+                PriceRequest::builder()
+                    .token_id(i.to_string())
+                    .side(Side::Buy)
+                    .build()
+            })
+            .collect();
+        let responses = future::try_join_all(requests.iter().map(|r| client.price(r))).await?;
+
+        for (i, response) in responses.iter().enumerate() {
+            assert_eq!(response.price, Decimal::from_str(&format!("0.{i}5"))?);
+        }
+        batch.assert_calls(1);
+        single.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_coalesced_batch_should_give_every_caller_its_status() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .coalesce_window(std::time::Duration::from_millis(50))
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let batch = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/prices");
+            then.status(StatusCode::SERVICE_UNAVAILABLE).body("down");
+        });
+
+        let requests: Vec<_> = (0..3)
+            .map(|i| {
+                PriceRequest::builder()
+                    .token_id(i.to_string())
+                    .side(Side::Buy)
+                    .build()
+            })
+            .collect();
+        let results = future::join_all(requests.iter().map(|r| client.price(r))).await;
+
+        for result in results {
+            let err = result.unwrap_err();
+            let status = err.downcast_ref::<Status>().unwrap();
+            assert_eq!(status.status_code, StatusCode::SERVICE_UNAVAILABLE);
+            assert_eq!(status.message, "down");
+        }
+        batch.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn coalesced_midpoint_missing_from_batch_should_be_requested_alone() -> anyhow::Result<()>
+    {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .coalesce_window(std::time::Duration::from_millis(50))
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let batch = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/midpoints");
+            then.status(StatusCode::OK).json_body(json!({ "1": "0.5" }));
+        });
+        let single = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/midpoint")
+                .query_param("token_id", "2");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "mid": "0.25" }));
+        });
+
+        let first = MidpointRequest::builder().token_id("1").build();
+        let second = MidpointRequest::builder().token_id("2").build();
+        let (first, second) =
+            future::try_join(client.midpoint(&first), client.midpoint(&second)).await?;
+
+        assert_eq!(first.mid, dec!(0.5));
+        assert_eq!(second.mid, dec!(0.25));
+        batch.assert_calls(1);
+        single.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn default_user_agent_should_carry_version() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        assert!(
+            polymarket_client_sdk::DEFAULT_USER_AGENT.ends_with(env!("CARGO_PKG_VERSION")),
+            "the default user agent should end with the crate version"
+        );
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("user-agent", polymarket_client_sdk::DEFAULT_USER_AGENT);
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        client.ok().await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn user_agent_should_be_configurable() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().user_agent("my-bot/1.0").build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("user-agent", "my-bot/1.0");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        client.ok().await?;
+        mock.assert();
+
+        let config = Config::builder().user_agent("bad\nagent").build();
+        let err = Client::new(&server.base_url(), config).unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn builder_should_apply_options() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::builder()
+            .user_agent("my-bot/1.0")
+            .redirect(polymarket_client_sdk::RedirectPolicy::Limited(1))
+            .build_client(&server.base_url())?;
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::FOUND).header("location", "/v2/");
+        });
+        let moved = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/v2/")
+                .header("user-agent", "my-bot/1.0");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        client.ok().await?;
+        moved.assert();
+
+        let err = Client::builder().build_client("not a url").unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn with_headers_should_merge_over_request_headers() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/midpoints")
+                .header("content-type", "application/json")
+                .header("x-correlation-id", "call-1");
+            then.status(StatusCode::OK).json_body(json!({ "1": "0.5" }));
+        });
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            "X-Correlation-Id",
+            reqwest::header::HeaderValue::from_static("call-1"),
+        );
+        let request = MidpointRequest::builder().token_id("1").build();
+        client.with_headers(headers).midpoints(&[request]).await?;
+
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resolve_override_should_pin_host_to_address() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let host = format!("clob.pinned.test:{}", server.port());
+        let config = Config::builder()
+            .resolve_override(("clob.pinned.test".to_owned(), *server.address()))
+            .build();
+        let client = Client::new(&format!("http://{host}"), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("host", host.as_str());
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        client.ok().await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_resolve_override_should_fail() {
+        let overrides = [
+            ("clob.polymarket.com/path", "127.0.0.1:443"),
+            ("", "127.0.0.1:443"),
+            ("clob.polymarket.com", "0.0.0.0:443"),
+        ];
+
+        for (domain, addr) in overrides {
+            let config = Config::builder()
+                .resolve_override((domain.to_owned(), addr.parse().unwrap()))
+                .build();
+            let err = Client::new("https://clob.polymarket.com", config).unwrap_err();
+
+            assert_eq!(err.kind(), Kind::Validation, "{domain} -> {addr}");
+        }
+    }
+
+    #[tokio::test]
+    async fn prices_with_large_batch_should_stream_body() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().batch_stream_threshold(10).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let token_ids: Vec<String> = (0..5_000).map(|i| i.to_string()).collect();
+        let expected_body: Vec<_> = token_ids
+            .iter()
+            .map(|id| json!({ "token_id": id, "side": "SELL" }))
+            .collect();
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/prices")
+                .header("content-type", "application/json")
+                .json_body(json!(expected_body));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "0": { "SELL": 0.5 } }));
+        });
+
+        let requests: Vec<_> = token_ids
+            .iter()
+            .map(|id| {
+                PriceRequest::builder()
+                    .token_id(id.as_str())
+                    .side(Side::Sell)
+                    .build()
+            })
+            .collect();
+        let response = client.prices(&requests).await?;
+
+        assert_eq!(response.prices.unwrap()["0"][&Side::Sell], dec!(0.5));
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn all_prices_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -315,6 +606,119 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn midpoint_history_should_follow_price_history() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/prices-history")
+                .query_param("market", "0xtoken")
+                .query_param("interval", "1d")
+                .query_param("fidelity", "60");
+            then.status(StatusCode::OK).json_body(json!({
+                "history": [
+                    { "t": 3000, "p": "0.62" },
+                    { "t": 1000, "p": "0.5" },
+                    { "t": 2000, "p": "0.55" },
+                    { "t": 2000, "p": "0.57" }
+                ]
+            }));
+        });
+
+        let request = PriceHistoryRequest::builder()
+            .market("ignored")
+            .time_range(Interval::OneDay)
+            .fidelity(60)
+            .build();
+        let history = client.midpoint_history("0xtoken", &request).await?;
+
+        let point = |t, p| PricePoint::builder().t(t).p(p).build();
+        assert_eq!(
+            history,
+            [
+                point(1000, dec!(0.5)),
+                point(2000, dec!(0.57)),
+                point(3000, dec!(0.62)),
+            ]
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn price_history_multi_should_return_every_token() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mocks = [
+            (
+                "0x1",
+                json!([{ "t": 1000, "p": "0.5" }, { "t": 2000, "p": "0.6" }]),
+            ),
+            ("0x2", json!([{ "t": 1500, "p": "0.3" }])),
+        ]
+        .map(|(market, history)| {
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET)
+                    .path("/prices-history")
+                    .query_param("market", market)
+                    .query_param("interval", "1d");
+                then.status(StatusCode::OK)
+                    .json_body(json!({ "history": history }));
+            })
+        });
+
+        let request = PriceHistoryRequest::builder()
+            .market("ignored")
+            .time_range(Interval::OneDay)
+            .build();
+        let point = |t, p| PricePoint::builder().t(t).p(p).build();
+
+        let histories = client
+            .price_history_multi(&["0x1", "0x2"], &request, 2, false)
+            .await?;
+
+        let mut keys: Vec<_> = histories.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["0x1", "0x2"]);
+        assert_eq!(
+            histories["0x1"],
+            [point(1000, dec!(0.5)), point(2000, dec!(0.6))]
+        );
+        assert_eq!(histories["0x2"], [point(1500, dec!(0.3))]);
+
+        let aligned = client
+            .price_history_multi(&["0x1", "0x2"], &request, 1, true)
+            .await?;
+
+        assert_eq!(
+            aligned["0x1"],
+            [
+                point(1000, dec!(0.5)),
+                point(1500, dec!(0.5)),
+                point(2000, dec!(0.6)),
+            ]
+        );
+        assert_eq!(
+            aligned["0x2"],
+            [point(1500, dec!(0.3)), point(2000, dec!(0.3))]
+        );
+        for mock in mocks {
+            mock.assert_calls(2);
+        }
+
+        let err = client
+            .price_history_multi(&["0x1"], &request, 0, false)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn spread_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -597,33 +1001,849 @@ mod unauthenticated {
     }
 
     #[tokio::test]
-    async fn last_trades_prices_should_succeed() -> anyhow::Result<()> {
+    async fn market_trades_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url(), Config::default())?;
 
         let mock = server.mock(|when, then| {
             when.method(httpmock::Method::GET)
-                .path("/last-trades-prices")
-                .json_body(json!([{ "token_id": "1" }]));
-            then.status(StatusCode::OK)
-                .json_body(json!([{ "token_id": "1", "price": 0.12, "side": "BUY" }]));
+                .path("/live-activity/events/0xabc")
+                .query_param("limit", "2");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "event_type": "trade",
+                    "market": { "condition_id": "0xabc", "asset_id": "1" },
+                    "side": "BUY",
+                    "size": "25",
+                    "price": "0.52",
+                    "outcome": "Yes",
+                    "transaction_hash": "0xhash1",
+                    "timestamp": "1705322096"
+                },
+                {
+                    "event_type": "trade",
+                    "market": { "condition_id": "0xabc", "asset_id": "2" },
+                    "side": "SELL",
+                    "size": "10.5",
+                    "price": "0.47",
+                    "outcome": "No",
+                    "timestamp": 1_705_322_000
+                }
+            ]));
+        });
+
+        let response = client.market_trades("0xabc", Some(2)).await?;
+
+        let expected = vec![
+            PublicTrade::builder()
+                .price(dec!(0.52))
+                .size(dec!(25))
+                .side(Side::Buy)
+                .outcome("Yes")
+                .transaction_hash("0xhash1")
+                .timestamp(Utc.timestamp_opt(1_705_322_096, 0).unwrap())
+                .build(),
+            PublicTrade::builder()
+                .price(dec!(0.47))
+                .size(dec!(10.5))
+                .side(Side::Sell)
+                .outcome("No")
+                .timestamp(Utc.timestamp_opt(1_705_322_000, 0).unwrap())
+                .build(),
+        ];
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_trades_without_limit_should_not_send_one() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/live-activity/events/0xabc")
+                .query_param_missing("limit");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let response = client.market_trades("0xabc", None).await?;
+
+        assert!(response.is_empty(), "no trades should be returned");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn last_trade_price_with_size_and_timestamp_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/last-trade-price")
+                .query_param("token_id", "1");
+            then.status(StatusCode::OK).json_body(json!({
+                "price": "0.45",
+                "side": "SELL",
+                "size": "120",
+                "timestamp": 1_700_000_000
+            }));
+        });
+
+        let request = LastTradePriceRequest::builder()
+            .token_id("1".to_owned())
+            .build();
+        let response = client.last_trade_price(&request).await?;
+
+        let expected = LastTradePriceResponse::builder()
+            .price(dec!(0.45))
+            .side(Side::Sell)
+            .size(dec!(120))
+            .timestamp(DateTime::from_timestamp(1_700_000_000, 0).unwrap())
+            .build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn last_trades_prices_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/last-trades-prices")
+                .json_body(json!([{ "token_id": "1" }, { "token_id": "2" }]));
+            then.status(StatusCode::OK).json_body(json!([
+                { "token_id": "1", "price": 0.12, "side": "BUY" },
+                { "token_id": "2", "price": 0.88, "side": "SELL", "size": "15.5", "timestamp": "1700000000" }
+            ]));
+        });
+
+        let requests = [
+            LastTradePriceRequest::builder()
+                .token_id("1".to_owned())
+                .build(),
+            LastTradePriceRequest::builder()
+                .token_id("2".to_owned())
+                .build(),
+        ];
+        let response = client.last_trades_prices(&requests).await?;
+
+        let expected = vec![
+            LastTradesPricesResponse::builder()
+                .token_id("1".to_owned())
+                .price(dec!(0.12))
+                .side(Side::Buy)
+                .build(),
+            LastTradesPricesResponse::builder()
+                .token_id("2".to_owned())
+                .price(dec!(0.88))
+                .side(Side::Sell)
+                .size(dec!(15.5))
+                .timestamp(DateTime::from_timestamp(1_700_000_000, 0).unwrap())
+                .build(),
+        ];
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn request_id_should_be_generated_and_recorded() -> anyhow::Result<()> {
+        use std::io;
+        use std::sync::{Arc, Mutex};
+
+        use tracing_subscriber::fmt::format::FmtSpan;
+        use tracing_subscriber::util::SubscriberInitExt as _;
+
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<Vec<u8>>>);
+
+        impl io::Write for Capture {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let _guard = tracing_subscriber::fmt()
+            .with_max_level(tracing_subscriber::filter::LevelFilter::DEBUG)
+            .with_span_events(FmtSpan::CLOSE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish()
+            .set_default();
+
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/").header_matches(
+                "^x-request-id$",
+                "^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[0-9a-f]{4}-[0-9a-f]{12}$",
+            );
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        client.ok().await?;
+        mock.assert();
+
+        let logs = String::from_utf8(capture.0.lock().unwrap().clone())?;
+        let id = logs
+            .split("request_id=\"")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap_or_default();
+        assert_eq!(id.len(), 36, "span should record the request id: {logs}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn warm_up_should_make_one_request() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let warm_up = server.mock(|when, then| {
+            when.method(httpmock::Method::HEAD).path("/");
+            then.status(StatusCode::METHOD_NOT_ALLOWED);
+        });
+        let ok = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        client.warm_up().await?;
+        warm_up.assert_calls(1);
+        ok.assert_calls(0);
+
+        assert_eq!(client.ok().await?, "OK");
+        warm_up.assert_calls(1);
+        ok.assert_calls(1);
+
+        Ok(())
+    }
+
+    /// Starts a keep-alive HTTP/1.1 server that answers `HEAD` with `405` and `GET` with `"OK"`,
+    /// returning its URL and the number of connections it has accepted.
+    fn connection_counting_server() -> anyhow::Result<(String, Arc<AtomicUsize>)> {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        let accepted = Arc::clone(&connections);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { return };
+                accepted.fetch_add(1, Ordering::SeqCst);
+                thread::spawn(move || serve_keep_alive(stream));
+            }
+        });
+
+        Ok((url, connections))
+    }
+
+    fn serve_keep_alive(stream: std::net::TcpStream) {
+        let Ok(mut writer) = stream.try_clone() else {
+            return;
+        };
+        let mut reader = BufReader::new(stream);
+
+        loop {
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+                return;
+            }
+            loop {
+                let mut header = String::new();
+                if reader.read_line(&mut header).unwrap_or(0) == 0 {
+                    return;
+                }
+                if header == "\r\n" {
+                    break;
+                }
+            }
+
+            let response = if request_line.starts_with("HEAD") {
+                "HTTP/1.1 405 Method Not Allowed\r\ncontent-length: 0\r\n\r\n"
+            } else {
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 4\r\n\r\n\"OK\""
+            };
+            if writer.write_all(response.as_bytes()).is_err() {
+                return;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn warm_up_connection_should_be_reused() -> anyhow::Result<()> {
+        let (url, connections) = connection_counting_server()?;
+        let client = Client::new(&url, Config::default())?;
+
+        client.warm_up().await?;
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+        assert_eq!(client.ok().await?, "OK");
+        assert_eq!(
+            connections.load(Ordering::SeqCst),
+            1,
+            "the request should reuse the warmed-up connection"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn warm_up_should_fail_without_server() -> anyhow::Result<()> {
+        let client = Client::new("http://127.0.0.1:1", Config::default())?;
+
+        client.warm_up().await.unwrap_err();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rate_limited_should_carry_retry_after_seconds() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::TOO_MANY_REQUESTS)
+                .header("retry-after", "7")
+                .body("slow down");
+        });
+
+        let err = client.ok().await.unwrap_err();
+
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.status_code, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(status.retry_after, Some(std::time::Duration::from_secs(7)));
+        assert_eq!(err.retry_after(), status.retry_after);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rate_limited_should_carry_retry_after_date() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+        let retry_at = Utc::now() + TimeDelta::seconds(60);
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::TOO_MANY_REQUESTS).header(
+                "retry-after",
+                retry_at.format("%a, %d %b %Y %H:%M:%S GMT").to_string(),
+            );
+        });
+
+        let err = client.ok().await.unwrap_err();
+
+        let retry_after = err.retry_after().unwrap();
+        assert!(
+            retry_after > std::time::Duration::from_secs(50)
+                && retry_after <= std::time::Duration::from_secs(60),
+            "delay should count down to the date, got {retry_after:?}"
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn status_error_without_retry_after_should_be_none() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::TOO_MANY_REQUESTS);
+        });
+
+        let err = client.ok().await.unwrap_err();
+
+        assert_eq!(err.retry_after(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rate_limited_host_should_pause_concurrent_requests() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().backpressure(true).build();
+        let client = Client::new(&server.base_url(), config)?;
+        let clone = client.clone();
+
+        let limited = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::TOO_MANY_REQUESTS)
+                .header("retry-after", "1");
+        });
+        let time = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/time");
+            then.status(StatusCode::OK).json_body(json!(1_700_000_000));
+        });
+
+        let started = std::time::Instant::now();
+        let (err, waited) = tokio::join!(async { client.ok().await.unwrap_err() }, async {
+            // Sent once the first request has been rate limited
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            clone.server_time().await?;
+            anyhow::Ok(started.elapsed())
+        });
+
+        assert_eq!(err.retry_after(), Some(std::time::Duration::from_secs(1)));
+        let waited = waited?;
+        assert!(
+            waited >= std::time::Duration::from_secs(1),
+            "request to the rate-limited host should wait for the retry-after, took {waited:?}"
+        );
+        limited.assert_calls(1);
+        time.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker_should_trip_and_recover() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let cooldown = std::time::Duration::from_millis(200);
+        let config = Config::builder()
+            .circuit_breaker(CircuitBreakerConfig::new(2, cooldown))
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+        let clone = client.clone();
+
+        let mut failing = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::SERVICE_UNAVAILABLE);
+        });
+
+        for _ in 0..2 {
+            let err = client.ok().await.unwrap_err();
+            assert_eq!(err.kind(), Kind::Status);
+        }
+        assert_eq!(client.circuit_state(), CircuitState::Open);
+
+        let err = clone.ok().await.unwrap_err();
+        assert_eq!(err.kind(), Kind::CircuitOpen);
+        assert!(
+            err.retry_after()
+                .is_some_and(|retry_in| retry_in <= cooldown),
+            "short-circuited request should report the remaining cooldown"
+        );
+        failing.assert_calls(2);
+
+        tokio::time::sleep(cooldown).await;
+        assert_eq!(client.circuit_state(), CircuitState::HalfOpen);
+
+        failing.delete();
+        let healthy = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK).json_body(json!("OK"));
+        });
+
+        client.ok().await?;
+        assert_eq!(clone.circuit_state(), CircuitState::Closed);
+        clone.ok().await?;
+        healthy.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker_should_ignore_client_errors() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .circuit_breaker(CircuitBreakerConfig::new(
+                1,
+                std::time::Duration::from_secs(60),
+            ))
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::BAD_REQUEST);
+        });
+
+        for _ in 0..3 {
+            let err = client.ok().await.unwrap_err();
+            assert_eq!(err.kind(), Kind::Status);
+        }
+        assert_eq!(client.circuit_state(), CircuitState::Closed);
+        mock.assert_calls(3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker_should_ignore_malformed_bodies() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .circuit_breaker(CircuitBreakerConfig::new(
+                1,
+                std::time::Duration::from_secs(60),
+            ))
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK).body("not json");
+        });
+
+        for _ in 0..3 {
+            let err = client.ok().await.unwrap_err();
+            assert_eq!(err.kind(), Kind::Internal);
+        }
+        assert_eq!(client.circuit_state(), CircuitState::Closed);
+        mock.assert_calls(3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker_should_trip_on_connection_failures() -> anyhow::Result<()> {
+        let config = Config::builder()
+            .circuit_breaker(CircuitBreakerConfig::new(
+                1,
+                std::time::Duration::from_secs(60),
+            ))
+            .build();
+        // Nothing listens on the discard port, so every connection is refused
+        let client = Client::new("http://127.0.0.1:9", config)?;
+
+        let err = client.ok().await.unwrap_err();
+        assert_eq!(err.kind(), Kind::Internal);
+        assert_eq!(client.circuit_state(), CircuitState::Open);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn latency_stats_should_track_requests_per_endpoint() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().latency_window(10).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK)
+                .json_body(json!("OK"))
+                .delay(std::time::Duration::from_millis(20));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/time");
+            then.status(StatusCode::SERVICE_UNAVAILABLE);
+        });
+
+        client.ok().await?;
+        client.clone().ok().await?;
+        client.server_time().await.unwrap_err();
+
+        let stats = client.latency_stats("/").unwrap();
+        assert_eq!(stats.count, 2);
+        assert!(
+            stats.p50 >= std::time::Duration::from_millis(20) && stats.p50 <= stats.max,
+            "the delay should be measured, got {stats:?}"
+        );
+        assert_eq!(client.latency_stats("/time").unwrap().count, 1);
+        assert_eq!(client.latency_stats("/book"), None);
+        mock.assert_calls(2);
+
+        let untracked = Client::new(&server.base_url(), Config::default())?;
+        untracked.ok().await?;
+        assert_eq!(untracked.latency_stats("/"), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn custom_retry_classifier_should_retry_client_errors() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .should_retry(|err, attempt| {
+                attempt < 3
+                    && err
+                        .downcast_ref::<Status>()
+                        .is_some_and(|status| status.status_code == StatusCode::BAD_REQUEST)
+            })
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::BAD_REQUEST);
+        });
+
+        let err = client.ok().await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+        mock.assert_calls(3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn custom_retry_classifier_should_skip_unmatched_errors() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .should_retry(|err, _| {
+                err.downcast_ref::<Status>()
+                    .is_some_and(|status| status.status_code == StatusCode::BAD_REQUEST)
+            })
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::NOT_FOUND);
+        });
+
+        let err = client.ok().await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn json_error_body_should_be_parsed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::BAD_REQUEST)
+                .header("content-type", "application/json; charset=utf-8")
+                .body(r#"{"error":"invalid token id"}"#);
+        });
+
+        let err = client.ok().await.unwrap_err();
+
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.body, Some(json!({ "error": "invalid token id" })));
+        assert_eq!(status.error_message(), "invalid token id");
+        assert_eq!(status.message, r#"{"error":"invalid token id"}"#);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn plain_text_error_body_should_be_kept_raw() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::BAD_REQUEST)
+                .header("content-type", "text/plain")
+                .body(r#"{"error":"looks like JSON"}"#);
+        });
+
+        let err = client.ok().await.unwrap_err();
+
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.body, None);
+        assert_eq!(status.error_message(), r#"{"error":"looks like JSON"}"#);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_with_filter_should_send_filters_and_cursor() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/markets")
+                .query_param("active", "true")
+                .query_param("closed", "false")
+                .query_param("next_cursor", "MTAw")
+                .is_true(|req| req.query_params().len() == 3);
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [],
+                "next_cursor": "LTE=",
+                "limit": 0,
+                "count": 0
+            }));
+        });
+
+        let request = MarketsRequest::builder().active(true).closed(false).build();
+        let page = client
+            .markets_with_filter(&request, Some("MTAw".into()))
+            .await?;
+
+        assert!(page.data.is_empty(), "the page should be empty");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_by_condition_ids_should_keep_order_and_errors() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+        let known = format!("0x{}", "ab".repeat(32));
+        let unknown = format!("0x{}", "cd".repeat(32));
+
+        let known_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/markets/{known}"));
+            then.status(StatusCode::OK).json_body(json!({
+                "enable_order_book": true,
+                "active": true,
+                "closed": false,
+                "archived": false,
+                "accepting_orders": true,
+                "minimum_order_size": "1",
+                "minimum_tick_size": "0.01",
+                "condition_id": known,
+                "question_id": "q_1",
+                "question": "Will it rain?",
+                "description": "",
+                "market_slug": "will-it-rain",
+                "seconds_delay": 0,
+                "fpmm": "",
+                "maker_base_fee": "0",
+                "taker_base_fee": "0",
+                "notifications_enabled": false,
+                "neg_risk": false,
+                "neg_risk_market_id": "",
+                "neg_risk_request_id": "",
+                "icon": "",
+                "image": "",
+                "rewards": {
+                    "rates": null,
+                    "min_size": "0",
+                    "max_spread": "0"
+                },
+                "is_50_50_outcome": false,
+                "tokens": [],
+                "tags": []
+            }));
+        });
+        let unknown_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/markets/{unknown}"));
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "market not found" }));
+        });
+
+        let results = client
+            .markets_by_condition_ids(&[&unknown, &known, &unknown], 2)
+            .await?;
+
+        assert_eq!(results.len(), 3);
+        let status = results[0].as_ref().unwrap_err().downcast_ref::<Status>();
+        assert_eq!(status.map(|s| s.status_code), Some(StatusCode::NOT_FOUND));
+        assert_eq!(results[1].as_ref().unwrap().condition_id, known);
+        assert!(results[2].is_err(), "the unknown market should fail again");
+        known_mock.assert();
+        unknown_mock.assert_calls(2);
+
+        let err = client
+            .markets_by_condition_ids(&[known.as_str(), "0x123"], 2)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+        known_mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn token_index_should_cache_lookups() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let market_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/markets/0xcond");
+            then.status(StatusCode::OK).json_body(json!({
+                "enable_order_book": true,
+                "active": true,
+                "closed": false,
+                "archived": false,
+                "accepting_orders": true,
+                "minimum_order_size": "1",
+                "minimum_tick_size": "0.01",
+                "condition_id": "0xcond",
+                "question_id": "q_1",
+                "question": "Will it rain?",
+                "description": "",
+                "market_slug": "will-it-rain",
+                "seconds_delay": 0,
+                "fpmm": "",
+                "maker_base_fee": "0",
+                "taker_base_fee": "0",
+                "notifications_enabled": false,
+                "neg_risk": false,
+                "neg_risk_market_id": "",
+                "neg_risk_request_id": "",
+                "icon": "",
+                "image": "",
+                "rewards": {
+                    "rates": null,
+                    "min_size": "0",
+                    "max_spread": "0"
+                },
+                "is_50_50_outcome": false,
+                "tokens": [
+                    { "token_id": "YES_TOKEN", "outcome": "Yes", "price": "0.5" },
+                    { "token_id": "NO_TOKEN", "outcome": "No", "price": "0.5" }
+                ],
+                "tags": []
+            }));
+        });
+        let book_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", "OTHER_TOKEN");
+            then.status(StatusCode::OK).json_body(json!({
+                "market": "0xother",
+                "asset_id": "OTHER_TOKEN",
+                "tick_size": "0.01",
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "123456789"
+            }));
         });
 
-        let request = LastTradePriceRequest::builder()
-            .token_id("1".to_owned())
-            .build();
-        let response = client.last_trades_prices(&[request]).await?;
+        let expected = vec!["YES_TOKEN".to_owned(), "NO_TOKEN".to_owned()];
+        assert_eq!(client.token_ids("0xcond").await?, expected);
+        assert_eq!(client.token_ids("0xcond").await?, expected);
+        assert_eq!(client.clone().condition_id("NO_TOKEN").await?, "0xcond");
+        market_mock.assert_calls(1);
 
-        let expected = vec![
-            LastTradesPricesResponse::builder()
-                .token_id("1".to_owned())
-                .price(dec!(0.12))
-                .side(Side::Buy)
-                .build(),
-        ];
+        assert_eq!(client.condition_id("OTHER_TOKEN").await?, "0xother");
+        assert_eq!(client.condition_id("OTHER_TOKEN").await?, "0xother");
+        book_mock.assert_calls(1);
 
-        assert_eq!(response, expected);
-        mock.assert();
+        client.invalidate_internal_caches();
+        assert_eq!(client.token_index().token_ids("0xcond"), None);
 
         Ok(())
     }
@@ -741,6 +1961,7 @@ mod unauthenticated {
             .build();
 
         assert_eq!(response, expected);
+        assert_eq!(response.state(), MarketState::AcceptingOrders);
         mock.assert();
 
         Ok(())
@@ -877,6 +2098,31 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn simplified_markets_should_percent_encode_cursor() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/simplified-markets")
+                .query_param("next_cursor", "a+b/c=");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [],
+                "limit": 1,
+                "count": 0,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let response = client.simplified_markets(Some("a+b/c=".into())).await?;
+
+        assert!(response.data.is_empty(), "the page should be empty");
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn simplified_markets_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -962,6 +2208,69 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn sampling_filter_should_keep_markets_above_min_daily_rate() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let market = |condition_id: &str, rates: serde_json::Value| {
+            json!({
+                "condition_id": condition_id,
+                "tokens": [],
+                "rewards": { "rates": rates, "min_size": "10", "max_spread": "3" },
+                "archived": false,
+                "accepting_orders": true,
+                "active": true,
+                "closed": false
+            })
+        };
+        let rate = |daily_rate: &str| {
+            json!({
+                "asset_address": "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174",
+                "rewards_daily_rate": daily_rate
+            })
+        };
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/sampling-simplified-markets")
+                .query_param("next_cursor", "MTAw");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    market("none", json!(null)),
+                    market("low", json!([rate("5")])),
+                    market("exact", json!([rate("20")])),
+                    market("high", json!([rate("150")])),
+                    market("split", json!([rate("15"), rate("10")]))
+                ],
+                "limit": 5,
+                "count": 5,
+                "next_cursor": "MjAw"
+            }));
+        });
+
+        let filter = SamplingFilter::builder()
+            .min_daily_rate(dec!(20))
+            .next_cursor("MTAw")
+            .build();
+        let page = client
+            .sampling_simplified_markets_with_filter(&filter)
+            .await?;
+        let unfiltered = client
+            .sampling_simplified_markets_with_filter(
+                &SamplingFilter::builder().next_cursor("MTAw").build(),
+            )
+            .await?;
+
+        let ids: Vec<_> = page.iter().map(|m| m.condition_id.as_str()).collect();
+        assert_eq!(ids, ["exact", "high", "split"]);
+        assert_eq!(page.count, 3);
+        assert_eq!(page.next_cursor, "MjAw");
+        assert_eq!(unfiltered.len(), 5);
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn sampling_simplified_markets_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1266,11 +2575,15 @@ mod unauthenticated {
 }
 
 mod authenticated {
+    use std::time::Duration;
+
     use alloy::primitives::Signature;
     use alloy::signers::Signer as _;
     use alloy::signers::local::LocalSigner;
     use chrono::NaiveDate;
+    use futures_util::TryStreamExt as _;
     use httpmock::Method::{DELETE, GET, POST};
+    use polymarket_client_sdk::auth::Credentials;
     use polymarket_client_sdk::clob::types::request::{
         BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
         OrdersRequest, TradesRequest, UserRewardsEarningRequest,
@@ -1287,34 +2600,439 @@ mod authenticated {
         TraderSide,
     };
     use polymarket_client_sdk::types::{Address, address};
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    use super::*;
+    use crate::common::{
+        API_KEY, PASSPHRASE, POLY_NONCE, POLY_SIGNATURE, POLY_TIMESTAMP, SECRET, SIGNATURE,
+        TIMESTAMP,
+    };
+
+    #[tokio::test]
+    async fn api_keys_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        let response = client.api_keys().await?;
+
+        let expected = ApiKeysResponse::builder().keys(vec![API_KEY]).build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn with_headers_should_send_extra_headers_on_reads() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let tagged = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .header("user-agent", polymarket_client_sdk::DEFAULT_USER_AGENT)
+                .header("x-correlation-id", "call-1");
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+        let untagged = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header_missing("x-correlation-id");
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Correlation-Id", HeaderValue::from_static("call-1"));
+
+        client.with_headers(headers).api_keys().await?;
+        client.api_keys().await?;
+
+        tagged.assert();
+        untagged.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_api_key_should_sign_later_requests_with_new_key() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let rotated_key = Uuid::max();
+
+        assert!(
+            !client
+                .rotate_api_key_if_older_than(&signer, Duration::from_secs(3600))
+                .await?,
+            "fresh credentials should not be rotated"
+        );
+
+        let create = server.mock(|when, then| {
+            when.method(POST)
+                .path("/auth/api-key")
+                .header(POLY_ADDRESS, signer.address().to_string().to_lowercase());
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": rotated_key.to_string(),
+                "passphrase": "rotated-passphrase",
+                "secret": SECRET
+            }));
+        });
+        let old_key = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_API_KEY, API_KEY);
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+        let new_key = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_API_KEY, rotated_key)
+                .header(POLY_PASSPHRASE, "rotated-passphrase");
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [rotated_key]}));
+        });
+        let delete_old = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/auth/api-key")
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK).json_body(json!("OK"));
+        });
+
+        client.api_keys().await?;
+        assert!(
+            client
+                .rotate_api_key_if_older_than(&signer, Duration::ZERO)
+                .await?,
+            "stale credentials should be rotated"
+        );
+        let response = client.api_keys().await?;
+
+        let expected = ApiKeysResponse::builder().keys(vec![rotated_key]).build();
+
+        assert_eq!(response, expected);
+        assert!(
+            client.api_key_age() < Duration::from_secs(60),
+            "rotation should reset the age"
+        );
+        create.assert();
+        old_key.assert();
+        new_key.assert();
+        delete_old.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rate_limited_host_should_pause_before_signing() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(GET).path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+        let limited = server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::TOO_MANY_REQUESTS)
+                .header("retry-after", "2");
+        });
+
+        let config = Config::builder().backpressure(true).build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        let before = Utc::now().timestamp();
+        let keys = server.mock(|when, then| {
+            // Headers signed before the pause would carry a timestamp from before it ended
+            when.method(GET).path("/auth/api-keys").is_true(move |req| {
+                req.headers()
+                    .get(POLY_TIMESTAMP)
+                    .and_then(|value| value.to_str().ok()?.parse::<i64>().ok())
+                    .is_some_and(|timestamp| timestamp >= before + 2)
+            });
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        client.ok().await.unwrap_err();
+        client.api_keys().await?;
+
+        limited.assert();
+        keys.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_api_key_should_fail_when_old_key_is_not_deleted() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(POST).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": Uuid::max().to_string(),
+                "passphrase": "rotated-passphrase",
+                "secret": SECRET
+            }));
+        });
+        let delete_old = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/auth/api-key")
+                .header(POLY_API_KEY, API_KEY);
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        let err = client.rotate_api_key(&signer).await.unwrap_err();
+
+        assert_eq!(err.kind(), polymarket_client_sdk::error::Kind::Status);
+        delete_old.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn api_key_rotation_should_rotate_on_interval() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let rotated_key = Uuid::max();
+
+        let create = server.mock(|when, then| {
+            when.method(POST).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": rotated_key.to_string(),
+                "passphrase": "rotated-passphrase",
+                "secret": SECRET
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(DELETE).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(json!("OK"));
+        });
+        let new_key = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_API_KEY, rotated_key);
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [rotated_key]}));
+        });
+
+        let rotation = tokio::spawn(client.api_key_rotation(signer, Duration::from_millis(100)));
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        rotation.abort();
+
+        client.api_keys().await?;
+        create.assert_calls(1);
+        new_key.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn authenticated_from_credentials_should_send_l2_headers() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let address = LocalSigner::from_str(PRIVATE_KEY)?.address();
+        let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
+        let client =
+            Client::authenticated(&server.base_url(), Config::default(), credentials, address)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/data/orders")
+                .header(POLY_ADDRESS, address.to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .header_exists(POLY_SIGNATURE)
+                .header_exists(POLY_TIMESTAMP);
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [],
+                "limit": 0,
+                "count": 0,
+                "next_cursor": "LTE="
+            }));
+        });
+        let public = server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        let page = client.orders(&OrdersRequest::default(), None).await?;
+
+        assert!(page.data.is_empty(), "no orders should be returned");
+        assert_eq!(client.address(), address);
+        assert_eq!(client.ok().await?, "OK");
+        mock.assert();
+        public.assert();
+
+        Ok(())
+    }
+
+    /// Stands in for a hardware or remote signer: it holds no key and answers every hash with
+    /// the same signature.
+    struct CannedSigner {
+        address: Address,
+        signature: Signature,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl alloy::signers::Signer for CannedSigner {
+        async fn sign_hash(
+            &self,
+            _hash: &alloy::primitives::B256,
+        ) -> alloy::signers::Result<Signature> {
+            self.calls
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            Ok(self.signature)
+        }
+
+        fn address(&self) -> Address {
+            self.address
+        }
+
+        fn chain_id(&self) -> Option<u64> {
+            Some(POLYGON)
+        }
+
+        fn set_chain_id(&mut self, _chain_id: Option<u64>) {}
+    }
+
+    #[tokio::test]
+    async fn authentication_should_use_custom_signer() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = CannedSigner {
+            address: address!("0x00000000000000000000000000000000000000aa"),
+            signature: Signature::new(U256::from(1), U256::from(2), false),
+            calls: std::sync::atomic::AtomicUsize::new(0),
+        };
+
+        let derive = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/derive-api-key")
+                .header(POLY_ADDRESS, signer.address.to_string().to_lowercase())
+                .header(POLY_SIGNATURE, signer.signature.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let client = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        assert_eq!(client.address(), signer.address);
+        assert!(
+            signer.calls.load(std::sync::atomic::Ordering::Relaxed) > 0,
+            "the L1 headers should be signed by the custom signer"
+        );
+        derive.assert();
 
-    use super::*;
-    use crate::common::{
-        API_KEY, PASSPHRASE, POLY_NONCE, POLY_SIGNATURE, POLY_TIMESTAMP, SECRET, SIGNATURE,
-        TIMESTAMP,
-    };
+        Ok(())
+    }
 
     #[tokio::test]
-    async fn api_keys_should_succeed() -> anyhow::Result<()> {
+    async fn rotate_api_key_with_other_signer_should_fail() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
+        let other = LocalSigner::random().with_chain_id(Some(POLYGON));
 
-        let mock = server.mock(|when, then| {
+        let err = client.rotate_api_key(&other).await.unwrap_err();
+
+        assert!(
+            err.to_string().contains("Cannot rotate the API key"),
+            "unexpected error: {err}"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn server_time_sync_should_offset_auth_timestamps() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        // The server clock runs a day ahead of the local clock
+        let skew = 86_400;
+        let server_time = Utc::now().timestamp() + skew;
+        let timestamp_regex = format!(
+            "^({}|{}|{})$",
+            server_time - 1,
+            server_time,
+            server_time + 1
+        );
+
+        let time_mock = server.mock(|when, then| {
+            when.method(GET).path("/time");
+            then.status(StatusCode::OK).json_body(server_time);
+        });
+        let derive_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/derive-api-key")
+                .header_matches("(?i)^poly_timestamp$", timestamp_regex.as_str());
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let config = Config::builder()
+            .server_time_sync_interval(Duration::from_secs(3600))
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        let keys_mock = server.mock(|when, then| {
             when.method(GET)
                 .path("/auth/api-keys")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
                 .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE);
+                .header_matches("(?i)^poly_timestamp$", timestamp_regex.as_str());
             then.status(StatusCode::OK)
                 .json_body(json!({"apiKeys": [API_KEY]}));
         });
 
-        let response = client.api_keys().await?;
-
-        let expected = ApiKeysResponse::builder().keys(vec![API_KEY]).build();
+        client.api_keys().await?;
 
-        assert_eq!(response, expected);
-        mock.assert();
+        assert!(
+            (skew - 1..=skew + 1).contains(&client.time_offset()),
+            "offset {} should be close to {skew}",
+            client.time_offset()
+        );
+        derive_mock.assert();
+        keys_mock.assert();
+        // The offset is reused within the sync interval
+        time_mock.assert_calls(1);
 
         Ok(())
     }
@@ -1514,6 +3232,172 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn post_order_idempotent_should_submit_once_per_key() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "1", TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/order")
+                .header("idempotency-key", "order-1");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let first = client.sign(&signer, SignableOrder::default()).await?;
+        let retry = client.sign(&signer, SignableOrder::default()).await?;
+        let concurrent = client.sign(&signer, SignableOrder::default()).await?;
+
+        let (first, concurrent) = tokio::join!(
+            client.post_order_idempotent(first, "order-1"),
+            client.post_order_idempotent(concurrent, "order-1"),
+        );
+        let retry = client.post_order_idempotent(retry, "order-1").await?;
+
+        assert_eq!(first?, retry);
+        assert_eq!(concurrent?, retry);
+        mock.assert_calls(1);
+
+        let batch = client.sign(&signer, SignableOrder::default()).await?;
+        let err = client
+            .post_orders_idempotent(vec![batch], "order-1")
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), polymarket_client_sdk::error::Kind::Validation);
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_idempotent_should_resend_after_error() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "1", TickSize::Hundredth);
+
+        let mut failing = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::BAD_GATEWAY);
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let order = client.sign(&signer, SignableOrder::default()).await?;
+        client
+            .post_order_idempotent(order, "order-2")
+            .await
+            .unwrap_err();
+        failing.assert();
+        failing.delete();
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.post_order_idempotent(order, "order-2").await?;
+
+        assert_eq!(response.status, OrderStatusType::Live);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_with_headers_should_send_extra_headers() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "1", TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/order")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .header("user-agent", polymarket_client_sdk::DEFAULT_USER_AGENT)
+                .header("idempotency-key", "order-1");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let mut headers = HeaderMap::new();
+        headers.insert("Idempotency-Key", HeaderValue::from_static("order-1"));
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client
+            .post_order_with_headers(signed_order, headers)
+            .await?;
+
+        assert_eq!(response.status, OrderStatusType::Live);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn request_id_should_reuse_caller_header() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, "1", TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/order")
+                .header("x-request-id", "trace-123")
+                .header_count("^x-request-id$", ".*", 1);
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Request-Id", HeaderValue::from_static("trace-123"));
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        client
+            .post_order_with_headers(signed_order, headers)
+            .await?;
+
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn post_order_should_accept_transactions_hashes_alias() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1696,6 +3580,66 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn orders_stream_should_follow_cursor() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let order = |id: &str| {
+            json!({
+                "id": id,
+                "status": "LIVE",
+                "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "maker_address": "0x2222222222222222222222222222222222222222",
+                "market": "market_abc",
+                "asset_id": "asset_xyz",
+                "side": "buy",
+                "original_size": "10.0",
+                "size_matched": "0",
+                "price": "0.45",
+                "associate_trades": [],
+                "outcome": "YES",
+                "created_at": 1_705_322_096,
+                "expiration": "0",
+                "order_type": "GTC"
+            })
+        };
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/data/orders")
+                .query_param("market", "market_abc")
+                .query_param_missing("next_cursor");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [order("1"), order("2")],
+                "limit": 2,
+                "count": 2,
+                "next_cursor": "Mg=="
+            }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/data/orders")
+                .query_param("market", "market_abc")
+                .query_param("next_cursor", "Mg==");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [order("3")],
+                "limit": 2,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let request = OrdersRequest::builder().market("market_abc").build();
+        let orders: Vec<OpenOrderResponse> = client.orders_stream(&request).try_collect().await?;
+
+        let ids: Vec<&str> = orders.iter().map(|order| order.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3"]);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn cancel_order_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2073,6 +4017,75 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn notifications_should_carry_id_and_timestamp() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/notifications");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": 42,
+                    "type": 2,
+                    "owner": API_KEY,
+                    "timestamp": 1_705_314_600,
+                    "payload": {
+                        "asset_id": "1",
+                        "condition_id": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+                        "eventSlug": "event",
+                        "icon": "",
+                        "image": "",
+                        "market": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+                        "market_slug": "market",
+                        "matched_size": "5",
+                        "order_id": "0x2ae21876d2702d8b71308d0999062db9625a691ce4593c5f10230eeeff945e70",
+                        "original_size": "5",
+                        "outcome": "NO",
+                        "outcome_index": 1,
+                        "owner": API_KEY,
+                        "price": "0.4",
+                        "question": "Question?",
+                        "remaining_size": "0",
+                        "seriesSlug": "",
+                        "side": "sell",
+                        "trade_id": "565a5035-d70e-4493-9215-8cae52d26efe",
+                        "transaction_hash": "0x3bc57dcae83a930df64fce8fdc46a8fca9b98af92a7b83a8a2f2c657446c2a71",
+                        "type": "GTC"
+                    }
+                }
+            ]));
+        });
+        let delete_mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/notifications")
+                .query_param("ids", "42")
+                .json_body(json!({ "ids": "42" }));
+            then.status(StatusCode::OK).json_body(json!(null));
+        });
+
+        let notifications = client.notifications().await?;
+
+        assert_eq!(notifications.len(), 1);
+        let notification = &notifications[0];
+        assert_eq!(notification.id.as_deref(), Some("42"));
+        assert_eq!(notification.r#type, 2);
+        assert_eq!(
+            notification.timestamp,
+            DateTime::from_timestamp(1_705_314_600, 0)
+        );
+        assert_eq!(notification.payload.side, Side::Sell);
+
+        let request = DeleteNotificationsRequest::builder()
+            .notification_ids(notifications.into_iter().filter_map(|n| n.id).collect())
+            .build();
+        client.delete_notifications(&request).await?;
+
+        delete_mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn delete_notifications_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2218,6 +4231,52 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn is_order_scoring_should_report_not_scoring() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/order-scoring")
+                .query_param("order_id", "2");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "scoring": false }));
+        });
+
+        let response = client.is_order_scoring("2").await?;
+
+        assert!(!response.scoring, "the order should not be scoring");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn are_orders_scoring_should_map_every_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/orders-scoring")
+                .header(POLY_API_KEY, API_KEY)
+                .json_body(json!(["0xa", "0xb", "0xc"]));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "0xa": true, "0xb": false, "0xc": true }));
+        });
+
+        let response = client.are_orders_scoring(&["0xa", "0xb", "0xc"]).await?;
+
+        assert_eq!(response.len(), 3);
+        assert_eq!(response.get("0xa"), Some(&true));
+        assert_eq!(response.get("0xb"), Some(&false));
+        assert_eq!(response.get("0xc"), Some(&true));
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn earnings_for_user_for_day_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2626,9 +4685,7 @@ mod authenticated {
             }));
         });
 
-        let response = client
-            .raw_rewards_for_market("1", Some("1".to_owned()))
-            .await?;
+        let response = client.raw_rewards_for_market("1", Some("1".into())).await?;
 
         let market_reward = MarketRewardResponse::builder()
             .condition_id("1")
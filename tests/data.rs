@@ -192,7 +192,7 @@ mod trades {
         assert_eq!(trade.side, Side::Buy);
         assert_eq!(trade.size, dec!(50.0));
         assert_eq!(trade.price, dec!(0.55));
-        assert_eq!(trade.timestamp, 1_703_980_800);
+        assert_eq!(trade.timestamp.timestamp(), 1_703_980_800);
         mock.assert();
 
         Ok(())
@@ -929,7 +929,12 @@ mod error_handling {
 }
 
 mod client {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::RedirectPolicy;
     use polymarket_client_sdk::data::Client;
+    use polymarket_client_sdk::error::{Kind, Redirect, Status};
+    use reqwest::StatusCode;
+    use serde_json::json;
 
     #[test]
     fn client_default_should_succeed() {
@@ -948,6 +953,74 @@ mod client {
     fn client_new_with_invalid_url_should_fail() {
         Client::new("not-a-valid-url").unwrap_err();
     }
+
+    #[tokio::test]
+    async fn redirect_should_fail_by_default() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::MOVED_PERMANENTLY)
+                .header("location", "/v2/");
+        });
+        let moved = server.mock(|when, then| {
+            when.method(GET).path("/v2/");
+            then.status(StatusCode::OK).json_body(json!({"data": "OK"}));
+        });
+
+        let err = client.health().await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Redirect);
+        let redirect = err.downcast_ref::<Redirect>().unwrap();
+        assert_eq!(redirect.location.as_deref(), Some("/v2/"));
+        mock.assert();
+        moved.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn limited_redirect_policy_should_follow() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::with_redirect(&server.base_url(), RedirectPolicy::Limited(1))?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::MOVED_PERMANENTLY)
+                .header("location", "/v2/");
+        });
+        let moved = server.mock(|when, then| {
+            when.method(GET).path("/v2/");
+            then.status(StatusCode::OK).json_body(json!({"data": "OK"}));
+        });
+
+        let response = client.health().await?;
+
+        assert_eq!(response.data, "OK");
+        moved.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn not_modified_should_not_be_a_redirect() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::NOT_MODIFIED);
+        });
+
+        let err = client.health().await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.status_code, StatusCode::NOT_MODIFIED);
+
+        Ok(())
+    }
 }
 
 mod types {
@@ -25,13 +25,18 @@
 //! - `comments`: Comment listing and lookup by ID/user address
 //! - `profiles`: Public profile lookup
 //! - `search`: Search across events, markets, and profiles
-//! - `health`: API health check
+//! - `health`: API health check, and the readiness check across Gamma and CLOB
+//! - `blocking`: Blocking client (requires the `blocking` feature)
 
 #![cfg(feature = "gamma")]
 
 mod sports {
+    use chrono::NaiveDate;
     use httpmock::{Method::GET, MockServer};
-    use polymarket_client_sdk::gamma::{Client, types::request::TeamsRequest};
+    use polymarket_client_sdk::gamma::{
+        Client,
+        types::request::{SportsGamesRequest, TeamsRequest},
+    };
     use reqwest::StatusCode;
     use serde_json::json;
 
@@ -110,6 +115,79 @@ mod sports {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn sports_games_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/sports/games")
+                .query_param("league", "NBA")
+                .query_param("date", "2025-01-15");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": 9001,
+                    "league": "NBA",
+                    "homeTeam": { "id": 1, "name": "Lakers", "abbreviation": "LAL" },
+                    "awayTeam": { "id": 2, "name": "Celtics", "abbreviation": "BOS" },
+                    "homeScore": 88,
+                    "awayScore": 91,
+                    "period": "Q4",
+                    "elapsed": "07:42",
+                    "status": "InProgress",
+                    "live": true,
+                    "ended": false,
+                    "startTime": "2025-01-15T00:30:00Z",
+                    "eventId": "35090"
+                },
+                {
+                    "id": "9002",
+                    "league": "NBA",
+                    "homeTeam": { "id": 3, "name": "Knicks" },
+                    "awayTeam": { "id": 4, "name": "Heat" },
+                    "homeScore": null,
+                    "awayScore": null,
+                    "period": null,
+                    "elapsed": null,
+                    "status": "Scheduled",
+                    "live": false,
+                    "ended": false,
+                    "startTime": "2025-01-15T03:00:00Z"
+                }
+            ]));
+        });
+
+        let request = SportsGamesRequest::builder()
+            .league(vec!["NBA".to_owned()])
+            .date(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap())
+            .build();
+        let games = client.sports_games(&request).await?;
+
+        assert_eq!(games.len(), 2);
+
+        let in_progress = &games[0];
+        assert_eq!(in_progress.id, "9001");
+        assert_eq!(in_progress.score(), Some((88, 91)));
+        assert_eq!(in_progress.period.as_deref(), Some("Q4"));
+        assert_eq!(
+            in_progress.home_team.as_ref().unwrap().name.as_deref(),
+            Some("Lakers")
+        );
+        assert_eq!(in_progress.event_id.as_deref(), Some("35090"));
+        assert!(in_progress.is_live(), "game in progress should be live");
+
+        let scheduled = &games[1];
+        assert_eq!(scheduled.id, "9002");
+        assert_eq!(scheduled.score(), None);
+        assert_eq!(scheduled.period, None);
+        assert_eq!(scheduled.event_id, None);
+        assert!(!scheduled.is_live(), "scheduled game should not be live");
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn sports_market_types_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -132,16 +210,78 @@ mod sports {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn sports_market_types_lookup_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/sports/market-types");
+            then.status(StatusCode::OK).json_body(json!({
+                "marketTypes": [
+                    "moneyline",
+                    "spreads",
+                    "totals",
+                    "tennis_match_totals",
+                    "tennis_set_handicap",
+                    "cricket_toss_winner",
+                    "tennisfirst_set"
+                ]
+            }));
+        });
+
+        let response = client.sports_market_types().await?;
+
+        assert_eq!(response.find("Moneyline"), Some("moneyline"));
+        assert_eq!(response.find("unknown"), None);
+        assert!(response.contains("TOTALS"), "lookup should ignore case");
+        assert_eq!(
+            response.by_sport("Tennis").collect::<Vec<_>>(),
+            vec!["tennis_match_totals", "tennis_set_handicap"]
+        );
+        assert_eq!(
+            response.by_sport("cricket").collect::<Vec<_>>(),
+            vec!["cricket_toss_winner"]
+        );
+        assert_eq!(response.by_sport("nba").count(), 0);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sports_market_types_null_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/sports/market-types");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "marketTypes": null }));
+        });
+
+        let response = client.sports_market_types().await?;
+
+        assert!(response.market_types.is_empty(), "null should be empty");
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod tags {
+    use std::collections::HashSet;
+
+    use chrono::{DateTime, TimeDelta, Utc};
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::gamma::{
         Client,
         types::request::{
             RelatedTagsByIdRequest, RelatedTagsBySlugRequest, TagByIdRequest, TagBySlugRequest,
-            TagsRequest,
+            TagTaxonomyRequest, TagsRequest,
         },
+        types::response::{Tag, TagById, TaxonomyProgress},
     };
     use reqwest::StatusCode;
     use serde_json::json;
@@ -182,6 +322,177 @@ mod tags {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn tags_by_id_should_deduplicate() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/tags");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "1", "label": "Politics", "slug": "politics" },
+                { "id": "2", "label": "Sports", "slug": "sports" },
+                { "id": "1", "label": "Politics (updated)", "slug": "politics" }
+            ]));
+        });
+
+        let request = TagsRequest::builder().build();
+        let response = client.tags(&request).await?;
+        let unique: HashSet<TagById> = response.into_iter().map(TagById::from).collect();
+
+        assert_eq!(unique.len(), 2);
+        assert!(unique.contains("1"), "tag 1 should be found by id");
+        assert!(unique.contains("2"), "tag 2 should be found by id");
+        assert!(!unique.contains("3"), "tag 3 was never returned");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn load_tag_taxonomy_should_page_tags_and_relationships() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first_page = server.mock(|when, then| {
+            when.method(GET)
+                .path("/tags")
+                .query_param("limit", "2")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "1", "label": "Sports", "slug": "sports" },
+                { "id": "2", "label": "Soccer", "slug": "soccer" }
+            ]));
+        });
+        let second_page = server.mock(|when, then| {
+            when.method(GET)
+                .path("/tags")
+                .query_param("limit", "2")
+                .query_param("offset", "2");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "3", "label": "Tennis", "slug": "tennis" }
+            ]));
+        });
+        let relationships = [
+            (
+                "1",
+                json!([
+                    { "id": "10", "tagID": 1, "relatedTagID": 2, "rank": 2 },
+                    { "id": "11", "tagID": 1, "relatedTagID": 3, "rank": 1 },
+                    { "id": "12", "tagID": 1, "relatedTagID": 99, "rank": 3 }
+                ]),
+            ),
+            ("2", json!([])),
+            ("3", json!([])),
+        ]
+        .map(|(id, body)| {
+            server.mock(|when, then| {
+                when.method(GET).path(format!("/tags/{id}/related-tags"));
+                then.status(StatusCode::OK).json_body(body);
+            })
+        });
+
+        let request = TagTaxonomyRequest::builder()
+            .page_size(2)
+            .concurrency(2)
+            .build();
+        let mut progress = Vec::new();
+        let mut on_progress = |step| progress.push(step);
+        let taxonomy = client
+            .load_tag_taxonomy(&request, Some(&mut on_progress))
+            .await?;
+
+        let ids = |tags: Vec<&Tag>| {
+            tags.into_iter()
+                .map(|tag| tag.id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(taxonomy.len(), 3);
+        assert_eq!(ids(taxonomy.roots()), ["1"]);
+        assert_eq!(ids(taxonomy.children("1")), ["3", "2"]);
+        assert!(
+            taxonomy.children("2").is_empty(),
+            "soccer has no related tags"
+        );
+        assert_eq!(
+            taxonomy.by_slug("tennis").map(|tag| tag.id.as_str()),
+            Some("3")
+        );
+        assert_eq!(
+            taxonomy.by_id("2").and_then(|tag| tag.label.as_deref()),
+            Some("Soccer")
+        );
+        assert_eq!(
+            progress[..2],
+            [
+                TaxonomyProgress::Tags { loaded: 2 },
+                TaxonomyProgress::Tags { loaded: 3 }
+            ]
+        );
+        assert_eq!(
+            progress.last(),
+            Some(&TaxonomyProgress::Relationships {
+                loaded: 3,
+                total: 3
+            })
+        );
+        first_page.assert();
+        second_page.assert();
+        for mock in relationships {
+            mock.assert();
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn load_tag_taxonomy_should_stop_at_max_tags() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let page = server.mock(|when, then| {
+            when.method(GET)
+                .path("/tags")
+                .query_param("limit", "1")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "1", "slug": "sports" }]));
+        });
+        let related = server.mock(|when, then| {
+            when.method(GET).path("/tags/1/related-tags");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = TagTaxonomyRequest::builder()
+            .page_size(10)
+            .max_tags(1)
+            .build();
+        let taxonomy = client.load_tag_taxonomy(&request, None).await?;
+
+        assert_eq!(taxonomy.len(), 1);
+        page.assert();
+        related.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn same_as_should_ignore_fields_other_than_id() -> anyhow::Result<()> {
+        let tag: Tag = serde_json::from_value(json!({ "id": "1", "label": "Politics" }))?;
+        let updated: Tag =
+            serde_json::from_value(json!({ "id": "1", "label": "Politics (updated)" }))?;
+        let other: Tag = serde_json::from_value(json!({ "id": "2", "label": "Politics" }))?;
+
+        assert!(tag.same_as(&updated), "same id should be the same tag");
+        assert_ne!(tag, updated);
+        assert!(
+            !tag.same_as(&other),
+            "different ids should be different tags"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn tag_by_id_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -237,6 +548,77 @@ mod tags {
         Ok(())
     }
 
+    #[test]
+    fn tag_should_be_stale_after_max_age() -> anyhow::Result<()> {
+        let tag: Tag = serde_json::from_value(json!({
+            "id": "99",
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": "2024-06-01T12:00:00.5Z"
+        }))?;
+        let now = "2024-06-02T12:00:00Z".parse::<DateTime<Utc>>()?;
+
+        assert_eq!(
+            tag.age(now),
+            Some(TimeDelta::hours(24) - TimeDelta::milliseconds(500))
+        );
+        assert!(
+            !tag.is_stale(TimeDelta::days(1), now),
+            "a tag updated within a day should be fresh"
+        );
+        assert!(
+            tag.is_stale(TimeDelta::hours(1), now),
+            "a tag updated a day ago should be stale after an hour"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn tag_should_fall_back_to_created_at() -> anyhow::Result<()> {
+        let tag: Tag = serde_json::from_value(json!({
+            "id": "99",
+            "createdAt": "2024-06-01T00:00:00Z",
+            "updatedAt": ""
+        }))?;
+        let now = "2024-06-01T03:00:00Z".parse::<DateTime<Utc>>()?;
+
+        assert_eq!(tag.updated_at, None);
+        assert_eq!(tag.age(now), Some(TimeDelta::hours(3)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn tag_without_timestamps_should_have_unknown_age() -> anyhow::Result<()> {
+        let tag: Tag = serde_json::from_value(json!({ "id": "99", "createdAt": "" }))?;
+        let now = Utc::now();
+
+        assert_eq!(tag.age(now), None);
+        assert!(
+            !tag.is_stale(TimeDelta::zero(), now),
+            "a tag of unknown age should not be stale"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn tag_should_display_id_and_slug() -> anyhow::Result<()> {
+        let tag: Tag = serde_json::from_value(json!({
+            "id": "1",
+            "label": "Politics",
+            "slug": "politics"
+        }))?;
+        let unslugged: Tag = serde_json::from_value(json!({ "id": "2", "label": "Sports" }))?;
+        let bare: Tag = serde_json::from_value(json!({ "id": "3", "slug": "" }))?;
+
+        assert_eq!(tag.to_string(), "#1 politics");
+        assert_eq!(unslugged.to_string(), "#2 Sports");
+        assert_eq!(bare.to_string(), "#3");
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn related_tags_by_id_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -357,11 +739,15 @@ mod tags {
 
 mod events {
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::error::{Kind, Status};
     use polymarket_client_sdk::gamma::{
         Client,
         types::request::{EventByIdRequest, EventBySlugRequest, EventsRequest},
+        types::response::{Event, Tag, TopLevelCategory},
     };
+    use polymarket_client_sdk::types::Decimal;
     use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
     use serde_json::json;
 
     #[tokio::test]
@@ -395,126 +781,1550 @@ mod events {
     }
 
     #[tokio::test]
-    async fn event_by_id_should_succeed() -> anyhow::Result<()> {
+    async fn events_with_meta_should_parse_rate_limit() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url())?;
 
         let mock = server.mock(|when, then| {
-            when.method(GET).path("/events/456");
-            then.status(StatusCode::OK).json_body(json!({
-                "id": "456",
-                "title": "Specific Event",
-                "slug": "specific-event"
-            }));
+            when.method(GET).path("/events");
+            then.status(StatusCode::OK)
+                .header("X-RateLimit-Limit", "120")
+                .header("X-RateLimit-Remaining", "7")
+                .header("X-RateLimit-Reset", "1700000000")
+                .json_body(json!([{ "id": "123" }]));
         });
 
-        let request = EventByIdRequest::builder().id("456").build();
-        let response = client.event_by_id(&request).await?;
+        let request = EventsRequest::default();
+        let outcome = client.events_with_meta(&request).await?;
 
-        assert_eq!(response.id, "456");
-        assert_eq!(response.title, Some("Specific Event".to_owned()));
+        assert_eq!(outcome.value.len(), 1);
+        let rate_limit = outcome.rate_limit.unwrap();
+        assert_eq!(rate_limit.limit, Some(120));
+        assert_eq!(rate_limit.remaining, Some(7));
+        assert_eq!(rate_limit.reset, Some(1_700_000_000));
         mock.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn event_by_slug_should_succeed() -> anyhow::Result<()> {
+    async fn events_with_meta_without_headers_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url())?;
 
         let mock = server.mock(|when, then| {
-            when.method(GET).path("/events/slug/my-event");
-            then.status(StatusCode::OK).json_body(json!({
-                "id": "789",
-                "title": "My Event",
-                "slug": "my-event"
-            }));
+            when.method(GET).path("/events");
+            then.status(StatusCode::OK).json_body(json!([]));
         });
 
-        let request = EventBySlugRequest::builder().slug("my-event").build();
-        let response = client.event_by_slug(&request).await?;
+        let outcome = client.events_with_meta(&EventsRequest::default()).await?;
 
-        assert_eq!(response.id, "789");
-        assert_eq!(response.slug, Some("my-event".to_owned()));
+        assert!(outcome.value.is_empty(), "no events should be returned");
+        assert_eq!(outcome.rate_limit, None);
         mock.assert();
 
         Ok(())
     }
-}
-
-mod markets {
-    use httpmock::{Method::GET, MockServer};
-    use polymarket_client_sdk::gamma::{
-        Client,
-        types::request::{MarketByIdRequest, MarketBySlugRequest, MarketsRequest},
-    };
-    use reqwest::StatusCode;
-    use serde_json::json;
 
     #[tokio::test]
-    async fn markets_should_succeed() -> anyhow::Result<()> {
+    async fn events_by_tag_slug_should_resolve_then_query() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url())?;
 
-        let mock = server.mock(|when, then| {
-            when.method(GET).path("/markets").query_param("limit", "10");
+        let tag_mock = server.mock(|when, then| {
+            when.method(GET).path("/tags/slug/nba");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "id": "745", "slug": "nba" }));
+        });
+        let events_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("tag_id", "745")
+                .query_param("active", "true")
+                .query_param_missing("tag_slug");
             then.status(StatusCode::OK).json_body(json!([
-                {
-                    "id": "1",
-                    "question": "Test Market?",
-                    "slug": "test-market"
-                }
+                { "id": "1", "markets": [{ "id": "10" }, { "id": "11" }] },
+                { "id": "2", "markets": [{ "id": "20" }] }
+            ]));
+        });
+        let markets_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("id", "10,11,20")
+                .query_param("limit", "3");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "10", "question": "Lakers win?" },
+                { "id": "20", "question": "Celtics win?" }
             ]));
         });
 
-        let request = MarketsRequest::builder().limit(10).build();
-        let response = client.markets(&request).await?;
-
-        assert_eq!(response.len(), 1);
-        assert_eq!(response[0].id, "1");
-        assert_eq!(response[0].question, Some("Test Market?".to_owned()));
-        mock.assert();
+        let request = EventsRequest::builder()
+            .active(true)
+            .tag_slug("ignored".to_owned())
+            .build();
+        let events = client.events_by_tag_slug("nba", &request, true).await?;
+
+        assert_eq!(events.len(), 2);
+        let markets = events[0].markets.as_deref().unwrap_or_default();
+        assert_eq!(markets[0].question.as_deref(), Some("Lakers win?"));
+        assert_eq!(markets[1].id, "11");
+        assert_eq!(markets[1].question, None);
+        let markets = events[1].markets.as_deref().unwrap_or_default();
+        assert_eq!(markets[0].question.as_deref(), Some("Celtics win?"));
+        tag_mock.assert();
+        events_mock.assert();
+        markets_mock.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn market_by_id_should_succeed() -> anyhow::Result<()> {
+    async fn events_by_tag_slug_without_expansion_should_skip_markets() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url())?;
 
-        let mock = server.mock(|when, then| {
-            when.method(GET).path("/markets/42");
-            then.status(StatusCode::OK).json_body(json!({
-                "id": "42",
-                "question": "Specific Market?",
-                "slug": "specific-market"
-            }));
+        server.mock(|when, then| {
+            when.method(GET).path("/tags/slug/nba");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "id": "745" }));
+        });
+        let events_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("tag_id", "745");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "1", "markets": [{ "id": "10" }] }]));
+        });
+        let markets_mock = server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([]));
         });
 
-        let request = MarketByIdRequest::builder().id("42").build();
-        let response = client.market_by_id(&request).await?;
+        let events = client
+            .events_by_tag_slug("nba", &EventsRequest::default(), false)
+            .await?;
 
-        assert_eq!(response.id, "42");
-        assert_eq!(response.question, Some("Specific Market?".to_owned()));
-        mock.assert();
+        assert_eq!(events.len(), 1);
+        events_mock.assert();
+        markets_mock.assert_calls(0);
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn market_by_slug_should_succeed() -> anyhow::Result<()> {
+    async fn events_by_tag_slug_with_unknown_slug_should_return_not_found() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url())?;
 
-        let mock = server.mock(|when, then| {
-            when.method(GET).path("/markets/slug/my-market");
-            then.status(StatusCode::OK).json_body(json!({
-                "id": "99",
-                "question": "My Market?",
-                "slug": "my-market"
-            }));
+        server.mock(|when, then| {
+            when.method(GET).path("/tags/slug/missing");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "not found" }));
+        });
+        let events_mock = server.mock(|when, then| {
+            when.method(GET).path("/events");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let err = client
+            .events_by_tag_slug("missing", &EventsRequest::default(), true)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.status_code, StatusCode::NOT_FOUND);
+        events_mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn series_events_should_page_filter_and_sort() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("series_id", "7")
+                .query_param("closed", "false")
+                .query_param("limit", "2")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "3", "startDate": "2025-03-01T00:00:00Z", "series": [{ "id": "7" }] },
+                { "id": "1", "startDate": "2025-01-01T00:00:00Z", "series": [{ "id": "7" }] }
+            ]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("series_id", "7")
+                .query_param("limit", "2")
+                .query_param("offset", "2");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "other", "startDate": "2025-02-01T00:00:00Z", "series": [{ "id": "8" }] },
+                { "id": "unscheduled" },
+            ]));
+        });
+        let third = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("series_id", "7")
+                .query_param("offset", "4");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "2", "startDate": "2025-02-01T00:00:00Z", "series": [{ "id": "7" }] }
+            ]));
+        });
+
+        let request = EventsRequest::builder().closed(false).limit(2).build();
+        let events = client.series_events("7", &request).await?;
+
+        let ids: Vec<_> = events.iter().map(|event| event.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3", "unscheduled"]);
+        first.assert();
+        second.assert();
+        third.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn malformed_event_on_full_page_should_not_end_series_when_lenient() -> anyhow::Result<()>
+    {
+        let server = MockServer::start();
+        let client = Client::builder()
+            .lenient_arrays(true)
+            .build_client(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("series_id", "7")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "1", "series": [{ "id": "7" }] },
+                { "id": "2", "startDate": "not a date" }
+            ]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("series_id", "7")
+                .query_param("offset", "2");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "3", "series": [{ "id": "7" }] }]));
+        });
+
+        let request = EventsRequest::builder()
+            .series_id("7")
+            .limit(2)
+            .offset(0)
+            .build();
+        let page = client.events_page(&request).await?;
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.fetched, 2);
+        assert!(page.has_more(), "the server returned a full page");
+
+        let events = client.series_events("7", &request).await?;
+
+        let ids: Vec<_> = events.iter().map(|event| event.id.as_str()).collect();
+        assert_eq!(ids, ["1", "3"]);
+        first.assert_calls(2);
+        second.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn series_events_with_inverted_date_range_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = EventsRequest::builder()
+            .start_date_min("2025-02-01T00:00:00Z".parse()?)
+            .start_date_max("2025-01-01T00:00:00Z".parse()?)
+            .build();
+        let err = client.series_events("7", &request).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    fn outcome_market(id: &str, title: &str, yes: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "groupItemTitle": title,
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": format!("[\"{yes}\", \"0\"]")
+        })
+    }
+
+    #[test]
+    fn leading_outcome_should_pick_highest_yes_price() -> anyhow::Result<()> {
+        let event: Event = serde_json::from_value(json!({
+            "id": "1",
+            "negRisk": true,
+            "markets": [
+                outcome_market("10", "Alice", "0.31"),
+                outcome_market("11", "Bob", "0.52"),
+                outcome_market("12", "Carol", "0.17"),
+                { "id": "13", "groupItemTitle": "Unpriced" }
+            ]
+        }))?;
+
+        assert_eq!(
+            event.leading_outcome(),
+            Some(("Bob".to_owned(), dec!(0.52)))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn leading_outcome_should_be_none_on_tie_or_without_markets() -> anyhow::Result<()> {
+        let tied: Event = serde_json::from_value(json!({
+            "id": "1",
+            "markets": [
+                outcome_market("10", "Alice", "0.4"),
+                outcome_market("11", "Bob", "0.4"),
+                outcome_market("12", "Carol", "0.2")
+            ]
+        }))?;
+        let empty: Event = serde_json::from_value(json!({ "id": "2" }))?;
+
+        assert_eq!(tied.leading_outcome(), None);
+        assert_eq!(empty.leading_outcome(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn event_image_urls_should_be_validated_and_resolved() -> anyhow::Result<()> {
+        let cdn = url::Url::parse("https://cdn.example.com")?;
+        let event: Event = serde_json::from_value(json!({
+            "id": "1",
+            "image": "/events/election.png",
+            "icon": "https://cdn.example.com/events/election-icon.png"
+        }))?;
+        let missing: Event = serde_json::from_value(json!({ "id": "2", "image": "" }))?;
+
+        assert_eq!(
+            event.image_url(Some(&cdn)).map(String::from).as_deref(),
+            Some("https://cdn.example.com/events/election.png")
+        );
+        assert_eq!(event.image_url(None), None);
+        assert_eq!(
+            event.icon_url(None).map(String::from).as_deref(),
+            Some("https://cdn.example.com/events/election-icon.png")
+        );
+        assert_eq!(missing.image_url(Some(&cdn)), None);
+        assert_eq!(missing.icon_url(Some(&cdn)), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn engagement_should_be_parsed() -> anyhow::Result<()> {
+        let event: Event = serde_json::from_value(json!({
+            "id": "1",
+            "commentCount": 128,
+            "volume24hr": 25_000.5,
+            "commentsEnabled": true
+        }))?;
+
+        assert_eq!(event.comment_count(), Some(128));
+        assert!(
+            event.is_trending(dec!(10_000)),
+            "25k of daily volume should be trending at 10k"
+        );
+        assert!(
+            !event.is_trending(dec!(50_000)),
+            "25k of daily volume should not be trending at 50k"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_engagement_should_be_none() -> anyhow::Result<()> {
+        let event: Event = serde_json::from_value(json!({ "id": "1" }))?;
+        let negative: Event = serde_json::from_value(json!({ "id": "2", "commentCount": -1 }))?;
+
+        assert_eq!(event.comment_count(), None);
+        assert_eq!(negative.comment_count(), None);
+        assert!(
+            !event.is_trending(Decimal::ZERO),
+            "an event without volume should not be trending"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn event_category_should_follow_its_tags() -> anyhow::Result<()> {
+        let event: Event = serde_json::from_value(json!({
+            "id": "1",
+            "tags": [
+                { "id": "101", "label": "Trump", "slug": "trump" },
+                { "id": "102", "label": "Elections", "slug": "elections" },
+                { "id": "2", "label": "Politics", "slug": "politics" }
+            ]
+        }))?;
+        let sports: Event = serde_json::from_value(json!({
+            "id": "2",
+            "tags": [{ "id": "745", "label": "NBA" }]
+        }))?;
+
+        assert_eq!(event.category(), TopLevelCategory::Politics);
+        assert_eq!(sports.category(), TopLevelCategory::Sports);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_tags_should_be_other_category() -> anyhow::Result<()> {
+        let tags: Vec<Tag> = serde_json::from_value(json!([
+            { "id": "1", "label": "Weather", "slug": "weather" },
+            { "id": "2", "label": "Hurricanes", "slug": "hurricanes" }
+        ]))?;
+        let untagged: Event = serde_json::from_value(json!({ "id": "1" }))?;
+
+        assert_eq!(
+            TopLevelCategory::from_tags(&tags),
+            TopLevelCategory::Unknown("weather".to_owned())
+        );
+        assert_eq!(
+            untagged.category(),
+            TopLevelCategory::Unknown(String::new())
+        );
+        assert_eq!(TopLevelCategory::PopCulture.as_str(), "pop-culture");
+
+        Ok(())
+    }
+
+    #[test]
+    fn event_flags_should_default_to_false() -> anyhow::Result<()> {
+        let featured: Event = serde_json::from_value(json!({
+            "id": "1",
+            "featured": true,
+            "restricted": true
+        }))?;
+        let plain: Event = serde_json::from_value(json!({ "id": "2" }))?;
+
+        assert!(featured.is_featured(), "featured flag should be read");
+        assert!(featured.is_restricted(), "restricted flag should be read");
+        assert!(!plain.is_featured(), "missing flag should not be featured");
+        assert!(
+            !plain.is_restricted(),
+            "missing flag should not be restricted"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn event_should_display_id_and_slug() -> anyhow::Result<()> {
+        let event: Event = serde_json::from_value(json!({
+            "id": "16167",
+            "slug": "fed-decision-in-march",
+            "title": "Fed decision in March?"
+        }))?;
+        let untitled: Event = serde_json::from_value(json!({ "id": "2" }))?;
+
+        assert_eq!(event.to_string(), "#16167 fed-decision-in-march");
+        assert_eq!(untitled.to_string(), "#2");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_page_should_capture_total_from_header() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("limit", "2")
+                .query_param("offset", "20");
+            then.status(StatusCode::OK)
+                .header("X-Total-Count", "340")
+                .json_body(json!([{ "id": "1" }, { "id": "2" }]));
+        });
+
+        let request = EventsRequest::builder().limit(2).offset(20).build();
+        let page = client.events_page(&request).await?;
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.total, Some(340));
+        assert_eq!(page.offset, 20);
+        assert_eq!(page.limit, 2);
+        assert!(page.has_more(), "20 + 2 of 340 should have more");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_page_should_capture_total_from_body() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [{ "id": "1" }],
+                "pagination": { "hasMore": false, "totalResults": 1 }
+            }));
+        });
+
+        let page = client.events_page(&EventsRequest::default()).await?;
+
+        assert_eq!(page.items[0].id, "1");
+        assert_eq!(page.total, Some(1));
+        assert_eq!(page.offset, 0);
+        assert_eq!(page.limit, 1);
+        assert!(!page.has_more(), "the only page should not have more");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn event_tag_membership_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "1",
+                    "tags": [
+                        { "id": "2", "slug": "politics" },
+                        { "id": "21", "label": "No slug" },
+                        { "id": "100", "slug": "elections" }
+                    ],
+                    "markets": [{ "id": "30", "tags": [{ "id": "2", "slug": "politics" }] }]
+                },
+                { "id": "2", "tags": null, "markets": [{ "id": "31" }] },
+                { "id": "3" }
+            ]));
+        });
+
+        let response = client.events(&EventsRequest::default()).await?;
+
+        let tagged = &response[0];
+        assert!(tagged.has_tag("2"), "event should have tag 2");
+        assert!(tagged.has_tag("21"), "event should have tag 21");
+        assert!(!tagged.has_tag("3"), "event should not have tag 3");
+        assert_eq!(
+            tagged.tag_slugs().collect::<Vec<_>>(),
+            vec!["politics", "elections"]
+        );
+        let market = &tagged.markets.as_ref().unwrap()[0];
+        assert!(market.has_tag("2"), "market should have tag 2");
+        assert_eq!(market.tag_slugs().collect::<Vec<_>>(), vec!["politics"]);
+
+        for untagged in &response[1..] {
+            assert!(!untagged.has_tag("2"), "untagged event should have no tags");
+            assert_eq!(untagged.tag_slugs().count(), 0);
+        }
+        let market = &response[1].markets.as_ref().unwrap()[0];
+        assert!(!market.has_tag("2"), "untagged market should have no tags");
+        assert_eq!(market.tag_slugs().count(), 0);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_with_null_nested_objects_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "1",
+                    "series": [{ "id": "10", "slug": "nba" }],
+                    "templates": [{ "id": "20", "eventTitle": "Game {{teams}}" }],
+                    "markets": [{ "id": "30", "events": [{ "id": "1" }] }],
+                    "imageOptimized": { "id": "40" }
+                },
+                {
+                    "id": "2",
+                    "series": null,
+                    "templates": null,
+                    "markets": [{ "id": "31", "events": null }],
+                    "imageOptimized": null,
+                    "tags": null
+                }
+            ]));
+        });
+
+        let request = EventsRequest::builder().build();
+        let response = client.events(&request).await?;
+
+        assert_eq!(response.len(), 2);
+
+        let present = &response[0];
+        assert_eq!(present.first_series().unwrap().id, "10");
+        assert_eq!(present.first_template().unwrap().id, "20");
+        let market = &present.markets.as_ref().unwrap()[0];
+        assert_eq!(market.first_event().unwrap().id, "1");
+        assert!(
+            present.image_optimized.is_some(),
+            "image optimization should be present"
+        );
+
+        let null = &response[1];
+        assert!(null.first_series().is_none(), "series should be absent");
+        assert!(null.first_template().is_none(), "template should be absent");
+        let market = &null.markets.as_ref().unwrap()[0];
+        assert!(market.first_event().is_none(), "event should be absent");
+        assert!(
+            null.image_optimized.is_none(),
+            "image optimization should be absent"
+        );
+        assert!(null.tags.is_none(), "tags should be absent");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_with_invalid_date_range_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = EventsRequest::builder()
+            .end_date_min("2024-02-01T00:00:00Z".parse()?)
+            .end_date_max("2024-01-01T00:00:00Z".parse()?)
+            .build();
+        let err = client.events(&request).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn event_by_id_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events/456");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "456",
+                "title": "Specific Event",
+                "slug": "specific-event"
+            }));
+        });
+
+        let request = EventByIdRequest::builder().id("456").build();
+        let response = client.event_by_id(&request).await?;
+
+        assert_eq!(response.id, "456");
+        assert_eq!(response.title, Some("Specific Event".to_owned()));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_by_ids_should_preserve_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET).path("/events/1");
+            then.status(StatusCode::OK).json_body(json!({ "id": "1" }));
+        });
+        let missing = server.mock(|when, then| {
+            when.method(GET).path("/events/2");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "not found" }));
+        });
+        let third = server.mock(|when, then| {
+            when.method(GET).path("/events/3");
+            then.status(StatusCode::OK).json_body(json!({ "id": "3" }));
+        });
+
+        let results = client.events_by_ids(&["1", "2", "3"], 2).await?;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().id, "1");
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!(err.kind(), Kind::Status);
+        assert_eq!(results[2].as_ref().unwrap().id, "3");
+        first.assert();
+        missing.assert();
+        third.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_by_ids_zero_concurrency_should_fail() -> anyhow::Result<()> {
+        let client = Client::new("http://localhost")?;
+
+        let err = client.events_by_ids(&["1"], 0).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn event_by_slug_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events/slug/my-event");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "789",
+                "title": "My Event",
+                "slug": "my-event"
+            }));
+        });
+
+        let request = EventBySlugRequest::builder().slug("my-event").build();
+        let response = client.event_by_slug(&request).await?;
+
+        assert_eq!(response.id, "789");
+        assert_eq!(response.slug, Some("my-event".to_owned()));
+        mock.assert();
+
+        Ok(())
+    }
+}
+
+mod markets {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::clob::types::MarketState;
+    use polymarket_client_sdk::error::Validation;
+    use polymarket_client_sdk::gamma::types::response::{
+        ClobMarketRef, CommentParentType, Event, GameStatus, Market, MarketType, ReactionType,
+        Recurrence, Series, UmaResolutionStatus,
+    };
+    use polymarket_client_sdk::gamma::{
+        Client,
+        types::request::{MarketByIdRequest, MarketBySlugRequest, MarketsRequest},
+    };
+    use polymarket_client_sdk::types::Decimal;
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    #[test]
+    fn image_urls_should_be_validated_and_resolved() -> anyhow::Result<()> {
+        let cdn = url::Url::parse("https://cdn.example.com/images/")?;
+        let absolute: Market = serde_json::from_value(json!({
+            "id": "1",
+            "image": "https://polymarket-upload.s3.amazonaws.com/btc.png",
+            "icon": "btc-icon.png"
+        }))?;
+        let empty: Market = serde_json::from_value(json!({
+            "id": "2",
+            "image": "",
+            "icon": "  "
+        }))?;
+        let invalid: Market = serde_json::from_value(json!({
+            "id": "3",
+            "image": "javascript:alert(1)",
+            "icon": "https://"
+        }))?;
+
+        assert_eq!(
+            absolute.image_url(Some(&cdn)).map(String::from).as_deref(),
+            Some("https://polymarket-upload.s3.amazonaws.com/btc.png")
+        );
+        assert_eq!(
+            absolute.icon_url(Some(&cdn)).map(String::from).as_deref(),
+            Some("https://cdn.example.com/images/btc-icon.png")
+        );
+        assert_eq!(absolute.icon_url(None), None);
+        assert_eq!(empty.image_url(Some(&cdn)), None);
+        assert_eq!(empty.icon_url(Some(&cdn)), None);
+        assert_eq!(invalid.image_url(Some(&cdn)), None);
+        assert_eq!(invalid.icon_url(Some(&cdn)), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn market_flags_should_default_to_unrestricted() -> anyhow::Result<()> {
+        let featured: Market = serde_json::from_value(json!({
+            "id": "1",
+            "featured": true,
+            "restricted": "true"
+        }))?;
+        let plain: Market = serde_json::from_value(json!({ "id": "2" }))?;
+
+        assert!(featured.is_featured(), "featured flag should be read");
+        assert!(featured.is_restricted(), "restricted flag should be read");
+        assert!(!plain.is_featured(), "missing flag should not be featured");
+        assert!(
+            !plain.is_restricted(),
+            "missing flag should not be restricted"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn market_should_convert_to_clob_identifiers() -> anyhow::Result<()> {
+        let market: Market = serde_json::from_value(json!({
+            "id": "1",
+            "conditionId": "0xabc",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "clobTokenIds": "[\"111\", \"222\"]"
+        }))?;
+
+        let clob = ClobMarketRef::try_from(&market)?;
+
+        assert_eq!(clob.condition_id, "0xabc");
+        assert_eq!(clob.token_ids, ["111", "222"]);
+        assert_eq!(clob.outcomes, ["Yes", "No"]);
+        assert_eq!(clob.token_id("no"), Some("222"));
+        assert_eq!(clob.token_id("Maybe"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn market_without_clob_token_ids_should_not_convert() -> anyhow::Result<()> {
+        let untradable: Market = serde_json::from_value(json!({
+            "id": "1",
+            "conditionId": "0xabc",
+            "outcomes": "[\"Yes\", \"No\"]"
+        }))?;
+        let mismatched: Market = serde_json::from_value(json!({
+            "id": "2",
+            "conditionId": "0xdef",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "clobTokenIds": "[\"111\"]"
+        }))?;
+
+        let err = ClobMarketRef::try_from(&untradable).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "market 1: no CLOB token IDs, it is not tradable on the CLOB"
+        );
+        let err = ClobMarketRef::try_from(&mismatched).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Validation>().unwrap().reason,
+            "market 2: 2 outcomes but 1 CLOB token IDs"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn market_should_display_id_and_slug() -> anyhow::Result<()> {
+        let market: Market = serde_json::from_value(json!({
+            "id": "1",
+            "slug": "will-btc-hit-100k",
+            "question": "Will BTC hit $100k?"
+        }))?;
+        let unslugged: Market = serde_json::from_value(json!({
+            "id": "2",
+            "question": "Will BTC hit $100k?"
+        }))?;
+
+        assert_eq!(market.to_string(), "#1 will-btc-hit-100k");
+        assert_eq!(unslugged.to_string(), "#2 Will BTC hit $100k?");
+
+        Ok(())
+    }
+
+    fn mock_markets_with_malformed_element(server: &MockServer) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "1", "volumeNum": 1000 },
+                { "id": "2", "volumeNum": "not a number" },
+                { "id": "3" }
+            ]));
+        })
+    }
+
+    #[tokio::test]
+    async fn malformed_market_should_fail_strict_listing() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+        mock_markets_with_malformed_element(&server);
+
+        let request = MarketsRequest::builder().build();
+        client.markets(&request).await.unwrap_err();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn malformed_market_should_be_skipped_when_lenient() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let strict = Client::new(&server.base_url())?;
+        let lenient = Client::builder()
+            .lenient_arrays(true)
+            .build_client(&server.base_url())?;
+        let mock = mock_markets_with_malformed_element(&server);
+
+        let request = MarketsRequest::builder().build();
+        let (markets, skipped) = strict.markets_lenient(&request).await?;
+        let configured = lenient.markets(&request).await?;
+
+        let ids: Vec<_> = markets.iter().map(|market| market.id.as_str()).collect();
+        assert_eq!(ids, ["1", "3"]);
+        assert_eq!(skipped, 1);
+        assert_eq!(markets[0].volume_num, Some(dec!(1000)));
+        assert_eq!(configured, markets);
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn market_should_accept_mixed_bool_encodings() -> anyhow::Result<()> {
+        let market: Market = serde_json::from_value(json!({
+            "id": "1",
+            "active": true,
+            "closed": "false",
+            "archived": 0,
+            "acceptingOrders": "1",
+            "negRisk": null
+        }))?;
+
+        assert_eq!(market.active, Some(true));
+        assert_eq!(market.closed, Some(false));
+        assert_eq!(market.archived, Some(false));
+        assert_eq!(market.accepting_orders, Some(true));
+        assert_eq!(market.neg_risk, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn same_as_should_compare_condition_ids() -> anyhow::Result<()> {
+        let market: Market = serde_json::from_value(json!({
+            "id": "1",
+            "conditionId": "0xabc",
+            "outcomePrices": "[\"0.4\", \"0.6\"]"
+        }))?;
+        let updated: Market = serde_json::from_value(json!({
+            "id": "1",
+            "conditionId": "0xabc",
+            "outcomePrices": "[\"0.5\", \"0.5\"]"
+        }))?;
+        let other: Market = serde_json::from_value(json!({ "id": "1", "conditionId": "0xdef" }))?;
+        let without_condition: Market = serde_json::from_value(json!({ "id": "1" }))?;
+
+        assert!(
+            market.same_as(&updated),
+            "same condition should be the same market"
+        );
+        assert_ne!(market, updated);
+        assert!(
+            !market.same_as(&other),
+            "different conditions should be different markets"
+        );
+        assert!(
+            market.same_as(&without_condition),
+            "markets without a condition ID should fall back to the id"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets").query_param("limit", "10");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "1",
+                    "question": "Test Market?",
+                    "slug": "test-market"
+                }
+            ]));
+        });
+
+        let request = MarketsRequest::builder().limit(10).build();
+        let response = client.markets(&request).await?;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].id, "1");
+        assert_eq!(response[0].question, Some("Test Market?".to_owned()));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_with_enforce_status_should_drop_stale_closed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("closed", "false")
+                .query_param_missing("enforce_status");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "1", "active": true, "closed": false, "acceptingOrders": true },
+                {
+                    "id": "2",
+                    "active": true,
+                    "closed": false,
+                    "umaResolutionStatus": "resolved"
+                },
+                { "id": "3", "active": true, "closed": false, "archived": true }
+            ]));
+        });
+
+        let lenient = MarketsRequest::builder().closed(false).build();
+        assert_eq!(client.markets(&lenient).await?.len(), 3);
+
+        let strict = MarketsRequest::builder()
+            .closed(false)
+            .enforce_status(true)
+            .build();
+        let response = client.markets(&strict).await?;
+
+        let ids: Vec<_> = response.iter().map(|market| market.id.as_str()).collect();
+        assert_eq!(ids, ["1"]);
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_with_enforce_status_should_drop_closed_when_active() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("active", "true")
+                .query_param_missing("closed");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "1", "active": true, "closed": false, "acceptingOrders": true },
+                { "id": "2", "active": true, "closed": true },
+                {
+                    "id": "3",
+                    "active": true,
+                    "closed": false,
+                    "umaResolutionStatus": "resolved"
+                }
+            ]));
+        });
+
+        let request = MarketsRequest::builder()
+            .active(true)
+            .enforce_status(true)
+            .build();
+        let response = client.markets(&request).await?;
+
+        let ids: Vec<_> = response.iter().map(|market| market.id.as_str()).collect();
+        assert_eq!(ids, ["1"]);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn outcome_prices_should_map_outcomes_to_prices() -> anyhow::Result<()> {
+        let market: Market = serde_json::from_value(json!({
+            "id": "1",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"0.65\", \"0.35\"]"
+        }))?;
+
+        let prices = market.outcome_prices()?;
+
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices["Yes"], dec!(0.65));
+        assert_eq!(prices["No"], dec!(0.35));
+
+        Ok(())
+    }
+
+    #[test]
+    fn outcome_prices_should_decode_double_encoded_lists() -> anyhow::Result<()> {
+        let outcomes = serde_json::to_string("[\"Yes\", \"No\"]")?;
+        let market: Market = serde_json::from_value(json!({
+            "id": "1",
+            "outcomes": outcomes,
+            "outcomePrices": "[\"1\", \"0\"]"
+        }))?;
+
+        let prices = market.outcome_prices()?;
+
+        assert_eq!(prices["Yes"], Decimal::ONE);
+        assert_eq!(prices["No"], Decimal::ZERO);
+
+        Ok(())
+    }
+
+    #[test]
+    fn outcome_prices_without_lists_should_be_empty() -> anyhow::Result<()> {
+        let market: Market = serde_json::from_value(json!({ "id": "1" }))?;
+
+        assert!(
+            market.outcome_prices()?.is_empty(),
+            "no outcomes should map to no prices"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn outcome_prices_with_mismatched_lengths_should_fail() -> anyhow::Result<()> {
+        let market: Market = serde_json::from_value(json!({
+            "id": "1",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"0.65\"]"
+        }))?;
+
+        let err = market.outcome_prices().unwrap_err();
+
+        assert!(
+            err.to_string().contains("2 outcomes but 1 prices"),
+            "unexpected error: {err}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalized_probabilities_should_remove_overround() -> anyhow::Result<()> {
+        let market: Market = serde_json::from_value(json!({
+            "id": "1",
+            "outcomes": "[\"Trump\", \"Harris\", \"Other\"]",
+            "outcomePrices": "[\"0.63\", \"0.42\", \"0\"]"
+        }))?;
+
+        assert_eq!(market.raw_sum()?, dec!(1.05));
+
+        let probabilities = market.normalized_probabilities()?;
+
+        assert_eq!(probabilities.len(), 3);
+        assert_eq!(probabilities["Trump"], dec!(0.6));
+        assert_eq!(probabilities["Harris"], dec!(0.4));
+        assert_eq!(probabilities["Other"], Decimal::ZERO);
+        assert_eq!(probabilities.values().sum::<Decimal>(), Decimal::ONE);
+
+        Ok(())
+    }
+
+    #[test]
+    fn normalized_probabilities_with_zero_prices_should_be_empty() -> anyhow::Result<()> {
+        let market: Market = serde_json::from_value(json!({
+            "id": "1",
+            "outcomes": "[\"Yes\", \"No\"]",
+            "outcomePrices": "[\"0\", \"0\"]"
+        }))?;
+
+        assert_eq!(market.raw_sum()?, Decimal::ZERO);
+        assert!(
+            market.normalized_probabilities()?.is_empty(),
+            "zero prices should have no probabilities"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn enum_fields_should_keep_unknown_values() -> anyhow::Result<()> {
+        let known: Market =
+            serde_json::from_value(json!({ "id": "1", "umaResolutionStatus": "Resolved" }))?;
+        let unknown: Market =
+            serde_json::from_value(json!({ "id": "2", "umaResolutionStatus": "escalated" }))?;
+
+        assert_eq!(
+            known.uma_resolution_status,
+            Some(UmaResolutionStatus::Resolved)
+        );
+        assert_eq!(known.state(), MarketState::Resolved);
+        assert_eq!(
+            unknown.uma_resolution_status,
+            Some(UmaResolutionStatus::Unknown("escalated".to_owned()))
+        );
+        assert_eq!(
+            serde_json::to_value(&unknown)?["umaResolutionStatus"],
+            "escalated"
+        );
+
+        let series: Series = serde_json::from_value(json!({ "id": "3", "recurrence": "yearly" }))?;
+        assert_eq!(
+            series.recurrence,
+            Some(Recurrence::Unknown("yearly".to_owned()))
+        );
+        assert_eq!(Recurrence::Weekly.to_string(), "weekly");
+
+        Ok(())
+    }
+
+    #[test]
+    fn enum_fields_should_round_trip() -> anyhow::Result<()> {
+        fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(
+            value: &str,
+        ) -> anyhow::Result<(T, serde_json::Value)> {
+            let parsed: T = serde_json::from_value(json!(value))?;
+            let serialized = serde_json::to_value(&parsed)?;
+            Ok((parsed, serialized))
+        }
+
+        assert_eq!(
+            round_trip::<GameStatus>("InProgress")?,
+            (GameStatus::InProgress, json!("InProgress"))
+        );
+        assert_eq!(
+            round_trip::<GameStatus>("Halftime")?,
+            (
+                GameStatus::Unknown("Halftime".to_owned()),
+                json!("Halftime")
+            )
+        );
+        assert_eq!(
+            round_trip::<Recurrence>("DAILY")?,
+            (Recurrence::Daily, json!("daily"))
+        );
+        assert_eq!(
+            round_trip::<MarketType>("scalar")?,
+            (MarketType::Scalar, json!("scalar"))
+        );
+        assert_eq!(
+            round_trip::<MarketType>("ranged")?,
+            (MarketType::Unknown("ranged".to_owned()), json!("ranged"))
+        );
+        assert_eq!(
+            round_trip::<CommentParentType>("market")?,
+            (CommentParentType::Market, json!("market"))
+        );
+        assert_eq!(
+            round_trip::<CommentParentType>("Event")?,
+            (CommentParentType::Event, json!("Event"))
+        );
+        assert_eq!(
+            round_trip::<ReactionType>("THUMBS_UP")?,
+            (ReactionType::ThumbsUp, json!("THUMBS_UP"))
+        );
+        assert_eq!(
+            round_trip::<ReactionType>("FIRE")?,
+            (ReactionType::Unknown("FIRE".to_owned()), json!("FIRE"))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_state_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "1", "active": true, "closed": false, "acceptingOrders": true },
+                { "id": "2", "active": true, "closed": false, "acceptingOrders": false },
+                {
+                    "id": "3",
+                    "active": true,
+                    "closed": false,
+                    "acceptingOrders": false,
+                    "acceptingOrdersTimestamp": "2024-01-15T12:34:56Z"
+                },
+                { "id": "4", "active": true, "closed": true, "acceptingOrders": true },
+                { "id": "5", "closed": true, "umaResolutionStatus": "resolved" },
+                { "id": "6" }
+            ]));
+        });
+
+        let request = MarketsRequest::builder().build();
+        let response = client.markets(&request).await?;
+
+        let states: Vec<_> = response.iter().map(Market::state).collect();
+        assert_eq!(
+            states,
+            vec![
+                MarketState::AcceptingOrders,
+                MarketState::Open,
+                MarketState::Paused,
+                MarketState::Closed,
+                MarketState::Resolved,
+                MarketState::Paused,
+            ]
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn embedded_event_and_markets_should_populate_both_levels() -> anyhow::Result<()> {
+        let event: Event = serde_json::from_value(json!({
+            "id": "35090",
+            "slug": "fed-decision-in-december",
+            "title": "Fed decision in December?",
+            "createdAt": "2025-05-02T15:25:12.281946Z",
+            "updatedAt": "2025-11-30T09:13:02.441273Z",
+            "volume": "3215560.4487",
+            "markets": [
+                {
+                    "id": "516710",
+                    "slug": "will-the-fed-cut-rates-in-december",
+                    "question": "Will the Fed cut rates in December?",
+                    "createdAt": "2025-05-02T15:25:16.646816Z",
+                    "volume": "1044197.464711",
+                    "events": [
+                        {
+                            "id": "35090",
+                            "slug": "fed-decision-in-december",
+                            "createdAt": "2025-05-02T15:25:12.281946Z"
+                        }
+                    ],
+                    "tags": [{ "id": "2", "slug": "politics" }]
+                },
+                { "id": "516711" }
+            ]
+        }))?;
+
+        assert_eq!(event.id, "35090");
+        assert_eq!(event.slug.as_deref(), Some("fed-decision-in-december"));
+        assert!(event.created_at.is_some(), "event timestamps should parse");
+        assert_eq!(event.volume, Some(dec!(3215560.4487)));
+
+        let markets = event.markets.as_deref().unwrap();
+        assert_eq!(markets.len(), 2);
+        assert_eq!(markets[0].id, "516710");
+        assert_eq!(
+            markets[0].slug.as_deref(),
+            Some("will-the-fed-cut-rates-in-december")
+        );
+        assert!(
+            markets[0].created_at.is_some(),
+            "market timestamps should parse"
+        );
+        assert!(markets[0].has_tag("2"), "embedded market tags should parse");
+        assert_eq!(markets[1].id, "516711");
+        assert_eq!(markets[1].slug, None);
+
+        let embedded = markets[0].first_event().unwrap();
+        assert_eq!(embedded.id, event.id);
+        assert_eq!(embedded.slug, event.slug);
+        assert_eq!(embedded.created_at, event.created_at);
+        assert_eq!(embedded.title, None);
+        assert_eq!(embedded.markets, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn market_diff_should_report_only_volume() -> anyhow::Result<()> {
+        let before: Market = serde_json::from_value(json!({
+            "id": "1",
+            "question": "Will it rain?",
+            "active": true,
+            "closed": false,
+            "outcomePrices": "[\"0.65\", \"0.35\"]",
+            "volume": "1000",
+            "volumeNum": 1000,
+            "bestBid": 0.64,
+            "tags": [{ "id": "1" }, { "id": "2" }]
+        }))?;
+        let after: Market = serde_json::from_value(json!({
+            "id": "1",
+            "question": "Will it rain?",
+            "active": true,
+            "closed": false,
+            "outcomePrices": "[\"0.650\", \"0.35\"]",
+            "volume": "1250.5",
+            "volumeNum": 1250.5,
+            "bestBid": 0.64,
+            "tags": [{ "id": "2" }, { "id": "1" }]
+        }))?;
+
+        let diff = before.diff(&after);
+
+        assert_eq!(
+            diff.volume,
+            Some((Some(Decimal::from(1000)), Some(dec!(1250.5))))
+        );
+        assert_eq!(diff.outcome_prices, None);
+        assert_eq!(diff.tag_ids, None);
+        assert_eq!(diff.best_bid, None);
+        assert!(
+            !diff.is_empty(),
+            "a volume change should make the diff non-empty"
+        );
+        assert!(before.diff(&before).is_empty(), "a market equals itself");
+
+        Ok(())
+    }
+
+    #[test]
+    fn event_diff_should_ignore_reordered_markets() -> anyhow::Result<()> {
+        let before: Event = serde_json::from_value(json!({
+            "id": "1",
+            "active": true,
+            "markets": [{ "id": "10" }, { "id": "11" }]
+        }))?;
+        let after: Event = serde_json::from_value(json!({
+            "id": "1",
+            "active": false,
+            "markets": [{ "id": "11" }, { "id": "10" }]
+        }))?;
+
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.active, Some((Some(true), Some(false))));
+        assert_eq!(diff.market_ids, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_resolution_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "yes",
+                    "closed": true,
+                    "umaResolutionStatus": "resolved",
+                    "outcomes": "[\"Yes\", \"No\"]",
+                    "outcomePrices": "[\"1\", \"0\"]",
+                    "clobTokenIds": "[\"111\", \"222\"]",
+                    "closedTime": "2024-11-06 12:00:00+00"
+                },
+                {
+                    "id": "no",
+                    "closed": true,
+                    "outcomes": "[\"Yes\", \"No\"]",
+                    "outcomePrices": "[\"0\", \"1\"]",
+                    "closedTime": "2024-11-06T12:00:00Z"
+                },
+                {
+                    "id": "unresolved",
+                    "active": true,
+                    "closed": false,
+                    "outcomes": "[\"Yes\", \"No\"]",
+                    "outcomePrices": "[\"0.65\", \"0.35\"]"
+                },
+                {
+                    "id": "split",
+                    "closed": true,
+                    "umaResolutionStatus": "resolved",
+                    "outcomes": "[\"Yes\", \"No\"]",
+                    "outcomePrices": "[\"0.5\", \"0.5\"]"
+                },
+                {
+                    "id": "open-at-one",
+                    "active": true,
+                    "closed": false,
+                    "outcomes": "[\"Yes\", \"No\"]",
+                    "outcomePrices": "[\"1\", \"0\"]"
+                }
+            ]));
+        });
+
+        let response = client.markets(&MarketsRequest::default()).await?;
+        let resolved_at = "2024-11-06T12:00:00Z".parse()?;
+
+        let yes = response[0].resolution().unwrap();
+        assert_eq!(yes.winning_outcome, "Yes");
+        assert_eq!(yes.winning_token_id.as_deref(), Some("111"));
+        assert_eq!(yes.resolved_at, Some(resolved_at));
+
+        let no = response[1].resolution().unwrap();
+        assert_eq!(no.winning_outcome, "No");
+        assert_eq!(no.winning_token_id, None);
+        assert_eq!(no.resolved_at, Some(resolved_at));
+
+        assert_eq!(response[2].resolution(), None);
+        assert!(
+            !response[2].is_split_resolution(),
+            "unresolved market should not be split"
+        );
+
+        assert_eq!(response[3].resolution(), None);
+        assert!(
+            response[3].is_split_resolution(),
+            "0.5/0.5 market should be split"
+        );
+        assert!(
+            !response[0].is_split_resolution(),
+            "resolved market should not be split"
+        );
+
+        assert_eq!(response[4].resolution(), None);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_by_id_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets/42");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "42",
+                "question": "Specific Market?",
+                "slug": "specific-market"
+            }));
+        });
+
+        let request = MarketByIdRequest::builder().id("42").build();
+        let response = client.market_by_id(&request).await?;
+
+        assert_eq!(response.id, "42");
+        assert_eq!(response.question, Some("Specific Market?".to_owned()));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_by_slug_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets/slug/my-market");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "99",
+                "question": "My Market?",
+                "slug": "my-market"
+            }));
         });
 
         let request = MarketBySlugRequest::builder().slug("my-market").build();
@@ -528,124 +2338,505 @@ mod markets {
     }
 
     #[tokio::test]
-    async fn markets_empty_request() -> anyhow::Result<()> {
-        // Tests (true, true): no base params, no clob_token_ids
+    async fn markets_empty_request() -> anyhow::Result<()> {
+        // Tests (true, true): no base params, no clob_token_ids
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = MarketsRequest::default();
+        let response = client.markets(&request).await?;
+
+        assert!(response.is_empty());
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_only_clob_token_ids() -> anyhow::Result<()> {
+        // Tests (true, false): only clob_token_ids, no base params
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("clob_token_ids", "token1")
+                .query_param("clob_token_ids", "token2");
+            then.status(StatusCode::OK).json_body(json!([
+                {"id": "1", "question": "Market 1?", "slug": "market-1"}
+            ]));
+        });
+
+        let request = MarketsRequest::builder()
+            .clob_token_ids(vec!["token1".to_owned(), "token2".to_owned()])
+            .build();
+        let response = client.markets(&request).await?;
+
+        assert_eq!(response.len(), 1);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_with_base_and_clob_params() -> anyhow::Result<()> {
+        // Tests (false, false): both base params and clob_token_ids
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("limit", "50")
+                .query_param("clob_token_ids", "abc")
+                .query_param("clob_token_ids", "def");
+            then.status(StatusCode::OK).json_body(json!([
+                {"id": "1", "question": "Market 1?", "slug": "market-1"},
+                {"id": "2", "question": "Market 2?", "slug": "market-2"}
+            ]));
+        });
+
+        let request = MarketsRequest::builder()
+            .limit(50)
+            .clob_token_ids(vec!["abc".to_owned(), "def".to_owned()])
+            .build();
+        let response = client.markets(&request).await?;
+
+        assert_eq!(response.len(), 2);
+        mock.assert();
+
+        Ok(())
+    }
+}
+
+mod search {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::gamma::{Client, types::request::SearchRequest};
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn search_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/public-search")
+                .query_param("q", "bitcoin");
+            then.status(StatusCode::OK).json_body(json!({
+                "events": [],
+                "tags": [],
+                "profiles": []
+            }));
+        });
+
+        let request = SearchRequest::builder().q("bitcoin").build();
+        let response = client.search(&request).await?;
+
+        assert!(
+            response.events.is_none()
+                || response
+                    .events
+                    .as_ref()
+                    .is_some_and(std::vec::Vec::is_empty)
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn search_results_should_rank_by_volume() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/public-search");
+            then.status(StatusCode::OK).json_body(json!({
+                "events": [
+                    {
+                        "id": "e1",
+                        "volume": "100",
+                        "markets": [
+                            { "id": "m1", "volumeNum": "50" },
+                            { "id": "m2" }
+                        ]
+                    },
+                    {
+                        "id": "e2",
+                        "volume": "300",
+                        "markets": [{ "id": "m3", "volume": "200" }]
+                    },
+                    {
+                        "id": "e3",
+                        "markets": [{ "id": "m4", "volumeNum": "10" }]
+                    }
+                ]
+            }));
+        });
+
+        let request = SearchRequest::builder().q("bitcoin").build();
+        let response = client.search(&request).await?;
+
+        let events: Vec<_> = response
+            .rank_by_volume()
+            .into_iter()
+            .map(|event| event.id.as_str())
+            .collect();
+        assert_eq!(events, ["e2", "e1", "e3"]);
+
+        let markets: Vec<_> = response
+            .top_markets(3)
+            .into_iter()
+            .map(|market| market.id.as_str())
+            .collect();
+        assert_eq!(markets, ["m3", "m1", "m4"]);
+        assert_eq!(response.top_markets(10).len(), 4);
+        mock.assert();
+
+        Ok(())
+    }
+}
+
+mod config {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::RedirectPolicy;
+    use polymarket_client_sdk::error::{Kind, Redirect};
+    use polymarket_client_sdk::gamma::{
+        Client, Config,
+        types::request::{EventBySlugRequest, EventsRequest, SearchRequest, TagBySlugRequest},
+    };
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn accept_language_should_be_sent_when_configured() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().accept_language("es").build();
+        let client = Client::with_config(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .header("accept-language", "es");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        client.events(&EventsRequest::default()).await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn accept_language_should_be_absent_by_default() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .header_missing("accept-language");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        client.events(&EventsRequest::default()).await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn localized_request_should_override_config() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().accept_language("es").build();
+        let client = Client::with_config(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/public-search")
+                .header("accept-language", "pt-BR");
+            then.status(StatusCode::OK).json_body(json!({}));
+        });
+
+        let request = SearchRequest::builder().q("futebol").build();
+        client.search_localized(&request, "pt-BR").await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn default_query_should_be_merged() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .default_query(vec![
+                ("source".to_owned(), "my-app".to_owned()),
+                ("limit".to_owned(), "5".to_owned()),
+            ])
+            .build();
+        let client = Client::with_config(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("source", "my-app")
+                .query_param("active", "true")
+                .query_param("limit", "10")
+                .query_param_count("^limit$", ".*", 1);
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = EventsRequest::builder().active(true).limit(10).build();
+        client.events(&request).await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn default_query_should_apply_without_call_params() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .default_query(vec![("cache".to_owned(), "false".to_owned())])
+            .build();
+        let client = Client::with_config(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/sports")
+                .query_param("cache", "false");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        client.sports().await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn builder_should_apply_every_option() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::builder()
+            .accept_language("es")
+            .user_agent("my-bot/1.0")
+            .default_query(vec![
+                ("source".to_owned(), "my-bot".to_owned()),
+                ("cache".to_owned(), "false".to_owned()),
+            ])
+            .redirect(RedirectPolicy::Limited(1))
+            .build_client(&server.base_url())?;
+
+        let moved = server.mock(|when, then| {
+            when.method(GET)
+                .path("/sports")
+                .header("accept-language", "es")
+                .header("user-agent", "my-bot/1.0")
+                .query_param("source", "my-bot")
+                .query_param("cache", "false");
+            then.status(StatusCode::MOVED_PERMANENTLY)
+                .header("location", "/sports/v2");
+        });
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/sports/v2")
+                .header("accept-language", "es")
+                .header("user-agent", "my-bot/1.0");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        client.sports().await?;
+
+        assert_eq!(client.host().as_str(), format!("{}/", server.base_url()));
+        moved.assert();
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn normalize_slugs_should_slugify_by_slug_requests() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().normalize_slugs(true).build();
+        let client = Client::with_config(&server.base_url(), config)?;
+
+        let event_mock = server.mock(|when, then| {
+            when.method(GET).path("/events/slug/nba-finals");
+            then.status(StatusCode::OK).json_body(json!({ "id": "1" }));
+        });
+        let tag_mock = server.mock(|when, then| {
+            when.method(GET).path("/tags/slug/nba");
+            then.status(StatusCode::OK).json_body(json!({ "id": "2" }));
+        });
+
+        let event = client
+            .event_by_slug(&EventBySlugRequest::builder().slug("NBA Finals").build())
+            .await?;
+        let tag = client
+            .tag_by_slug(&TagBySlugRequest::builder().slug(" NBA ").build())
+            .await?;
+
+        assert_eq!(event.id, "1");
+        assert_eq!(tag.id, "2");
+        event_mock.assert();
+        tag_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn empty_normalized_slug_should_fail_without_request() -> anyhow::Result<()> {
         let server = MockServer::start();
-        let client = Client::new(&server.base_url())?;
+        let config = Config::builder().normalize_slugs(true).build();
+        let client = Client::with_config(&server.base_url(), config)?;
 
-        let mock = server.mock(|when, then| {
-            when.method(GET).path("/markets");
-            then.status(StatusCode::OK).json_body(json!([]));
+        let listing = server.mock(|when, then| {
+            when.method(GET).path("/tags/slug/");
+            then.status(StatusCode::OK).json_body(json!({ "id": "2" }));
         });
 
-        let request = MarketsRequest::default();
-        let response = client.markets(&request).await?;
+        let err = client
+            .tag_by_slug(&TagBySlugRequest::builder().slug("?!").build())
+            .await
+            .unwrap_err();
 
-        assert!(response.is_empty());
-        mock.assert();
+        assert_eq!(err.kind(), Kind::Validation);
+        listing.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn builder_should_validate_host() -> anyhow::Result<()> {
+        let client = Client::builder().build_client("https://gamma-api.polymarket.com")?;
+
+        assert_eq!(client.host().as_str(), "https://gamma-api.polymarket.com/");
+
+        let err = Client::builder().build_client("not a url").unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn markets_only_clob_token_ids() -> anyhow::Result<()> {
-        // Tests (true, false): only clob_token_ids, no base params
-        let server = MockServer::start();
-        let client = Client::new(&server.base_url())?;
+    async fn with_host_should_target_new_host_and_keep_config() -> anyhow::Result<()> {
+        let primary = MockServer::start();
+        let mirror = MockServer::start();
+        let config = Config::builder()
+            .accept_language("es")
+            .default_query(vec![("source".to_owned(), "my-app".to_owned())])
+            .build();
+        let client = Client::with_config(&primary.base_url(), config)?;
 
-        let mock = server.mock(|when, then| {
+        let failover = client.with_host(&mirror.base_url())?;
+
+        let primary_mock = primary.mock(|when, then| {
+            when.method(GET).path("/sports");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+        let mirror_mock = mirror.mock(|when, then| {
             when.method(GET)
-                .path("/markets")
-                .query_param("clob_token_ids", "token1")
-                .query_param("clob_token_ids", "token2");
-            then.status(StatusCode::OK).json_body(json!([
-                {"id": "1", "question": "Market 1?", "slug": "market-1"}
-            ]));
+                .path("/sports")
+                .header("accept-language", "es")
+                .query_param("source", "my-app");
+            then.status(StatusCode::OK).json_body(json!([]));
         });
 
-        let request = MarketsRequest::builder()
-            .clob_token_ids(vec!["token1".to_owned(), "token2".to_owned()])
-            .build();
-        let response = client.markets(&request).await?;
+        failover.sports().await?;
 
-        assert_eq!(response.len(), 1);
-        mock.assert();
+        assert_eq!(failover.host().as_str(), format!("{}/", mirror.base_url()));
+        assert_eq!(client.host().as_str(), format!("{}/", primary.base_url()));
+        mirror_mock.assert();
+        primary_mock.assert_calls(0);
 
         Ok(())
     }
 
+    #[test]
+    fn with_host_should_validate_host() {
+        let client = Client::new("http://localhost").unwrap();
+
+        let err = client.with_host("gamma-api.polymarket.com").unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+    }
+
     #[tokio::test]
-    async fn markets_with_base_and_clob_params() -> anyhow::Result<()> {
-        // Tests (false, false): both base params and clob_token_ids
+    async fn redirect_should_fail_by_default() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url())?;
 
         let mock = server.mock(|when, then| {
-            when.method(GET)
-                .path("/markets")
-                .query_param("limit", "50")
-                .query_param("clob_token_ids", "abc")
-                .query_param("clob_token_ids", "def");
-            then.status(StatusCode::OK).json_body(json!([
-                {"id": "1", "question": "Market 1?", "slug": "market-1"},
-                {"id": "2", "question": "Market 2?", "slug": "market-2"}
-            ]));
+            when.method(GET).path("/sports");
+            then.status(StatusCode::FOUND)
+                .header("location", "/v2/sports");
+        });
+        let moved = server.mock(|when, then| {
+            when.method(GET).path("/v2/sports");
+            then.status(StatusCode::OK).json_body(json!([]));
         });
 
-        let request = MarketsRequest::builder()
-            .limit(50)
-            .clob_token_ids(vec!["abc".to_owned(), "def".to_owned()])
-            .build();
-        let response = client.markets(&request).await?;
+        let err = client.sports().await.unwrap_err();
 
-        assert_eq!(response.len(), 2);
+        assert_eq!(err.kind(), Kind::Redirect);
+        let redirect = err.downcast_ref::<Redirect>().unwrap();
+        assert_eq!(redirect.status_code, StatusCode::FOUND);
+        assert_eq!(redirect.path, "/sports");
+        assert_eq!(redirect.location.as_deref(), Some("/v2/sports"));
         mock.assert();
+        moved.assert_calls(0);
 
         Ok(())
     }
-}
-
-mod search {
-    use httpmock::{Method::GET, MockServer};
-    use polymarket_client_sdk::gamma::{Client, types::request::SearchRequest};
-    use reqwest::StatusCode;
-    use serde_json::json;
 
     #[tokio::test]
-    async fn search_should_succeed() -> anyhow::Result<()> {
+    async fn limited_redirect_policy_should_follow() -> anyhow::Result<()> {
         let server = MockServer::start();
-        let client = Client::new(&server.base_url())?;
+        let config = Config::builder()
+            .redirect(RedirectPolicy::Limited(1))
+            .build();
+        let client = Client::with_config(&server.base_url(), config)?;
 
-        let mock = server.mock(|when, then| {
-            when.method(GET)
-                .path("/public-search")
-                .query_param("q", "bitcoin");
-            then.status(StatusCode::OK).json_body(json!({
-                "events": [],
-                "tags": [],
-                "profiles": []
-            }));
+        server.mock(|when, then| {
+            when.method(GET).path("/sports");
+            then.status(StatusCode::FOUND)
+                .header("location", "/v2/sports");
+        });
+        let moved = server.mock(|when, then| {
+            when.method(GET).path("/v2/sports");
+            then.status(StatusCode::OK).json_body(json!([]));
         });
 
-        let request = SearchRequest::builder().q("bitcoin").build();
-        let response = client.search(&request).await?;
+        let response = client.sports().await?;
 
-        assert!(
-            response.events.is_none()
-                || response
-                    .events
-                    .as_ref()
-                    .is_some_and(std::vec::Vec::is_empty)
-        );
-        mock.assert();
+        assert!(response.is_empty(), "redirect target should be returned");
+        moved.assert();
 
         Ok(())
     }
+
+    #[test]
+    fn invalid_accept_language_should_fail() {
+        let config = Config::builder().accept_language("es\n").build();
+
+        Client::with_config("http://localhost", config).unwrap_err();
+    }
 }
 
 mod health {
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::clob;
+    use polymarket_client_sdk::error::Status;
     use polymarket_client_sdk::gamma::Client;
+    use polymarket_client_sdk::health::{HealthStatus, healthcheck};
     use reqwest::StatusCode;
 
     #[tokio::test]
@@ -665,10 +2856,66 @@ mod health {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn healthcheck_should_report_degraded_when_one_api_fails() -> anyhow::Result<()> {
+        let gamma_server = MockServer::start();
+        let clob_server = MockServer::start();
+        let gamma = Client::new(&gamma_server.base_url())?;
+        let clob = clob::Client::new(&clob_server.base_url(), clob::Config::default())?;
+
+        gamma_server.mock(|when, then| {
+            when.method(GET).path("/status");
+            then.status(StatusCode::OK).body("OK");
+        });
+        let mut clob_mock = clob_server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::SERVICE_UNAVAILABLE);
+        });
+
+        let report = healthcheck(&gamma, &clob).await;
+
+        assert_eq!(report.status(), HealthStatus::Degraded);
+        assert!(report.gamma.is_reachable(), "gamma should be up");
+        let err = report.clob.error.unwrap();
+        assert_eq!(
+            err.downcast_ref::<Status>().unwrap().status_code,
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        clob_mock.delete();
+        clob_server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK).json_body("OK");
+        });
+
+        assert_eq!(healthcheck(&gamma, &clob).await.status(), HealthStatus::Up);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn healthcheck_should_report_down_when_both_apis_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let gamma = Client::new(&server.base_url())?;
+        let clob = clob::Client::new(&server.base_url(), clob::Config::default())?;
+
+        server.mock(|when, then| {
+            when.method(GET);
+            then.status(StatusCode::SERVICE_UNAVAILABLE);
+        });
+
+        let report = healthcheck(&gamma, &clob).await;
+
+        assert_eq!(report.status(), HealthStatus::Down);
+
+        Ok(())
+    }
 }
 
 mod series {
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::gamma::types::response::Recurrence;
     use polymarket_client_sdk::gamma::{
         Client,
         types::request::{SeriesByIdRequest, SeriesListRequest},
@@ -727,7 +2974,7 @@ mod series {
 
         assert_eq!(response.id, "42");
         assert_eq!(response.title, Some("NFL Season 2024".to_owned()));
-        assert_eq!(response.recurrence, Some("weekly".to_owned()));
+        assert_eq!(response.recurrence, Some(Recurrence::Weekly));
         mock.assert();
 
         Ok(())
@@ -737,6 +2984,7 @@ mod series {
 mod comments {
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::gamma::types::ParentEntityType;
+    use polymarket_client_sdk::gamma::types::response::{Comment, CommentThread, ReactionType};
     use polymarket_client_sdk::gamma::{
         Client,
         types::request::{CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest},
@@ -744,6 +2992,91 @@ mod comments {
     use reqwest::StatusCode;
     use serde_json::json;
 
+    fn ids(threads: &[CommentThread]) -> Vec<&str> {
+        threads
+            .iter()
+            .map(|thread| thread.comment.id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn thread_should_nest_replies() -> anyhow::Result<()> {
+        let comments: Vec<Comment> = serde_json::from_value(json!([
+            { "id": "1", "body": "First" },
+            { "id": "2", "parentCommentID": "1", "body": "Reply to first" },
+            { "id": "3", "body": "Second" },
+            { "id": "4", "parentCommentID": "2", "body": "Reply to reply" },
+            { "id": "5", "parentCommentID": "1", "body": "Another reply" },
+            { "id": "6", "parentCommentID": "99", "body": "Orphaned reply" }
+        ]))?;
+
+        let threads = CommentThread::build(comments);
+
+        assert_eq!(ids(&threads), ["1", "3", "6"]);
+        assert_eq!(ids(&threads[0].replies), ["2", "5"]);
+        assert_eq!(ids(&threads[0].replies[0].replies), ["4"]);
+        assert_eq!(threads[0].reply_count(), 3);
+        assert!(
+            threads[1].replies.is_empty() && threads[2].replies.is_empty(),
+            "comments without replies should have none"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn thread_should_break_parent_cycles() -> anyhow::Result<()> {
+        let comments: Vec<Comment> = serde_json::from_value(json!([
+            { "id": "1", "parentCommentID": "2" },
+            { "id": "2", "parentCommentID": "1" },
+            { "id": "3", "parentCommentID": "3" }
+        ]))?;
+
+        let threads = CommentThread::build(comments);
+
+        assert_eq!(ids(&threads), ["3", "1"]);
+        assert_eq!(ids(&threads[1].replies), ["2"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reaction_counts_should_group_by_type() -> anyhow::Result<()> {
+        let comment: Comment = serde_json::from_value(json!({
+            "id": "1",
+            "reactions": [
+                { "id": "10", "reactionType": "HEART" },
+                { "id": "11", "reactionType": "HEART" },
+                { "id": "12", "reactionType": "THUMBS_UP" },
+                { "id": "13" }
+            ]
+        }))?;
+
+        let counts = comment.reaction_counts();
+
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts.get(&ReactionType::Heart), Some(&2));
+        assert_eq!(counts.get(&ReactionType::ThumbsUp), Some(&1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn comment_timestamps_should_accept_unix_and_rfc3339() -> anyhow::Result<()> {
+        let comment: Comment = serde_json::from_value(json!({
+            "id": "1",
+            "createdAt": "2024-01-15T10:30:00Z",
+            "updatedAt": 1_705_314_600_000_i64
+        }))?;
+
+        assert!(
+            comment.created_at.is_some() && comment.created_at == comment.updated_at,
+            "both encodings should denote the same instant"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn comments_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -988,6 +3321,7 @@ mod market_tags {
 
 mod query_string {
     use chrono::{TimeZone as _, Utc};
+    use polymarket_client_sdk::error::{Kind, Validation};
     use polymarket_client_sdk::gamma::types::request::{
         CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest, EventByIdRequest,
         EventBySlugRequest, EventTagsRequest, EventsRequest, MarketByIdRequest,
@@ -1064,6 +3398,20 @@ mod query_string {
         assert!(qs.contains("is_carousel=true"));
     }
 
+    #[test]
+    fn tags_request_display_flags() {
+        let request = TagsRequest::builder()
+            .is_carousel(true)
+            .force_show(true)
+            .build();
+
+        assert_eq!(query_string(&request), "?is_carousel=true&force_show=true");
+        assert!(
+            !query_string(&TagsRequest::default()).contains("force_show"),
+            "unset flags should be omitted"
+        );
+    }
+
     #[test]
     fn tag_by_id_request_with_include_template() {
         let request = TagByIdRequest::builder()
@@ -1186,6 +3534,50 @@ mod query_string {
         assert!(qs.contains("end_date_max="));
     }
 
+    #[test]
+    fn events_request_date_range_rfc3339() {
+        let start_date = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end_date = Utc.with_ymd_and_hms(2024, 1, 7, 23, 59, 59).unwrap();
+
+        let request = EventsRequest::builder()
+            .end_date_min(start_date)
+            .end_date_max(end_date)
+            .build();
+
+        let qs = query_string(&request);
+        assert_eq!(
+            qs,
+            "?end_date_min=2024-01-01T00%3A00%3A00Z&end_date_max=2024-01-07T23%3A59%3A59Z"
+        );
+        request.validate().unwrap();
+    }
+
+    #[test]
+    fn events_request_date_range_min_after_max_fails() {
+        let start_date = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let end_date = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        let request = EventsRequest::builder()
+            .start_date_min(start_date)
+            .start_date_max(end_date)
+            .build();
+
+        let err = request.validate().unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(
+            msg,
+            "start_date_min (2024-02-01T00:00:00+00:00) must not be after start_date_max (2024-01-01T00:00:00+00:00)"
+        );
+
+        // Only one bound set is always valid
+        EventsRequest::builder()
+            .start_date_min(start_date)
+            .end_date_max(end_date)
+            .build()
+            .validate()
+            .unwrap();
+    }
+
     #[test]
     fn events_request_empty_arrays_not_included() {
         let request = EventsRequest::builder()
@@ -1303,6 +3695,26 @@ mod query_string {
         assert!(qs.contains("closed=false"));
     }
 
+    #[test]
+    fn markets_request_date_range_min_after_max_fails() {
+        let start_date = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let end_date = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        let request = MarketsRequest::builder()
+            .end_date_min(start_date)
+            .end_date_max(end_date)
+            .build();
+
+        let err = request.validate().unwrap_err();
+        assert_eq!(err.kind(), Kind::Validation);
+
+        let request = MarketsRequest::builder()
+            .end_date_min(end_date)
+            .end_date_max(start_date)
+            .build();
+        request.validate().unwrap();
+    }
+
     #[test]
     fn markets_request_empty_arrays_not_included() {
         let request = MarketsRequest::builder()
@@ -1548,3 +3960,66 @@ mod query_string {
         assert!(qs.is_empty());
     }
 }
+
+#[cfg(feature = "blocking")]
+mod blocking {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::error::Kind;
+    use polymarket_client_sdk::gamma::blocking::Client;
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    #[test]
+    fn sports_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/sports");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "sport": "ncaab",
+                    "image": "https://example.com/basketball.png",
+                    "resolution": "https://example.com",
+                    "ordering": "home",
+                    "tags": "1,2,3",
+                    "series": "39"
+                }
+            ]));
+        });
+
+        let response = client.sports()?;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].sport, "ncaab");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn error_status_should_be_returned() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/sports");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR).body("boom");
+        });
+
+        let err = client.sports().unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn new_inside_runtime_should_fail() {
+        let err = Client::new("https://gamma-api.polymarket.com").unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        assert!(err.to_string().contains("async runtime"));
+    }
+}
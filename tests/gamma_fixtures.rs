@@ -0,0 +1,144 @@
+//! Contract tests for the Gamma API types against captured responses.
+//!
+//! Every file under `tests/fixtures/gamma/` holds a response body as returned by the Gamma API.
+//! Each one must deserialize into the crate's types, and the fields that callers rely on must not
+//! come back empty. A field that is added to a type without `Option` fails here as soon as a
+//! fixture lacks it, so refresh the fixtures whenever the API changes shape.
+//!
+//! # Running Tests
+//!
+//! ```bash
+//! cargo test --features gamma --test gamma_fixtures
+//! ```
+
+#![cfg(feature = "gamma")]
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+use polymarket_client_sdk::gamma::types::response::{Event, Market, Series, Tag};
+use serde::de::DeserializeOwned;
+
+/// Fixture files checked by the tests below. A file that is not listed here fails
+/// `every_fixture_should_be_checked`, so new captures cannot be skipped silently.
+const FIXTURES: &[&str] = &["events.json", "markets.json", "series.json", "tags.json"];
+
+fn fixture_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/gamma")
+}
+
+fn load<T: DeserializeOwned>(name: &str) -> anyhow::Result<Vec<T>> {
+    let path = fixture_dir().join(name);
+    let body = fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+    let values: Vec<T> = serde_json::from_str(&body)
+        .with_context(|| format!("{name} no longer matches the crate's types"))?;
+
+    assert!(!values.is_empty(), "{name} should not be empty");
+    Ok(values)
+}
+
+fn require(value: Option<&str>, field: &str, id: &str) {
+    assert!(
+        value.is_some_and(|v| !v.trim().is_empty()),
+        "`{field}` of {id} should not be empty"
+    );
+}
+
+fn json_list(value: Option<&str>, field: &str, id: &str) -> Vec<String> {
+    require(value, field, id);
+    serde_json::from_str(value.unwrap_or_default())
+        .unwrap_or_else(|e| panic!("`{field}` of {id} should be a JSON list: {e}"))
+}
+
+fn check_market(market: &Market) {
+    let id = format!("market {}", market.id);
+
+    assert!(!market.id.is_empty(), "market id should not be empty");
+    require(market.question.as_deref(), "question", &id);
+    require(market.condition_id.as_deref(), "conditionId", &id);
+    require(market.slug.as_deref(), "slug", &id);
+
+    let outcomes = json_list(market.outcomes.as_deref(), "outcomes", &id);
+    let prices = json_list(market.outcome_prices.as_deref(), "outcomePrices", &id);
+    assert_eq!(outcomes.len(), prices.len(), "{id}: one price per outcome");
+
+    if market.enable_order_book == Some(true) {
+        let token_ids = json_list(market.clob_token_ids.as_deref(), "clobTokenIds", &id);
+        assert_eq!(
+            outcomes.len(),
+            token_ids.len(),
+            "{id}: one token per outcome"
+        );
+    }
+}
+
+fn check_tag(tag: &Tag) {
+    let id = format!("tag {}", tag.id);
+
+    assert!(!tag.id.is_empty(), "tag id should not be empty");
+    require(tag.label.as_deref(), "label", &id);
+    require(tag.slug.as_deref(), "slug", &id);
+}
+
+fn check_series(series: &Series) {
+    let id = format!("series {}", series.id);
+
+    assert!(!series.id.is_empty(), "series id should not be empty");
+    require(series.slug.as_deref(), "slug", &id);
+    require(series.title.as_deref(), "title", &id);
+}
+
+#[test]
+fn every_fixture_should_be_checked() -> anyhow::Result<()> {
+    let mut names = fs::read_dir(fixture_dir())?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    names.sort();
+
+    assert_eq!(names, FIXTURES);
+
+    Ok(())
+}
+
+#[test]
+fn events_fixture_should_deserialize() -> anyhow::Result<()> {
+    for event in load::<Event>("events.json")? {
+        let id = format!("event {}", event.id);
+
+        assert!(!event.id.is_empty(), "event id should not be empty");
+        require(event.slug.as_deref(), "slug", &id);
+        require(event.title.as_deref(), "title", &id);
+
+        let markets = event.markets.as_deref().unwrap_or_default();
+        assert!(!markets.is_empty(), "{id} should list its markets");
+        markets.iter().for_each(check_market);
+        event.tags.iter().flatten().for_each(check_tag);
+        event.series.iter().flatten().for_each(check_series);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn markets_fixture_should_deserialize() -> anyhow::Result<()> {
+    load::<Market>("markets.json")?
+        .iter()
+        .for_each(check_market);
+
+    Ok(())
+}
+
+#[test]
+fn series_fixture_should_deserialize() -> anyhow::Result<()> {
+    load::<Series>("series.json")?.iter().for_each(check_series);
+
+    Ok(())
+}
+
+#[test]
+fn tags_fixture_should_deserialize() -> anyhow::Result<()> {
+    load::<Tag>("tags.json")?.iter().for_each(check_tag);
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use std::process::Command;
+
+/// The crate has to compile with every optional feature turned off, including the TLS backends.
+///
+/// The check runs in its own target directory, as the outer `cargo test` keeps the main one locked,
+/// and offline, as it only needs a subset of the crates that build already fetched.
+#[test]
+fn no_default_features_should_compile() {
+    let output = Command::new(env!("CARGO"))
+        .args([
+            "check",
+            "--lib",
+            "--no-default-features",
+            "--offline",
+            "--quiet",
+        ])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env(
+            "CARGO_TARGET_DIR",
+            concat!(env!("CARGO_TARGET_TMPDIR"), "/minimal-build"),
+        )
+        .output()
+        .expect("failed to run cargo");
+
+    assert!(
+        output.status.success(),
+        "minimal build failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -8,7 +8,7 @@ mod common;
 use std::str::FromStr as _;
 
 use alloy::primitives::U256;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use httpmock::MockServer;
 use polymarket_client_sdk::clob::types::response::OrderSummary;
 use polymarket_client_sdk::clob::types::{Amount, OrderType, Side, SignatureType, TickSize};
@@ -474,6 +474,60 @@ mod lifecycle {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn maker_and_signer_should_follow_signature_type() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let funder = address!("0xaDEFf2158d668f64308C62ef227C5CcaCAAf976D");
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key")
+                .header(POLY_ADDRESS, signer.address().to_string().to_lowercase());
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+        ensure_requirements(&server, "1", TickSize::Tenth);
+
+        // The EOA signs every order; with a proxy or Safe the funds, and so the maker, are the
+        // wallet's
+        for (signature_type, funder, maker) in [
+            (SignatureType::Eoa, None, signer.address()),
+            (SignatureType::Proxy, Some(funder), funder),
+            (SignatureType::GnosisSafe, Some(funder), funder),
+        ] {
+            let mut builder = Client::new(&server.base_url(), Config::default())?
+                .authentication_builder(&signer)
+                .signature_type(signature_type);
+            if let Some(funder) = funder {
+                builder = builder.funder(funder);
+            }
+            let client = builder.authenticate().await?;
+
+            let signable_order = client
+                .limit_order()
+                .token_id("1")
+                .size(Decimal::ONE_HUNDRED)
+                .price(dec!(0.1))
+                .side(Side::Buy)
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.order.maker, maker, "{signature_type:?}");
+            assert_eq!(
+                signable_order.order.signer,
+                signer.address(),
+                "{signature_type:?}"
+            );
+            assert_eq!(signable_order.order.signatureType, signature_type as u8);
+        }
+
+        Ok(())
+    }
+
     /// Tests that explicit funder address overrides the auto-derivation.
     #[tokio::test]
     async fn explicit_funder_overrides_auto_derivation() -> anyhow::Result<()> {
@@ -564,7 +618,8 @@ mod lifecycle {
 }
 
 mod limit {
-    use polymarket_client_sdk::error::Validation;
+    use polymarket_client_sdk::error::{Kind, Validation};
+    use serde_json::json;
 
     use super::*;
 
@@ -593,6 +648,58 @@ mod limit {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn expires_in_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, TOKEN_1, TickSize::Tenth);
+
+        let before = Utc::now().timestamp() + 3600;
+        let signable_order = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.5))
+            .size(dec!(21.04))
+            .side(Side::Buy)
+            .order_type(OrderType::GTD)
+            .expires_in(TimeDelta::hours(1))
+            .build()
+            .await?;
+        let after = Utc::now().timestamp() + 3600;
+
+        let expiration = i64::try_from(signable_order.order.expiration)?;
+        assert!(
+            (before..=after).contains(&expiration),
+            "expiration should be an hour from now, in seconds"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn no_expiration_should_succeed_for_gtc() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, TOKEN_1, TickSize::Tenth);
+
+        let signable_order = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.5))
+            .size(dec!(21.04))
+            .side(Side::Buy)
+            .order_type(OrderType::GTC)
+            .no_expiration()
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.order.expiration, U256::ZERO);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn should_fail_on_missing_fields() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -659,6 +766,97 @@ mod limit {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn order_builder_should_snap_price_to_tick() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, TOKEN_1, TickSize::Hundredth);
+
+        let signable_order = client
+            .order_builder(TOKEN_1)
+            .buy()
+            .price(dec!(0.5251))
+            .size(dec!(10))
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.order.makerAmount, U256::from(5_300_000));
+        assert_eq!(signable_order.order.takerAmount, U256::from(10_000_000));
+        assert_eq!(signable_order.order.side, Side::Buy as u8);
+
+        let signable_order = client
+            .order_builder(TOKEN_1)
+            .sell()
+            .price(dec!(0.125))
+            .size(dec!(10))
+            .build()
+            .await?;
+
+        // 0.125 rounds half to even
+        assert_eq!(signable_order.order.makerAmount, U256::from(10_000_000));
+        assert_eq!(signable_order.order.takerAmount, U256::from(1_200_000));
+        assert_eq!(signable_order.order.side, Side::Sell as u8);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn order_builder_should_fail_on_out_of_range_price() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, TOKEN_1, TickSize::Hundredth);
+
+        for price in [dec!(0.996), dec!(0.004), dec!(1.2)] {
+            let err = client
+                .order_builder(TOKEN_1)
+                .buy()
+                .price(price)
+                .size(dec!(10))
+                .build()
+                .await
+                .unwrap_err();
+            let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+            assert!(
+                msg.ends_with("is too small or too large for the minimum tick size 0.01"),
+                "unexpected error for {price}: {msg}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn order_builder_should_surface_metadata_errors() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/fee-rate");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "base_fee": 0 }));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/tick-size");
+            then.status(StatusCode::NOT_FOUND);
+        });
+
+        let err = client
+            .order_builder(TOKEN_1)
+            .buy()
+            .price(dec!(0.5))
+            .size(dec!(10))
+            .build()
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn should_fail_on_negative_price_and_size() -> anyhow::Result<()> {
         let server = MockServer::start();
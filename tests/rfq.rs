@@ -149,7 +149,7 @@ mod request {
         });
 
         let request = RfqRequestsRequest::default();
-        let response = client.requests(&request, Some("abc123")).await?;
+        let response = client.requests(&request, Some("abc123".into())).await?;
 
         assert_eq!(response.count, 0);
         mock.assert();
@@ -338,6 +338,35 @@ mod market_channel {
         assert_eq!(book.asset_id, subscribed_asset);
     }
 
+    #[tokio::test]
+    async fn undecodable_frame_surfaces_as_error() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let config = Config::default();
+        let client = Client::new(&endpoint, config).unwrap();
+
+        let stream = client
+            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+
+        server.send("{not json");
+        server.send(&json!({"event_type": "brand_new_thing"}).to_string());
+        server.send(&payloads::book().to_string());
+
+        // The bad frame is reported, the unknown one is skipped by the typed stream, and the
+        // stream keeps going
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        result.unwrap().unwrap().unwrap_err();
+
+        let result = timeout(Duration::from_secs(2), stream.next()).await;
+        let book = result.unwrap().unwrap().unwrap();
+        assert_eq!(book.asset_id, payloads::ASSET_ID);
+    }
+
     #[tokio::test]
     async fn subscribe_midpoints_calculates_midpoint() {
         let mut server = MockWsServer::start().await;
@@ -402,6 +431,169 @@ mod market_channel {
     }
 }
 
+mod commands {
+    use polymarket_client_sdk::clob::ws::{MarketChannel, WsCommand};
+
+    use super::*;
+    use crate::payloads::OTHER_ASSET_ID;
+
+    /// Drives `channel` in the background, forwarding its items.
+    fn drive(mut channel: MarketChannel) -> mpsc::UnboundedReceiver<WsMessage> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Some(Ok(msg)) = channel.next().await {
+                drop(tx.send(msg));
+            }
+        });
+        rx
+    }
+
+    #[tokio::test]
+    async fn subscribe_command_sends_subscription_and_forwards_messages() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+        let channel = client
+            .market_channel(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let commands = channel.command_sender();
+        let mut messages = drive(channel);
+
+        let sub = server.recv_subscription().await.unwrap();
+        assert!(sub.contains(payloads::ASSET_ID));
+
+        commands
+            .send(WsCommand::Subscribe(vec![OTHER_ASSET_ID.to_owned()]))
+            .await
+            .unwrap();
+
+        let sub = server.recv_subscription().await.unwrap();
+        assert!(
+            sub.contains(OTHER_ASSET_ID),
+            "Should subscribe to the new asset, got: {sub}"
+        );
+        assert!(
+            !sub.contains(payloads::ASSET_ID),
+            "Should not resubscribe to the existing asset, got: {sub}"
+        );
+
+        let mut other_book = payloads::book();
+        other_book["asset_id"] = serde_json::Value::String(OTHER_ASSET_ID.to_owned());
+        server.send(&other_book.to_string());
+
+        let msg = timeout(Duration::from_secs(2), messages.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let WsMessage::Book(book) = msg else {
+            panic!("Expected a book update, got: {msg:?}");
+        };
+        assert_eq!(book.asset_id, OTHER_ASSET_ID);
+    }
+
+    #[tokio::test]
+    async fn unsubscribe_command_stops_forwarding() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+        let channel = client
+            .market_channel(vec![
+                payloads::ASSET_ID.to_owned(),
+                OTHER_ASSET_ID.to_owned(),
+            ])
+            .unwrap();
+        let commands = channel.command_sender();
+        let mut messages = drive(channel);
+
+        let _: Option<String> = server.recv_subscription().await;
+
+        commands
+            .send(WsCommand::Unsubscribe(vec![OTHER_ASSET_ID.to_owned()]))
+            .await
+            .unwrap();
+
+        let unsub = server.recv_subscription().await.unwrap();
+        assert!(
+            unsub.contains("\"operation\":\"unsubscribe\""),
+            "Should send unsubscribe request, got: {unsub}"
+        );
+        assert!(unsub.contains(OTHER_ASSET_ID));
+
+        let mut other_book = payloads::book();
+        other_book["asset_id"] = serde_json::Value::String(OTHER_ASSET_ID.to_owned());
+        server.send(&other_book.to_string());
+        server.send(&payloads::book().to_string());
+
+        let msg = timeout(Duration::from_secs(2), messages.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let WsMessage::Book(book) = msg else {
+            panic!("Expected a book update, got: {msg:?}");
+        };
+        assert_eq!(book.asset_id, payloads::ASSET_ID);
+    }
+
+    #[tokio::test]
+    async fn close_command_unsubscribes_and_ends_stream() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+        let mut channel = client
+            .market_channel(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+
+        let _: Option<String> = server.recv_subscription().await;
+
+        channel
+            .command_sender()
+            .send(WsCommand::Close)
+            .await
+            .unwrap();
+
+        let next = timeout(Duration::from_secs(2), channel.next())
+            .await
+            .unwrap();
+        assert!(next.is_none(), "Stream should end after close");
+
+        let unsub = server.recv_subscription().await.unwrap();
+        assert!(
+            unsub.contains("\"operation\":\"unsubscribe\""),
+            "Should send unsubscribe request, got: {unsub}"
+        );
+        assert_eq!(client.subscription_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn dropping_channel_unsubscribes() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+        let channel = client
+            .market_channel(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+
+        let _: Option<String> = server.recv_subscription().await;
+
+        drop(channel);
+
+        let unsub = timeout(Duration::from_secs(2), server.recv_subscription())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(
+            unsub.contains("\"operation\":\"unsubscribe\""),
+            "Should send unsubscribe request, got: {unsub}"
+        );
+        assert!(unsub.contains(payloads::ASSET_ID));
+        assert_eq!(client.subscription_count(), 0);
+    }
+}
+
 mod user_channel {
     use polymarket_client_sdk::auth::Credentials;
     use polymarket_client_sdk::clob::types::Side;
@@ -851,6 +1043,56 @@ mod reconnection {
         );
     }
 
+    #[tokio::test]
+    async fn reconnects_once_connection_goes_silent() {
+        let mut server = ReconnectableMockServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let max_idle = Duration::from_millis(300);
+        let mut config = config();
+        config.max_idle = Some(max_idle);
+        let client = Client::new(&endpoint, config).unwrap();
+
+        let _stream = client
+            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+        let subscribed_at = std::time::Instant::now();
+
+        // The server stays connected but sends nothing, not even PONG replies
+        let resub = server.recv_subscription().await;
+        let elapsed = subscribed_at.elapsed();
+
+        assert!(
+            resub.is_some_and(|resub| resub.contains(payloads::ASSET_ID)),
+            "a silent connection should be replaced and resubscribed"
+        );
+        assert!(
+            elapsed >= max_idle - Duration::from_millis(50),
+            "the watchdog should not fire early, fired after {elapsed:?}"
+        );
+        assert!(
+            elapsed < max_idle + Duration::from_secs(1),
+            "the watchdog should fire within the window, fired after {elapsed:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn keeps_silent_connection_without_max_idle() {
+        let mut server = ReconnectableMockServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, config()).unwrap();
+
+        let _stream = client
+            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+
+        let resub = timeout(Duration::from_millis(600), server.subscription_rx.recv()).await;
+        assert!(resub.is_err(), "the connection should not be replaced");
+    }
+
     #[tokio::test]
     async fn resubscribes_all_assets_after_reconnect() {
         let mut server = ReconnectableMockServer::start().await;
@@ -1102,9 +1344,65 @@ mod unsubscribe {
 
 mod client_state {
     use polymarket_client_sdk::clob::ws::ChannelType;
+    use polymarket_client_sdk::ws::stats::WsStats;
 
     use super::*;
 
+    #[tokio::test]
+    async fn stats_reflect_received_frames() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+        assert_eq!(client.stats(ChannelType::Market).messages(), 0);
+
+        let stream = client
+            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+        let _: Option<String> = server.recv_subscription().await;
+
+        let book = payloads::book().to_string();
+        let last_trade_price = payloads::last_trade_price(payloads::ASSET_ID).to_string();
+        for _ in 0..3 {
+            server.send(&book);
+        }
+        server.send(&last_trade_price);
+        server.send("not json");
+        for _ in 0..3 {
+            timeout(Duration::from_secs(2), stream.next())
+                .await
+                .unwrap()
+                .unwrap()
+                .unwrap();
+        }
+        // Books are delivered in order, so wait until the last frame has been counted
+        timeout(Duration::from_secs(2), async {
+            while client.stats(ChannelType::Market).parse_errors == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        let stats = client.stats(ChannelType::Market);
+        assert_eq!(stats.messages_by_type.get("book"), Some(&3));
+        assert_eq!(stats.messages_by_type.get("last_trade_price"), Some(&1));
+        assert_eq!(stats.messages(), 4);
+        assert_eq!(stats.parse_errors, 1);
+        assert_eq!(
+            stats.bytes_received,
+            u64::try_from(3 * book.len() + last_trade_price.len() + "not json".len()).unwrap()
+        );
+        assert_eq!(stats.reconnects, 0);
+        assert_eq!(stats.subscriptions, 1);
+        assert!(
+            stats.last_message_at.is_some(),
+            "the last message time should be set"
+        );
+        assert_eq!(client.stats(ChannelType::User), WsStats::default());
+    }
+
     #[tokio::test]
     async fn is_connected_returns_false_before_subscription() {
         let server = MockWsServer::start().await;
@@ -1575,3 +1873,61 @@ mod message_parsing {
         assert_eq!(ltp.timestamp, 1_750_428_146_322);
     }
 }
+
+mod shutdown {
+    use tokio::sync::oneshot;
+
+    use super::*;
+
+    /// Starts a server for a single connection that sends a book update after the subscription
+    /// request, and reports whether the client ended the connection with a close frame.
+    async fn start_single_connection_server() -> (String, oneshot::Receiver<bool>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (closed_tx, closed_rx) = oneshot::channel();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws_stream = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+            let clean_close = loop {
+                match ws_stream.next().await {
+                    Some(Ok(Message::Text(text))) if text != "PING" => {
+                        let book = Message::Text(payloads::book().to_string().into());
+                        ws_stream.send(book).await.unwrap();
+                    }
+                    Some(Ok(Message::Close(_))) => break true,
+                    Some(Ok(_)) => {}
+                    Some(Err(_)) | None => break false,
+                }
+            };
+            _ = closed_tx.send(clean_close);
+        });
+
+        (format!("ws://{addr}/ws/market"), closed_rx)
+    }
+
+    #[tokio::test]
+    async fn dropping_client_and_stream_closes_connection() {
+        let (endpoint, closed_rx) = start_single_connection_server().await;
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let stream = client
+            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+        let book = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap();
+        assert_eq!(book.unwrap().unwrap().asset_id, payloads::ASSET_ID);
+
+        drop(stream);
+        drop(client);
+
+        let clean_close = timeout(Duration::from_secs(2), closed_rx)
+            .await
+            .expect("connection task should shut down")
+            .unwrap();
+        assert!(clean_close, "client should send a close frame");
+    }
+}